@@ -580,7 +580,10 @@ class VanityGeneratorApp {
         }
         
         if (isMatch) {
-          this.showSuccess(keypair);
+          this.showSuccess({
+            address: keypair.address,
+            mnemonic: keypair.reveal_mnemonic(true)
+          });
           return;
         }
       }
@@ -679,6 +682,9 @@ class VanityGeneratorApp {
 
   /**
    * Show generation success
+   *
+   * Takes a plain { address, mnemonic } object; WASM keypairs are revealed
+   * with reveal_mnemonic() before they get here.
    */
   showSuccess(keypair) {
     this.elements.addressValue.textContent = keypair.address;
@@ -0,0 +1,755 @@
+//! Bech32 address encoding utilities
+//!
+//! This module implements the Cosmos account address scheme
+//! (SHA256 → RIPEMD160 → bech32) independently of any secret material, so
+//! addresses can be computed for public keys that come from elsewhere. It also
+//! covers the validator operator and consensus address forms. Both the
+//! bech32 and bech32m checksum variants are supported.
+
+use crate::error::VanityError;
+use crate::hd::hash160;
+use bech32::{decode as bech32_decode, encode as bech32_encode, FromBase32, ToBase32, Variant};
+use k256::{elliptic_curve::sec1::ToEncodedPoint, PublicKey};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
+use wasm_bindgen::prelude::*;
+
+/// Human-readable part used for MANTRA account addresses
+pub const MANTRA_HRP: &str = "mantra";
+
+/// The 32-character bech32 data alphabet
+pub const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Maximum total length of a bech32 string
+const MAX_BECH32_LENGTH: usize = 90;
+
+/// Amino prefix of `tendermint/PubKeySecp256k1`
+pub const SECP256K1_AMINO_PREFIX: [u8; 4] = [0xEB, 0x5A, 0xE9, 0x87];
+
+/// Maximum length of a bech32 human-readable part
+const MAX_HRP_LENGTH: usize = 83;
+
+/// Number of bytes in a standard account ID
+const ACCOUNT_ID_LENGTH: usize = 20;
+
+/// Number of checksum characters appended to every bech32 string
+const CHECKSUM_LENGTH: usize = 6;
+
+/// Describes why a string is not a syntactically valid bech32 HRP
+///
+/// Checks the BIP173 rules only: 1 to 83 printable ASCII characters, not
+/// mixing upper and lower case. Returns None for a valid HRP.
+pub fn hrp_error(hrp: &str) -> Option<String> {
+    if hrp.is_empty() {
+        return Some("HRP must not be empty".to_string());
+    }
+    if hrp.len() > MAX_HRP_LENGTH {
+        return Some(format!(
+            "HRP is {} characters long (max {})",
+            hrp.len(),
+            MAX_HRP_LENGTH
+        ));
+    }
+    if let Some((index, ch)) = hrp
+        .char_indices()
+        .find(|(_, ch)| !(33..=126).contains(&(*ch as u32)))
+    {
+        return Some(format!(
+            "HRP {:?} contains {:?} at position {}; only printable ASCII is allowed",
+            hrp, ch, index
+        ));
+    }
+    if hrp.bytes().any(|byte| byte.is_ascii_lowercase())
+        && hrp.bytes().any(|byte| byte.is_ascii_uppercase())
+    {
+        return Some(format!("HRP {:?} mixes upper and lower case", hrp));
+    }
+    None
+}
+
+/// Describes why `data_length` bytes encoded under `hrp` would exceed 90 characters
+///
+/// Returns None if the resulting bech32 string fits.
+pub fn address_length_error(hrp: &str, data_length: usize) -> Option<String> {
+    let length = hrp.len() + 1 + (data_length * 8).div_ceil(5) + CHECKSUM_LENGTH;
+    (length > MAX_BECH32_LENGTH).then(|| {
+        format!(
+            "HRP {:?} is too long: a {}-byte address would be {} characters (max {})",
+            hrp, data_length, length, MAX_BECH32_LENGTH
+        )
+    })
+}
+
+/// Checks whether a string is a valid bech32 human-readable part
+///
+/// The HRP must be 1 to 83 printable ASCII characters, must not mix upper
+/// and lower case, and must leave room for a 20-byte account address within
+/// the 90-character bech32 limit (so at most 51 characters in practice);
+/// `"mantra"`, `"cosmos"` and `"osmo"` are all valid.
+///
+/// # Arguments
+/// * `hrp` - The human-readable part to validate (without the `1` separator)
+///
+/// # Returns
+/// * `bool` - true if addresses can be encoded under the HRP
+#[wasm_bindgen]
+pub fn validate_hrp(hrp: &str) -> bool {
+    hrp_problem(hrp).is_none()
+}
+
+/// Checks a bech32 human-readable part, explaining why it is rejected
+///
+/// This applies the same rules as `validate_hrp()` but returns an error that
+/// names the problem, so a custom-prefix form can show it to the user before
+/// any search starts.
+///
+/// # Arguments
+/// * `hrp` - The human-readable part to check (without the `1` separator)
+///
+/// # Returns
+/// * `()` - Nothing if the HRP is usable, or an `InvalidHrp` error describing the problem
+///
+/// # Example
+/// ```javascript
+/// try {
+///   check_hrp(prefixInput.value);
+/// } catch (e) {
+///   prefixError.textContent = e.message;
+/// }
+/// ```
+#[wasm_bindgen]
+pub fn check_hrp(hrp: &str) -> Result<(), VanityError> {
+    match hrp_problem(hrp) {
+        Some(message) => Err(VanityError::InvalidHrp(message)),
+        None => Ok(()),
+    }
+}
+
+/// Applies the HRP syntax rules and the 20-byte account address length limit
+fn hrp_problem(hrp: &str) -> Option<String> {
+    hrp_error(hrp).or_else(|| address_length_error(hrp, ACCOUNT_ID_LENGTH))
+}
+
+/// Bech32 checksum variant
+///
+/// Cosmos SDK addresses use classic bech32; bech32m (BIP350) fixes a
+/// length-extension weakness and is used by some newer chains and address types.
+#[wasm_bindgen]
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub enum Bech32Variant {
+    /// Classic bech32 (BIP173), used by Cosmos SDK addresses
+    #[default]
+    Bech32 = 0,
+    /// Bech32m (BIP350)
+    Bech32m = 1,
+}
+
+impl From<Bech32Variant> for Variant {
+    fn from(variant: Bech32Variant) -> Self {
+        match variant {
+            Bech32Variant::Bech32 => Variant::Bech32,
+            Bech32Variant::Bech32m => Variant::Bech32m,
+        }
+    }
+}
+
+impl From<Variant> for Bech32Variant {
+    fn from(variant: Variant) -> Self {
+        match variant {
+            Variant::Bech32 => Bech32Variant::Bech32,
+            Variant::Bech32m => Bech32Variant::Bech32m,
+        }
+    }
+}
+
+/// Encodes raw bytes as a bech32 string under the given human-readable part
+pub fn encode_bech32(hrp: &str, data: &[u8]) -> Result<String, Box<dyn std::error::Error>> {
+    encode_bech32_with_variant(hrp, data, Bech32Variant::Bech32)
+}
+
+/// Encodes raw bytes under the given human-readable part with an explicit checksum variant
+pub fn encode_bech32_with_variant(
+    hrp: &str,
+    data: &[u8],
+    variant: Bech32Variant,
+) -> Result<String, Box<dyn std::error::Error>> {
+    if let Some(message) = hrp_error(hrp).or_else(|| address_length_error(hrp, data.len())) {
+        return Err(message.into());
+    }
+
+    bech32_encode(hrp, data.to_base32(), variant.into())
+        .map_err(|e| format!("Failed to encode bech32 address: {}", e).into())
+}
+
+/// Encodes a compressed secp256k1 public key as an account address
+///
+/// # Arguments
+/// * `pubkey_bytes` - The 33-byte compressed public key
+/// * `hrp` - The bech32 human-readable part (e.g. "mantra")
+///
+/// # Returns
+/// * `String` - The bech32-encoded account address
+pub fn public_key_to_address(
+    pubkey_bytes: &[u8],
+    hrp: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    // Standard Cosmos address derivation: SHA256 then RIPEMD160 of public key
+    let ripemd_hash = hash160(pubkey_bytes);
+
+    encode_bech32(hrp, &ripemd_hash)
+}
+
+/// Computes the 20-byte Ethereum-style account ID of a secp256k1 public key
+///
+/// This is the last 20 bytes of keccak256 over the 64-byte uncompressed public
+/// key (without the 0x04 SEC1 tag), as used by ethsecp256k1 accounts.
+///
+/// # Arguments
+/// * `pubkey_bytes` - A compressed or uncompressed SEC1 public key
+///
+/// # Returns
+/// * `[u8; 20]` - The account ID bytes
+pub fn evm_account_id(pubkey_bytes: &[u8]) -> Result<[u8; 20], Box<dyn std::error::Error>> {
+    let public_key = PublicKey::from_sec1_bytes(pubkey_bytes)
+        .map_err(|_| "Invalid public key: not a valid secp256k1 point")?;
+    let uncompressed = public_key.to_encoded_point(false);
+
+    let hash = Keccak256::digest(&uncompressed.as_bytes()[1..]);
+    let mut account_id = [0u8; 20];
+    account_id.copy_from_slice(&hash[12..32]);
+    Ok(account_id)
+}
+
+/// Encodes a secp256k1 public key as an ethsecp256k1 (EVM-style) bech32 address
+///
+/// # Arguments
+/// * `pubkey_bytes` - A compressed or uncompressed SEC1 public key
+/// * `hrp` - The bech32 human-readable part (e.g. "mantra")
+///
+/// # Returns
+/// * `String` - The bech32-encoded address of the keccak256-derived account ID
+pub fn evm_public_key_to_address(
+    pubkey_bytes: &[u8],
+    hrp: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    encode_bech32(hrp, &evm_account_id(pubkey_bytes)?)
+}
+
+/// Renders a 20-byte account ID as an EIP-55 checksummed `0x...` hex address
+///
+/// Each hex letter is uppercased when the corresponding nibble of
+/// keccak256(lowercase hex) is 8 or higher, as displayed by MetaMask.
+pub fn to_checksum_hex_address(account_id: &[u8; 20]) -> String {
+    let lower_hex = hex::encode(account_id);
+    let hash = Keccak256::digest(lower_hex.as_bytes());
+
+    let mut checksummed = String::with_capacity(42);
+    checksummed.push_str("0x");
+    for (i, ch) in lower_hex.chars().enumerate() {
+        let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+        if ch.is_ascii_alphabetic() && nibble >= 8 {
+            checksummed.push(ch.to_ascii_uppercase());
+        } else {
+            checksummed.push(ch);
+        }
+    }
+    checksummed
+}
+
+/// Derives an account address from raw public key bytes
+///
+/// This applies the SHA256 → RIPEMD160 → bech32 step to a public key the
+/// caller already has (e.g. from a hardware wallet), without any secret
+/// material. Uncompressed keys are compressed first, since Cosmos account
+/// addresses are always computed over the 33-byte compressed form.
+///
+/// # Arguments
+/// * `pubkey` - A 33-byte compressed or 65-byte uncompressed secp256k1 public key
+/// * `hrp` - The bech32 human-readable part (e.g. "mantra")
+/// * `variant` - Optional checksum variant (defaults to bech32, as used by Cosmos SDK chains)
+///
+/// # Returns
+/// * `String` - The bech32-encoded address, or an error if the key is not a valid curve point
+///
+/// # Example
+/// ```javascript
+/// const address = address_from_pubkey(pubkeyBytes, "mantra", undefined);
+/// console.log(`Address: ${address}`);
+/// ```
+#[wasm_bindgen]
+pub fn address_from_pubkey(
+    pubkey: &[u8],
+    hrp: &str,
+    variant: Option<Bech32Variant>,
+) -> Result<String, VanityError> {
+    let public_key = PublicKey::from_sec1_bytes(pubkey).map_err(|_| {
+        VanityError::InvalidKey("Invalid public key: not a valid secp256k1 point".to_string())
+    })?;
+    let compressed = public_key.to_encoded_point(true);
+
+    encode_bech32_with_variant(
+        hrp,
+        &hash160(compressed.as_bytes()),
+        variant.unwrap_or_default(),
+    )
+    .map_err(|e| VanityError::DerivationFailed(format!("Error encoding address: {}", e)))
+}
+
+/// Re-encodes a bech32 address under another human-readable part
+///
+/// The account bytes are kept as-is and only the prefix (and therefore the
+/// checksum) changes, so `mantra1...` and the resulting `cosmos1...` address
+/// belong to the same key. This only gives the right address on chains that
+/// use the same coin type and account scheme.
+///
+/// # Arguments
+/// * `address` - A valid bech32 address (e.g. `mantra1...`)
+/// * `new_hrp` - The human-readable part to encode under (e.g. "cosmos")
+/// * `variant` - Optional checksum variant for the result; defaults to the variant of `address`
+///
+/// # Returns
+/// * `String` - The address under the new prefix, or an error if either input
+///   is invalid, the address does not hold 20 or 32 bytes, or the result would
+///   exceed 90 characters
+///
+/// # Example
+/// ```javascript
+/// const cosmosAddress = convert_address_prefix("mantra1...", "cosmos", undefined);
+/// console.log(`Cosmos Hub address: ${cosmosAddress}`);
+/// ```
+#[wasm_bindgen]
+pub fn convert_address_prefix(
+    address: &str,
+    new_hrp: &str,
+    variant: Option<Bech32Variant>,
+) -> Result<String, VanityError> {
+    let new_hrp = new_hrp.trim();
+    check_hrp(new_hrp)?;
+
+    let (_, data, source_variant) = bech32_decode(address.trim())
+        .map_err(|e| VanityError::InvalidAddress(format!("Invalid bech32 address: {}", e)))?;
+    let data_length = Vec::<u8>::from_base32(&data)
+        .map_err(|e| VanityError::InvalidAddress(format!("Invalid address data: {}", e)))?
+        .len();
+    if !matches!(data_length, 20 | 32) {
+        return Err(VanityError::InvalidAddress(format!(
+            "Address holds {} bytes (expected 20 for accounts or 32 for contracts)",
+            data_length
+        )));
+    }
+    let new_hrp = new_hrp.to_lowercase();
+    if let Some(error) = address_length_error(&new_hrp, data_length) {
+        return Err(VanityError::InvalidHrp(error));
+    }
+    let variant = variant.map_or(source_variant, Variant::from);
+
+    bech32_encode(&new_hrp, data, variant)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error encoding address: {}", e)))
+}
+
+/// Decodes a bech32 string and re-encodes its data under another HRP
+///
+/// The checksum variant (bech32 or bech32m) of the input is kept.
+pub fn reencode_bech32(address: &str, hrp: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let (_, data, variant) = bech32_decode(address)?;
+    Ok(bech32_encode(hrp, data, variant)?)
+}
+
+/// Decodes a bech32 or bech32m address into its HRP and raw data bytes
+pub fn decode_bech32(address: &str) -> Result<(String, Vec<u8>), Box<dyn std::error::Error>> {
+    let (hrp, data, _) = bech32_decode(address)?;
+    Ok((hrp, Vec::<u8>::from_base32(&data)?))
+}
+
+/// Converts an account address to the validator operator form of the same account
+///
+/// The Cosmos SDK appends "valoper" to the account HRP, so `mantra1...` becomes
+/// `mantravaloper1...` with the same 20-byte account ID.
+pub fn operator_address(address: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let (hrp, _, _) = bech32_decode(address)?;
+    reencode_bech32(address, &format!("{}valoper", hrp))
+}
+
+/// Encodes a compressed secp256k1 public key in legacy bech32 form (e.g. `mantrapub1...`)
+///
+/// The data is the amino encoding of the key (type prefix, length byte, key)
+/// under the account HRP with "pub" appended, as older SDK releases displayed it.
+pub fn bech32_public_key(
+    pubkey_bytes: &[u8; 33],
+    hrp: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mut amino = SECP256K1_AMINO_PREFIX.to_vec();
+    amino.push(pubkey_bytes.len() as u8);
+    amino.extend_from_slice(pubkey_bytes);
+
+    encode_bech32(&format!("{}pub", hrp), &amino)
+}
+
+/// Computes the 20-byte Tendermint address of a consensus public key
+///
+/// CometBFT hashes consensus keys differently from account keys: ed25519 keys
+/// (32 bytes) use the first 20 bytes of SHA256, while secp256k1 keys (33-byte
+/// compressed) use RIPEMD160(SHA256), like accounts.
+///
+/// # Arguments
+/// * `pubkey` - A 32-byte ed25519 or 33-byte compressed secp256k1 public key
+///
+/// # Returns
+/// * `[u8; 20]` - The consensus address bytes
+pub fn consensus_address_bytes(pubkey: &[u8]) -> Result<[u8; 20], Box<dyn std::error::Error>> {
+    match pubkey.len() {
+        32 => {
+            let key_bytes: [u8; 32] = pubkey.try_into()?;
+            ed25519_dalek::VerifyingKey::from_bytes(&key_bytes)
+                .map_err(|_| "Invalid public key: not a valid ed25519 point")?;
+
+            let hash = Sha256::digest(pubkey);
+            let mut address = [0u8; 20];
+            address.copy_from_slice(&hash[..20]);
+            Ok(address)
+        }
+        33 => {
+            PublicKey::from_sec1_bytes(pubkey)
+                .map_err(|_| "Invalid public key: not a valid secp256k1 point")?;
+            Ok(hash160(pubkey))
+        }
+        len => Err(format!(
+            "Invalid consensus public key length: {} bytes (expected 32 for ed25519 or 33 for secp256k1)",
+            len
+        )
+        .into()),
+    }
+}
+
+/// Encodes a consensus public key as a validator consensus address
+///
+/// The Cosmos SDK appends "valcons" to the account HRP, so for MANTRA the
+/// result is a `mantravalcons1...` address.
+pub fn consensus_address(pubkey: &[u8], hrp: &str) -> Result<String, Box<dyn std::error::Error>> {
+    encode_bech32(
+        &format!("{}valcons", hrp),
+        &consensus_address_bytes(pubkey)?,
+    )
+}
+
+/// Derives a validator consensus address from a consensus public key
+///
+/// This is the address shown for a validator's signing key (e.g. in
+/// `query slashing signing-info`), computed offline from the public key in
+/// `priv_validator_key.json` or `show-validator` output.
+///
+/// # Arguments
+/// * `pubkey` - A 32-byte ed25519 or 33-byte compressed secp256k1 consensus public key
+/// * `hrp` - The account HRP (e.g. "mantra"); "valcons" is appended
+///
+/// # Returns
+/// * `String` - The bech32 consensus address (e.g. `mantravalcons1...`), or an error if the key is invalid
+///
+/// # Example
+/// ```javascript
+/// const pubkey = Uint8Array.from(atob(validator.pub_key.value), (c) => c.charCodeAt(0));
+/// console.log(consensus_address_from_pubkey(pubkey, "mantra"));
+/// ```
+#[wasm_bindgen]
+pub fn consensus_address_from_pubkey(pubkey: &[u8], hrp: &str) -> Result<String, VanityError> {
+    check_hrp(hrp)?;
+
+    consensus_address(pubkey, &hrp.to_lowercase()).map_err(|e| {
+        VanityError::DerivationFailed(format!("Error encoding consensus address: {}", e))
+    })
+}
+
+/// Decodes a bech32 address into its raw account ID bytes
+///
+/// This is the canonical binary address (20 bytes for accounts, 32 for
+/// contracts and other derived accounts) used by indexers and by CosmWasm
+/// `CanonicalAddr`. The HRP is not checked, so any Cosmos chain's address works.
+///
+/// # Arguments
+/// * `address` - A bech32 address (e.g. `mantra1...`)
+///
+/// # Returns
+/// * `Vec<u8>` - The account ID bytes, or an error if the address is not valid bech32
+///
+/// # Example
+/// ```javascript
+/// const accountId = account_id_from_address("mantra1...");
+/// console.log(`Account ID: ${Array.from(accountId, (b) => b.toString(16).padStart(2, "0")).join("")}`);
+/// ```
+#[wasm_bindgen]
+pub fn account_id_from_address(address: &str) -> Result<Vec<u8>, VanityError> {
+    decode_bech32(address.trim())
+        .map(|(_, data)| data)
+        .map_err(|e| VanityError::InvalidAddress(format!("Invalid bech32 address: {}", e)))
+}
+
+/// Renders a bech32 address in its all-uppercase form
+///
+/// Bech32 allows an address to be written entirely in uppercase (never mixed
+/// case) and it decodes to the same data. Uppercase text fits QR alphanumeric
+/// mode, which yields a noticeably smaller, easier to scan code. Wallets should
+/// still display and store the lowercase form.
+///
+/// # Arguments
+/// * `address` - A valid bech32 address in either case
+///
+/// # Returns
+/// * `String` - The uppercase address, or an error if the address is not valid bech32
+///
+/// # Example
+/// ```javascript
+/// const upper = uppercase_address(keypair.address); // "MANTRA1..."
+/// ```
+#[wasm_bindgen]
+pub fn uppercase_address(address: &str) -> Result<String, VanityError> {
+    let address = address.trim();
+    bech32_decode(address)
+        .map_err(|e| VanityError::InvalidAddress(format!("Invalid bech32 address: {}", e)))?;
+
+    Ok(address.to_uppercase())
+}
+
+/// Result of validating a user-supplied address
+#[wasm_bindgen]
+pub struct AddressValidation {
+    valid: bool,
+    error: Option<String>,
+    error_position: Option<u32>,
+    hrp: Option<String>,
+    data_length: Option<u32>,
+    variant: Option<Bech32Variant>,
+}
+
+#[wasm_bindgen]
+impl AddressValidation {
+    /// Gets whether the address is a well-formed account or contract address
+    #[wasm_bindgen(getter)]
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Gets the error message for an invalid address
+    #[wasm_bindgen(getter)]
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    /// Gets the zero-based character position of an invalid character, if that is the problem
+    #[wasm_bindgen(getter)]
+    pub fn error_position(&self) -> Option<u32> {
+        self.error_position
+    }
+
+    /// Gets the human-readable part, once the address has been split
+    #[wasm_bindgen(getter)]
+    pub fn hrp(&self) -> Option<String> {
+        self.hrp.clone()
+    }
+
+    /// Gets the decoded data length in bytes, once the checksum has passed
+    #[wasm_bindgen(getter)]
+    pub fn data_length(&self) -> Option<u32> {
+        self.data_length
+    }
+
+    /// Gets the detected checksum variant, once the checksum has passed
+    #[wasm_bindgen(getter)]
+    pub fn variant(&self) -> Option<Bech32Variant> {
+        self.variant
+    }
+}
+
+impl AddressValidation {
+    fn invalid(error: impl Into<String>, error_position: Option<usize>, hrp: Option<&str>) -> Self {
+        AddressValidation {
+            valid: false,
+            error: Some(error.into()),
+            error_position: error_position.map(|position| position as u32),
+            hrp: hrp.map(str::to_string),
+            data_length: None,
+            variant: None,
+        }
+    }
+}
+
+/// Fully validates a bech32 address
+///
+/// Checks, in order: overall length and case, the `1` separator, the HRP
+/// characters, the data charset (reporting the first bad character), the
+/// checksum (bech32 or bech32m, auto-detected and reported), the data length (20-byte accounts or 32-byte contracts) and,
+/// when given, that the HRP is the expected one.
+///
+/// # Arguments
+/// * `address` - The address to validate (e.g. pasted by a user)
+/// * `expected_hrp` - Optional HRP the address must use (e.g. "mantra")
+///
+/// # Returns
+/// * `AddressValidation` - The structured verdict
+///
+/// # Example
+/// ```javascript
+/// const result = validate_address(input, "mantra");
+/// if (!result.valid) console.log(result.error, result.error_position);
+/// ```
+#[wasm_bindgen]
+pub fn validate_address(address: &str, expected_hrp: Option<String>) -> AddressValidation {
+    let address = address.trim();
+
+    if address.len() > MAX_BECH32_LENGTH {
+        return AddressValidation::invalid(
+            format!(
+                "Address is {} characters long (maximum {})",
+                address.len(),
+                MAX_BECH32_LENGTH
+            ),
+            None,
+            None,
+        );
+    }
+    if address.chars().any(|ch| ch.is_ascii_lowercase())
+        && address.chars().any(|ch| ch.is_ascii_uppercase())
+    {
+        return AddressValidation::invalid("Address mixes upper and lower case", None, None);
+    }
+
+    let lower = address.to_lowercase();
+    // Bech32 splits at the last '1', but Cosmos HRPs never contain one, so the
+    // first '1' is used and a mistyped '1' in the data is reported precisely
+    let Some(separator) = lower.find('1') else {
+        return AddressValidation::invalid("Address has no \"1\" separator", None, None);
+    };
+    let (hrp, data) = (&lower[..separator], &lower[separator + 1..]);
+
+    if let Some(message) = hrp_error(hrp) {
+        return AddressValidation::invalid(message, None, None);
+    }
+    // The HRP is ASCII, so counting characters from the separator gives the position
+    if let Some((offset, ch)) = data
+        .chars()
+        .enumerate()
+        .find(|&(_, ch)| !BECH32_CHARSET.contains(ch))
+    {
+        let position = separator + 1 + offset;
+        return AddressValidation::invalid(
+            format!("Invalid character {:?} at position {}", ch, position),
+            Some(position),
+            Some(hrp),
+        );
+    }
+    if data.len() < 6 {
+        return AddressValidation::invalid(
+            "Address is too short to hold a checksum",
+            None,
+            Some(hrp),
+        );
+    }
+
+    let (data_length, variant) = match bech32_decode(&lower) {
+        Ok((_, data, variant)) => match Vec::<u8>::from_base32(&data) {
+            Ok(bytes) => (bytes.len(), Bech32Variant::from(variant)),
+            Err(e) => {
+                return AddressValidation::invalid(format!("Invalid data: {}", e), None, Some(hrp))
+            }
+        },
+        Err(_) => {
+            return AddressValidation::invalid(
+                "Checksum does not match; the address has a typo",
+                None,
+                Some(hrp),
+            )
+        }
+    };
+
+    let mut validation = AddressValidation {
+        valid: true,
+        error: None,
+        error_position: None,
+        hrp: Some(hrp.to_string()),
+        data_length: Some(data_length as u32),
+        variant: Some(variant),
+    };
+    if !matches!(data_length, 20 | 32) {
+        validation.valid = false;
+        validation.error = Some(format!(
+            "Address holds {} bytes (expected 20 for accounts or 32 for contracts)",
+            data_length
+        ));
+    } else if let Some(expected) = expected_hrp.map(|expected| expected.trim().to_lowercase()) {
+        if hrp != expected {
+            validation.valid = false;
+            validation.error = Some(format!(
+                "Expected a {}1... address, got {}1...",
+                expected, hrp
+            ));
+        }
+    }
+    validation
+}
+
+/// Suggests corrected addresses for an address that fails its checksum
+///
+/// The bech32 BCH code detects every single-character error, so each
+/// candidate is produced by one edit of the data part — substituting one
+/// character or swapping two adjacent ones — and kept only if its checksum
+/// verifies and it still holds a 20- or 32-byte account ID. The HRP is never
+/// changed. Substitutions are listed before transpositions.
+///
+/// # Arguments
+/// * `address` - The mistyped bech32 address
+///
+/// # Returns
+/// * `Vec<String>` - Plausible corrected addresses; empty if the address is
+///   already valid or no single edit repairs it
+///
+/// # Example
+/// ```javascript
+/// const fixes = suggest_address_corrections(input);
+/// if (fixes.length > 0) console.log(`Did you mean ${fixes[0]}?`);
+/// ```
+#[wasm_bindgen]
+pub fn suggest_address_corrections(address: &str) -> Vec<String> {
+    let lower = address.trim().to_lowercase();
+    if !lower.is_ascii() || lower.len() > MAX_BECH32_LENGTH || is_account_address(&lower) {
+        return Vec::new();
+    }
+    // Split at the first '1' as validate_address does, so a mistyped '1' in
+    // the data part is treated as a substitution candidate
+    let Some(separator) = lower.find('1') else {
+        return Vec::new();
+    };
+    if hrp_error(&lower[..separator]).is_some() {
+        return Vec::new();
+    }
+
+    let mut chars: Vec<u8> = lower.bytes().collect();
+    let mut corrections = Vec::new();
+    let mut consider = |candidate: &[u8]| {
+        let candidate = String::from_utf8_lossy(candidate).into_owned();
+        if is_account_address(&candidate) && !corrections.contains(&candidate) {
+            corrections.push(candidate);
+        }
+    };
+
+    for position in separator + 1..chars.len() {
+        let original = chars[position];
+        for replacement in BECH32_CHARSET.bytes().filter(|&ch| ch != original) {
+            chars[position] = replacement;
+            consider(&chars);
+        }
+        chars[position] = original;
+    }
+    for position in separator + 1..chars.len().saturating_sub(1) {
+        if chars[position] != chars[position + 1] {
+            chars.swap(position, position + 1);
+            consider(&chars);
+            chars.swap(position, position + 1);
+        }
+    }
+
+    corrections
+}
+
+/// Checks whether a string decodes to a 20- or 32-byte bech32 account ID
+fn is_account_address(address: &str) -> bool {
+    decode_bech32(address).is_ok_and(|(_, bytes)| matches!(bytes.len(), 20 | 32))
+}
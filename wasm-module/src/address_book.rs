@@ -0,0 +1,318 @@
+//! Labeled address book of vanity results
+//!
+//! Long hunts produce many addresses worth keeping. The address book holds
+//! them with a label, the derivation path and, optionally, the mnemonic as an
+//! `encrypt_mnemonic()` blob, and saves the whole collection as one encrypted
+//! JSON document instead of a spreadsheet of plaintext phrases.
+
+use crate::address::decode_bech32;
+use crate::error::VanityError;
+use crate::hd::{format_derivation_path, parse_derivation_path};
+use crate::keystore::{check_password, compact_mnemonic_blob, open_blob, seal_blob, KdfOptions};
+use crate::schema::{upgrade_document, SCHEMA_VERSION};
+use crate::Keypair;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use wasm_bindgen::prelude::*;
+
+/// Content tag of encrypted address book blobs
+const ADDRESS_BOOK_CONTENT: &str = "address_book";
+
+/// One labeled address
+#[wasm_bindgen]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AddressBookEntry {
+    label: String,
+    address: String,
+    derivation_path: Option<String>,
+    encrypted_mnemonic: Option<String>,
+}
+
+#[wasm_bindgen]
+impl AddressBookEntry {
+    /// Gets the label
+    #[wasm_bindgen(getter)]
+    pub fn label(&self) -> String {
+        self.label.clone()
+    }
+
+    /// Gets the bech32 address (lowercase)
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// Gets the derivation path, if recorded
+    #[wasm_bindgen(getter)]
+    pub fn derivation_path(&self) -> Option<String> {
+        self.derivation_path.clone()
+    }
+
+    /// Gets the `encrypt_mnemonic()` blob for this address, if stored
+    ///
+    /// Decrypt it with `decrypt_mnemonic()`; its password may differ from
+    /// the address book's.
+    #[wasm_bindgen(getter)]
+    pub fn encrypted_mnemonic(&self) -> Option<String> {
+        self.encrypted_mnemonic.clone()
+    }
+
+    /// Checks whether the label or address contains the query, ignoring case
+    fn matches(&self, query: &str) -> bool {
+        self.label.to_lowercase().contains(query) || self.address.contains(query)
+    }
+}
+
+/// Saved form of an address book
+#[derive(Serialize, Deserialize)]
+struct AddressBookRecord {
+    schema_version: u64,
+    entries: Vec<AddressBookEntry>,
+}
+
+/// A collection of labeled addresses, at most one entry per address
+#[wasm_bindgen]
+#[derive(Clone, Default)]
+pub struct AddressBook {
+    entries: Vec<AddressBookEntry>,
+}
+
+#[wasm_bindgen]
+impl AddressBook {
+    /// Creates an empty address book
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> AddressBook {
+        AddressBook::default()
+    }
+
+    /// Gets the number of entries
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Gets all entries in insertion order
+    pub fn entries(&self) -> Vec<AddressBookEntry> {
+        self.entries.clone()
+    }
+
+    /// Adds a labeled address unless the address is already in the book
+    ///
+    /// Addresses are compared case-insensitively, so `MANTRA1...` and
+    /// `mantra1...` count as the same entry.
+    ///
+    /// # Arguments
+    /// * `label` - A name for the address (must not be empty)
+    /// * `address` - The bech32 address
+    /// * `derivation_path` - Optional derivation path (e.g. "m/44'/118'/0'/0/0")
+    /// * `encrypted_mnemonic` - Optional `encrypt_mnemonic()` blob holding the phrase
+    ///
+    /// # Returns
+    /// * `bool` - true if the entry was added, false if the address was already
+    ///   present, or an error if an argument is invalid
+    ///
+    /// # Example
+    /// ```javascript
+    /// const book = new AddressBook();
+    /// book.add("treasury", keypair.address, keypair.derivation_path, encrypt_mnemonic(keypair.reveal_mnemonic(false), pw, undefined));
+    /// ```
+    pub fn add(
+        &mut self,
+        label: &str,
+        address: &str,
+        derivation_path: Option<String>,
+        encrypted_mnemonic: Option<String>,
+    ) -> Result<bool, VanityError> {
+        let entry = new_entry(label, address, derivation_path, encrypted_mnemonic)?;
+        Ok(self.insert(entry))
+    }
+
+    /// Adds a keypair's address and derivation path under a label
+    ///
+    /// The mnemonic is not stored; use `add()` with an encrypted blob to keep it.
+    ///
+    /// # Arguments
+    /// * `label` - A name for the address (must not be empty)
+    /// * `keypair` - The keypair to record
+    ///
+    /// # Returns
+    /// * `bool` - true if the entry was added, false if the address was already present
+    pub fn add_keypair(&mut self, label: &str, keypair: &Keypair) -> Result<bool, VanityError> {
+        self.add(label, &keypair.address, keypair.derivation_path(), None)
+    }
+
+    /// Removes the entry for an address
+    ///
+    /// # Returns
+    /// * `bool` - true if an entry was removed
+    pub fn remove(&mut self, address: &str) -> bool {
+        let address = address.to_lowercase();
+        let before = self.entries.len();
+        self.entries.retain(|entry| entry.address != address);
+        self.entries.len() != before
+    }
+
+    /// Looks up the entry for an address
+    pub fn get(&self, address: &str) -> Option<AddressBookEntry> {
+        let address = address.to_lowercase();
+        self.entries
+            .iter()
+            .find(|entry| entry.address == address)
+            .cloned()
+    }
+
+    /// Finds entries whose label or address contains the query, ignoring case
+    ///
+    /// # Example
+    /// ```javascript
+    /// const hits = book.search("cafe");
+    /// ```
+    pub fn search(&self, query: &str) -> Vec<AddressBookEntry> {
+        let query = query.trim().to_lowercase();
+        self.entries
+            .iter()
+            .filter(|entry| entry.matches(&query))
+            .cloned()
+            .collect()
+    }
+
+    /// Adds every entry of another book whose address is not yet present
+    ///
+    /// # Returns
+    /// * `usize` - The number of entries added
+    pub fn merge(&mut self, other: &AddressBook) -> usize {
+        other
+            .entries
+            .iter()
+            .filter(|entry| self.insert((*entry).clone()))
+            .count()
+    }
+
+    /// Encrypts the address book as a JSON blob
+    ///
+    /// Uses the same KDF choices and AES-256-GCM as `encrypt_mnemonic()`,
+    /// tagged `"content": "address_book"`.
+    ///
+    /// # Arguments
+    /// * `password` - The password protecting the blob (at least 8 characters)
+    /// * `kdf` - Optional KDF settings (defaults to Argon2id with 64 MiB and 3 passes)
+    ///
+    /// # Returns
+    /// * `String` - The encrypted address book as JSON, or an error for a weak password
+    ///
+    /// # Example
+    /// ```javascript
+    /// localStorage.setItem("address-book", book.to_encrypted_json(password, undefined));
+    /// ```
+    pub fn to_encrypted_json(
+        &self,
+        password: &str,
+        kdf: Option<KdfOptions>,
+    ) -> Result<String, VanityError> {
+        check_password(password)?;
+
+        let record = AddressBookRecord {
+            schema_version: SCHEMA_VERSION,
+            entries: self.entries.clone(),
+        };
+        let plaintext = serde_json::to_vec(&record).map_err(|e| {
+            VanityError::DerivationFailed(format!("Error serializing address book: {}", e))
+        })?;
+        seal_blob(&plaintext, password, kdf, Some(ADDRESS_BOOK_CONTENT))
+            .map(|blob| blob.to_string())
+            .map_err(VanityError::DerivationFailed)
+    }
+
+    /// Decrypts an address book written by `to_encrypted_json()`
+    ///
+    /// # Arguments
+    /// * `blob` - The encrypted address book JSON
+    /// * `password` - The password the book was encrypted with
+    ///
+    /// # Returns
+    /// * `AddressBook` - The address book, or an error for a wrong password or malformed blob
+    ///
+    /// # Example
+    /// ```javascript
+    /// const book = AddressBook.from_encrypted_json(localStorage.getItem("address-book"), password);
+    /// ```
+    pub fn from_encrypted_json(blob: &str, password: &str) -> Result<AddressBook, VanityError> {
+        open_address_book(blob, password).map_err(VanityError::DecryptionFailed)
+    }
+}
+
+impl AddressBook {
+    /// Appends an entry unless its address is already present
+    fn insert(&mut self, entry: AddressBookEntry) -> bool {
+        if self
+            .entries
+            .iter()
+            .any(|existing| existing.address == entry.address)
+        {
+            return false;
+        }
+        self.entries.push(entry);
+        true
+    }
+}
+
+/// Validates and normalizes the fields of a new entry
+fn new_entry(
+    label: &str,
+    address: &str,
+    derivation_path: Option<String>,
+    encrypted_mnemonic: Option<String>,
+) -> Result<AddressBookEntry, VanityError> {
+    let label = label.trim();
+    if label.is_empty() {
+        return Err(VanityError::InvalidInput(
+            "Label must not be empty".to_string(),
+        ));
+    }
+    let address = address.trim().to_lowercase();
+    decode_bech32(&address)
+        .map_err(|e| VanityError::InvalidAddress(format!("Invalid address: {}", e)))?;
+    let derivation_path = derivation_path
+        .map(|path| parse_derivation_path(&path).map(|path| format_derivation_path(&path)))
+        .transpose()
+        .map_err(|e| VanityError::InvalidPath(format!("Invalid derivation path: {}", e)))?;
+    let encrypted_mnemonic = encrypted_mnemonic
+        .map(|blob| compact_mnemonic_blob(&blob))
+        .transpose()
+        .map_err(|e| VanityError::InvalidInput(format!("Invalid encrypted mnemonic: {}", e)))?;
+
+    Ok(AddressBookEntry {
+        label: label.to_string(),
+        address,
+        derivation_path,
+        encrypted_mnemonic,
+    })
+}
+
+/// Parses, decrypts and checks an encrypted address book
+fn open_address_book(blob: &str, password: &str) -> Result<AddressBook, String> {
+    let blob: Value =
+        serde_json::from_str(blob).map_err(|e| format!("Invalid address book JSON: {}", e))?;
+    if blob["content"] != ADDRESS_BOOK_CONTENT {
+        return Err("Blob is not an address book".to_string());
+    }
+
+    let plaintext = open_blob(blob, password)?;
+    let record = serde_json::from_slice(&plaintext)
+        .map_err(|e| format!("Address book is not valid JSON: {}", e))?;
+    let record: AddressBookRecord = serde_json::from_value(upgrade_document(record)?)
+        .map_err(|e| format!("Malformed address book: {}", e))?;
+
+    let mut book = AddressBook::new();
+    for entry in record.entries {
+        let entry = new_entry(
+            &entry.label,
+            &entry.address,
+            entry.derivation_path,
+            entry.encrypted_mnemonic,
+        )
+        .map_err(|e| e.to_string())?;
+        book.insert(entry);
+    }
+    Ok(book)
+}
@@ -0,0 +1,55 @@
+//! Audit hook for sensitive exports
+//!
+//! Exports that hand out raw private material are rare and deliberate, so an
+//! embedding app may want a record of each one. A JavaScript callback can be
+//! registered to receive an event before every such export; it never sees
+//! the secret itself.
+
+use crate::error::VanityError;
+use crate::schema::SCHEMA_VERSION;
+use js_sys::Function;
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+thread_local! {
+    static EXPORT_AUDIT_HOOK: RefCell<Option<Function>> = const { RefCell::new(None) };
+}
+
+/// Registers a callback that is told about every sensitive export
+///
+/// The callback receives one JSON string argument, e.g.
+/// `{"schema_version":2,"event":"private_key_hex","address":"mantra1..."}` (the address is null
+/// when the export is not tied to one account). It runs before the secret is
+/// returned; if it throws, the export is aborted, so a failing audit log
+/// fails closed. Pass `undefined` to remove the hook.
+///
+/// # Arguments
+/// * `hook` - The callback, or undefined to clear it
+///
+/// # Example
+/// ```javascript
+/// set_export_audit_hook((event) => auditLog.push({ at: Date.now(), ...JSON.parse(event) }));
+/// ```
+#[wasm_bindgen]
+pub fn set_export_audit_hook(hook: Option<Function>) {
+    EXPORT_AUDIT_HOOK.with(|cell| *cell.borrow_mut() = hook);
+}
+
+/// Reports a sensitive export to the audit hook, if one is registered
+pub fn audit_export(event: &str, address: Option<&str>) -> Result<(), VanityError> {
+    let Some(hook) = EXPORT_AUDIT_HOOK.with(|cell| cell.borrow().clone()) else {
+        return Ok(());
+    };
+
+    let record = serde_json::json!({
+        "schema_version": SCHEMA_VERSION,
+        "event": event,
+        "address": address,
+    })
+    .to_string();
+    hook.call1(&JsValue::NULL, &JsValue::from_str(&record))
+        .map(|_| ())
+        .map_err(|_| {
+            VanityError::AuditHookFailed("Export audit hook failed; export aborted".to_string())
+        })
+}
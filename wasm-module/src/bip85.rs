@@ -0,0 +1,106 @@
+//! BIP85 deterministic entropy from a master key
+//!
+//! BIP85 derives child entropy from a hardened path below the master key,
+//! so a single backed-up mnemonic can deterministically reproduce any number
+//! of independent child mnemonics (e.g. one per vanity hunt or per app).
+
+use crate::audit::audit_export;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::hd::{ExtendedPrivateKey, HARDENED_OFFSET};
+use crate::mnemonic::parse_mnemonic;
+use bip39::{Language, Mnemonic};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+/// BIP85 application number for BIP39 mnemonics
+const BIP39_APPLICATION: u32 = 39;
+
+/// BIP85 language code for the English wordlist
+const ENGLISH_LANGUAGE_CODE: u32 = 0;
+
+/// Derives a BIP85 child mnemonic from a master mnemonic or root xprv
+///
+/// The child entropy is taken from path
+/// m/83696968'/39'/0'/{words}'/{index}' (English wordlist), so the same master
+/// and index always reproduce the same child mnemonic, and different indexes
+/// give unrelated wallets.
+///
+/// # Arguments
+/// * `master` - The master BIP39 mnemonic, or a root `xprv...` key
+/// * `index` - The child index (any value below 2^31)
+/// * `words` - Number of words in the child mnemonic (12, 15, 18, 21 or 24)
+///
+/// # Returns
+/// * `String` - The child mnemonic, or an error if the inputs are invalid or
+///   hardened mode is on
+///
+/// # Example
+/// ```javascript
+/// const child = derive_bip85_mnemonic("word1 word2 ... word24", 0, 24);
+/// console.log(`Child mnemonic: ${child}`);
+/// ```
+#[wasm_bindgen]
+pub fn derive_bip85_mnemonic(master: &str, index: u32, words: u32) -> Result<String, VanityError> {
+    check_plaintext_secrets("a BIP85 child mnemonic")?;
+    let root = parse_master(master)?;
+
+    let child = bip85_mnemonic(&root, index, words)
+        .map_err(|e| VanityError::InvalidInput(format!("Error deriving BIP85 mnemonic: {}", e)))?;
+    audit_export("bip85_mnemonic", None)?;
+    Ok(child)
+}
+
+/// Parses the master input as a root xprv or a BIP39 mnemonic
+fn parse_master(master: &str) -> Result<ExtendedPrivateKey, VanityError> {
+    let trimmed = master.trim();
+    if trimmed.starts_with("xprv") {
+        return ExtendedPrivateKey::from_root_xprv(trimmed)
+            .map_err(|e| VanityError::InvalidKey(format!("Invalid xprv: {}", e)));
+    }
+
+    let mnemonic = parse_mnemonic(trimmed)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    ExtendedPrivateKey::master(&*Zeroizing::new(mnemonic.to_seed("")))
+        .map_err(|e| VanityError::DerivationFailed(format!("Error creating master key: {}", e)))
+}
+
+/// Derives the BIP85 BIP39-application child mnemonic below a root key
+fn bip85_mnemonic(
+    root: &ExtendedPrivateKey,
+    index: u32,
+    words: u32,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let entropy_length = match words {
+        12 => 16,
+        15 => 20,
+        18 => 24,
+        21 => 28,
+        24 => 32,
+        _ => return Err(format!("Unsupported word count: {}", words).into()),
+    };
+    if index >= HARDENED_OFFSET {
+        return Err(format!("Index out of range: {}", index).into());
+    }
+
+    let path = [
+        83696968 + HARDENED_OFFSET,
+        BIP39_APPLICATION + HARDENED_OFFSET,
+        ENGLISH_LANGUAGE_CODE + HARDENED_OFFSET,
+        words + HARDENED_OFFSET,
+        index + HARDENED_OFFSET,
+    ];
+    let derived = root.derive_path(&path)?;
+
+    // BIP85 entropy: HMAC-SHA512(key = "bip-entropy-from-k", msg = derived private key)
+    let mut mac = Hmac::<Sha512>::new_from_slice(b"bip-entropy-from-k")
+        .map_err(|e| format!("Failed to create HMAC: {}", e))?;
+    mac.update(&derived.private_key_bytes());
+    let entropy = mac.finalize().into_bytes();
+
+    let mnemonic = Mnemonic::from_entropy_in(Language::English, &entropy[..entropy_length])
+        .map_err(|e| format!("Failed to generate mnemonic: {}", e))?;
+    Ok(mnemonic.to_string())
+}
@@ -0,0 +1,127 @@
+//! Memorized (brain) wallets stretched with Argon2id
+//!
+//! **Brain wallets are dangerous.** Humans choose guessable phrases, and every
+//! address derived from a phrase is a standing target: attackers grind common
+//! phrases around the clock, and a wallet found later is drained at once. A
+//! random mnemonic from `generate_random_keypair()` is always the better choice.
+//!
+//! Some users insist anyway, and would otherwise hash a phrase with a single
+//! SHA-256 in a script. This mode at least makes each guess expensive and
+//! per-user: the phrase is stretched with Argon2id (256 MiB, 4 passes) under a
+//! salt the user also remembers (such as an email address), and the 32-byte
+//! result becomes the entropy of an ordinary 24-word mnemonic. That mnemonic
+//! can be written down and imported into any wallet, so the phrase is not the
+//! only way back in.
+
+use crate::address::check_hrp;
+use crate::crosscheck::paranoid_checked;
+use crate::error::VanityError;
+use crate::{hrp_or_default, keypair_from_mnemonic, AccountScheme, Keypair};
+use argon2::{Algorithm, Argon2, Params, Version};
+use bip39::Mnemonic;
+use unicode_normalization::UnicodeNormalization;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+/// Argon2id memory cost in KiB (256 MiB)
+const BRAINWALLET_MEMORY_KIB: u32 = 256 * 1024;
+
+/// Argon2id passes over memory
+const BRAINWALLET_PASSES: u32 = 4;
+
+/// Argon2id lanes; WASM runs single-threaded, so extra lanes would only help an attacker
+const BRAINWALLET_LANES: u32 = 1;
+
+/// Domain separator prepended to the user's salt
+const BRAINWALLET_SALT_PREFIX: &[u8] = b"vanity-mantra brainwallet v1";
+
+/// Shortest passphrase accepted, in characters after normalization
+const MIN_PASSPHRASE_CHARS: usize = 20;
+
+/// Shortest salt accepted, in characters after normalization
+const MIN_SALT_CHARS: usize = 4;
+
+/// Stretches the normalized passphrase and salt into 32 bytes of mnemonic entropy
+fn brainwallet_entropy(passphrase: &str, salt: &str) -> Result<Zeroizing<[u8; 32]>, String> {
+    let mut salted = BRAINWALLET_SALT_PREFIX.to_vec();
+    salted.extend_from_slice(salt.as_bytes());
+
+    let params = Params::new(
+        BRAINWALLET_MEMORY_KIB,
+        BRAINWALLET_PASSES,
+        BRAINWALLET_LANES,
+        Some(32),
+    )
+    .map_err(|e| format!("Invalid Argon2 parameters: {}", e))?;
+    let mut entropy = Zeroizing::new([0u8; 32]);
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(passphrase.as_bytes(), &salted, &mut *entropy)
+        .map_err(|e| format!("Error stretching passphrase: {}", e))?;
+    Ok(entropy)
+}
+
+/// Derives a keypair from a memorized passphrase (**not recommended**)
+///
+/// The passphrase and salt are NFKD-normalized, stretched with Argon2id
+/// (256 MiB, 4 passes) and used as the entropy of a 24-word mnemonic, which
+/// is returned in the keypair like any other. The same passphrase, salt and
+/// scheme always give the same wallet. Derivation takes several seconds and
+/// 256 MiB of memory, so run it in a Web Worker.
+///
+/// Anyone who guesses the passphrase and salt owns the funds, forever. Only
+/// pass `acknowledge_risk = true` after the user has been shown that warning
+/// and offered a random mnemonic instead.
+///
+/// # Arguments
+/// * `passphrase` - The memorized phrase (at least 20 characters; a long sentence only the user knows)
+/// * `salt` - A second remembered value unique to the user, such as an email address
+/// * `scheme` - The account scheme (Cosmos or EthSecp256k1)
+/// * `hrp` - Optional bech32 prefix for the address (defaults to "mantra")
+/// * `acknowledge_risk` - Must be true to confirm the user accepted the risk
+///
+/// # Returns
+/// * `Keypair` - The derived keypair and its 24-word backup mnemonic, or a
+///   `VanityError` if the risk was not acknowledged or an input is too short
+///
+/// # Example
+/// ```javascript
+/// const keypair = derive_brainwallet_keypair(phrase, email, AccountScheme.Cosmos, undefined, riskCheckbox.checked);
+/// console.log(`Back up this mnemonic too: ${keypair.reveal_mnemonic(false)}`);
+/// ```
+#[wasm_bindgen]
+pub fn derive_brainwallet_keypair(
+    passphrase: &str,
+    salt: &str,
+    scheme: AccountScheme,
+    hrp: Option<String>,
+    acknowledge_risk: bool,
+) -> Result<Keypair, VanityError> {
+    if !acknowledge_risk {
+        return Err(VanityError::NotAcknowledged(
+            "Refusing to derive a brain wallet without acknowledge_risk = true".to_string(),
+        ));
+    }
+    let passphrase = Zeroizing::new(passphrase.nfkd().collect::<String>());
+    if passphrase.chars().count() < MIN_PASSPHRASE_CHARS {
+        return Err(VanityError::InvalidInput(format!(
+            "Brain wallet passphrase must be at least {} characters",
+            MIN_PASSPHRASE_CHARS
+        )));
+    }
+    let salt: String = salt.nfkd().collect();
+    if salt.chars().count() < MIN_SALT_CHARS {
+        return Err(VanityError::InvalidInput(format!(
+            "Brain wallet salt must be at least {} characters",
+            MIN_SALT_CHARS
+        )));
+    }
+    let hrp = hrp_or_default(hrp);
+    check_hrp(&hrp)?;
+
+    let entropy = brainwallet_entropy(&passphrase, &salt).map_err(VanityError::DerivationFailed)?;
+    let mnemonic = Mnemonic::from_entropy(&*entropy)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error building mnemonic: {}", e)))?;
+    let keypair = keypair_from_mnemonic(&mnemonic, scheme, &hrp)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))?;
+    paranoid_checked(keypair)
+}
@@ -0,0 +1,203 @@
+//! Bulk keypair generation and export
+//!
+//! Teams provisioning devnets or load tests need many funded test accounts at
+//! once. These helpers generate a batch and render it as CSV or NDJSON in a
+//! single call, so a script can write the result straight to a file.
+
+use crate::audit::audit_export;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::{hrp_or_default, random_keypair, AccountScheme, Keypair};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+/// Most keypairs `generate_batch()` produces in one call
+const MAX_BATCH_COUNT: u32 = 10_000;
+
+/// Header row of the CSV export
+const CSV_HEADER: &str = "address,mnemonic,derivation_path,public_key";
+
+/// Text format for exported keypairs
+#[wasm_bindgen]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row
+    Csv = 0,
+    /// One `Keypair.to_json()` record per line
+    Ndjson = 1,
+}
+
+/// Quotes a CSV field when it contains a separator, quote or line break
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders one keypair as a CSV row, leaving the mnemonic column empty unless `with_mnemonic`
+fn csv_row(keypair: &Keypair, with_mnemonic: bool) -> String {
+    [
+        keypair.address(),
+        if with_mnemonic {
+            keypair.mnemonic.clone().unwrap_or_default()
+        } else {
+            String::new()
+        },
+        keypair.derivation_path().unwrap_or_default(),
+        keypair.public_key_hex(true).unwrap_or_default(),
+    ]
+    .iter()
+    .map(|field| csv_field(field))
+    .collect::<Vec<_>>()
+    .join(",")
+}
+
+/// Builder that renders keypairs as CSV or NDJSON
+///
+/// CSV has the columns address, mnemonic, derivation_path and public_key
+/// (compressed hex). NDJSON writes each keypair as its `to_json()` record, so
+/// every line can be reloaded with `Keypair.from_json()`. Mnemonics are only
+/// written when the export is created with `include_secrets`; that is refused
+/// in hardened mode, and every row is reported to the hook registered with
+/// `set_export_audit_hook()`. Otherwise the output is a watch-only listing.
+/// Keypairs are added by reference, so the JavaScript handles stay usable
+/// afterwards.
+#[wasm_bindgen]
+pub struct KeypairExport {
+    format: ExportFormat,
+    include_secrets: bool,
+    lines: Vec<Zeroizing<String>>,
+}
+
+#[wasm_bindgen]
+impl KeypairExport {
+    /// Creates an empty export in the given format
+    ///
+    /// # Arguments
+    /// * `format` - CSV or NDJSON
+    /// * `include_secrets` - true to write mnemonics; treat the output as a secret
+    ///
+    /// # Returns
+    /// * `KeypairExport` - The empty export, or an error if secrets were
+    ///   requested in hardened mode
+    #[wasm_bindgen(constructor)]
+    pub fn new(format: ExportFormat, include_secrets: bool) -> Result<KeypairExport, VanityError> {
+        if include_secrets {
+            check_plaintext_secrets("a bulk export with mnemonics")?;
+        }
+        Ok(KeypairExport {
+            format,
+            include_secrets,
+            lines: Vec::new(),
+        })
+    }
+
+    /// Gets the number of keypairs added so far
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// Adds a keypair as the next row
+    ///
+    /// # Arguments
+    /// * `keypair` - The keypair to export
+    ///
+    /// # Returns
+    /// * `()` - Nothing, or an error if the audit hook failed
+    pub fn add(&mut self, keypair: &Keypair) -> Result<(), VanityError> {
+        if self.include_secrets {
+            audit_export("bulk_export", Some(&keypair.address))?;
+        }
+        let line = match self.format {
+            ExportFormat::Csv => csv_row(keypair, self.include_secrets),
+            ExportFormat::Ndjson => {
+                let record = if self.include_secrets {
+                    keypair.to_record()
+                } else {
+                    keypair.to_public_record()
+                };
+                serde_json::to_string(&record).map_err(|e| {
+                    VanityError::DerivationFailed(format!("Error serializing keypair: {}", e))
+                })?
+            }
+        };
+        self.lines.push(Zeroizing::new(line));
+        Ok(())
+    }
+
+    /// Renders the added keypairs
+    ///
+    /// # Returns
+    /// * `String` - The rendered keypairs, one per line (CSV starts with a header row)
+    ///
+    /// # Example
+    /// ```javascript
+    /// const csv = new KeypairExport(ExportFormat.Csv, userConfirmed);
+    /// generate_random_keypairs_batch(10, undefined).forEach((keypair) => csv.add(keypair));
+    /// download("accounts.csv", csv.render());
+    /// ```
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+        if self.format == ExportFormat::Csv {
+            output.push_str(CSV_HEADER);
+            output.push('\n');
+        }
+        for line in &self.lines {
+            output.push_str(line);
+            output.push('\n');
+        }
+        output
+    }
+}
+
+/// Generates random keypairs and exports them in one call
+///
+/// Each keypair gets a fresh 24-word mnemonic and the standard Cosmos path
+/// (m/44'/118'/0'/0/0). Generation takes a few milliseconds per keypair, so
+/// run large batches in a Web Worker. The output carries every mnemonic, so
+/// the caller must pass `acknowledge_sensitive_output = true`; the export is
+/// refused in hardened mode and each keypair is reported to the audit hook.
+///
+/// # Arguments
+/// * `count` - Number of keypairs to generate (at most 10000)
+/// * `format` - CSV or NDJSON
+/// * `hrp` - Optional bech32 prefix for the addresses (defaults to "mantra")
+/// * `acknowledge_sensitive_output` - Must be true to confirm mnemonics may be returned
+///
+/// # Returns
+/// * `String` - The rendered keypairs, or a `VanityError` if not acknowledged,
+///   `count` is too large, hardened mode is on or generation fails
+///
+/// # Example
+/// ```javascript
+/// const ndjson = generate_batch(100, ExportFormat.Ndjson, "mantra", true);
+/// ```
+#[wasm_bindgen]
+pub fn generate_batch(
+    count: u32,
+    format: ExportFormat,
+    hrp: Option<String>,
+    acknowledge_sensitive_output: bool,
+) -> Result<String, VanityError> {
+    if !acknowledge_sensitive_output {
+        return Err(VanityError::NotAcknowledged(
+            "Bulk generation requires acknowledge_sensitive_output = true".to_string(),
+        ));
+    }
+    if count > MAX_BATCH_COUNT {
+        return Err(VanityError::InvalidInput(format!(
+            "Batch count must be at most {}",
+            MAX_BATCH_COUNT
+        )));
+    }
+    let hrp = hrp_or_default(hrp);
+
+    let mut export = KeypairExport::new(format, true)?;
+    for _ in 0..count {
+        export.add(&random_keypair(AccountScheme::Cosmos, &hrp)?)?;
+    }
+    Ok(export.render())
+}
@@ -0,0 +1,105 @@
+//! Bundled Cosmos chain presets
+//!
+//! A small registry of popular Cosmos chains (bech32 prefix and BIP44 coin
+//! type) is compiled into the module from `chains.json`, so frontends can
+//! offer a chain picker without keeping their own copy of this data.
+
+use crate::hd::{format_derivation_path, HARDENED_OFFSET};
+use serde::Deserialize;
+use std::sync::OnceLock;
+use wasm_bindgen::prelude::*;
+
+/// Raw registry data, embedded at compile time
+const CHAINS_JSON: &str = include_str!("chains.json");
+
+/// Address settings for a known Cosmos chain
+#[wasm_bindgen]
+#[derive(Clone, Deserialize)]
+pub struct ChainPreset {
+    name: String,
+    pretty_name: String,
+    hrp: String,
+    coin_type: u32,
+}
+
+#[wasm_bindgen]
+impl ChainPreset {
+    /// Gets the registry key of the chain (e.g. "osmosis")
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Gets the display name of the chain (e.g. "Osmosis")
+    #[wasm_bindgen(getter)]
+    pub fn pretty_name(&self) -> String {
+        self.pretty_name.clone()
+    }
+
+    /// Gets the bech32 human-readable part of the chain's addresses
+    #[wasm_bindgen(getter)]
+    pub fn hrp(&self) -> String {
+        self.hrp.clone()
+    }
+
+    /// Gets the BIP44 coin type wallets use for the chain
+    #[wasm_bindgen(getter)]
+    pub fn coin_type(&self) -> u32 {
+        self.coin_type
+    }
+
+    /// Gets the default address path for the chain (e.g. `m/44'/118'/0'/0/0`)
+    #[wasm_bindgen(getter)]
+    pub fn derivation_path(&self) -> String {
+        format_derivation_path(&[
+            44 + HARDENED_OFFSET,
+            self.coin_type + HARDENED_OFFSET,
+            HARDENED_OFFSET,
+            0,
+            0,
+        ])
+    }
+}
+
+/// Returns the parsed registry, parsing the embedded JSON on first use
+fn registry() -> &'static [ChainPreset] {
+    static REGISTRY: OnceLock<Vec<ChainPreset>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        serde_json::from_str(CHAINS_JSON).expect("Embedded chain registry is not valid JSON")
+    })
+}
+
+/// Lists every bundled chain preset
+///
+/// # Returns
+/// * `Vec<ChainPreset>` - The presets in registry order (MANTRA first)
+///
+/// # Example
+/// ```javascript
+/// for (const chain of list_chains()) {
+///   select.add(new Option(chain.pretty_name, chain.name));
+/// }
+/// ```
+#[wasm_bindgen]
+pub fn list_chains() -> Vec<ChainPreset> {
+    registry().to_vec()
+}
+
+/// Looks up a bundled chain preset by name
+///
+/// # Arguments
+/// * `chain` - The registry key (case-insensitive, e.g. "osmosis")
+///
+/// # Returns
+/// * `Option<ChainPreset>` - The preset, or None if the chain is not bundled
+///
+/// # Example
+/// ```javascript
+/// const osmosis = preset("osmosis");
+/// const keypair = generate_random_keypair(osmosis.hrp);
+/// ```
+#[wasm_bindgen]
+pub fn preset(chain: &str) -> Option<ChainPreset> {
+    let chain = chain.trim().to_lowercase();
+    registry().iter().find(|entry| entry.name == chain).cloned()
+}
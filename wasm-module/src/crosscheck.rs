@@ -0,0 +1,175 @@
+//! Independent re-derivation of returned keypairs
+//!
+//! A derivation bug would hand out an address whose funds the mnemonic cannot
+//! reach. In paranoid mode, every keypair returned by the vanity searches and
+//! random generation is derived a second time before it leaves the module:
+//! the seed is recomputed with PBKDF2 directly, the path is walked with the
+//! `bip32` crate instead of this crate's own BIP32 code, and the address is
+//! re-encoded from scratch. A mismatch throws instead of returning the key.
+//!
+//! The second implementation is compiled in with the `paranoid` cargo feature.
+
+use crate::error::VanityError;
+use crate::Keypair;
+use std::sync::atomic::{AtomicBool, Ordering};
+use wasm_bindgen::prelude::*;
+
+/// Whether returned keypairs are cross-checked
+static PARANOID_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Error for builds compiled without the second implementation
+const UNAVAILABLE: &str =
+    "This build does not include the paranoid cross-check (cargo feature \"paranoid\")";
+
+/// Re-derives a keypair's public key and address with independent code
+#[cfg(feature = "paranoid")]
+fn independent_check(keypair: &Keypair) -> Result<(), String> {
+    use bech32::{ToBase32, Variant};
+    use bip32::{ChildNumber, XPrv};
+    use k256::ecdsa::SigningKey;
+    use k256::elliptic_curve::sec1::ToEncodedPoint;
+    use pbkdf2::pbkdf2_hmac;
+    use ripemd::Ripemd160;
+    use sha2::{Digest, Sha256, Sha512};
+    use sha3::Keccak256;
+    use unicode_normalization::UnicodeNormalization;
+
+    let public_key: [u8; 33] = match (&keypair.mnemonic, &keypair.private_key_hex) {
+        (Some(mnemonic), _) => {
+            let phrase: String = mnemonic.nfkd().collect();
+            let mut seed = [0u8; 64];
+            pbkdf2_hmac::<Sha512>(phrase.as_bytes(), b"mnemonic", 2048, &mut seed);
+
+            let path = keypair
+                .derivation_path
+                .as_deref()
+                .unwrap_or(keypair.scheme.derivation_path());
+            let mut key = XPrv::new(seed).map_err(|e| e.to_string())?;
+            for &index in path {
+                key = key
+                    .derive_child(ChildNumber::from(index))
+                    .map_err(|e| e.to_string())?;
+            }
+            key.public_key().to_bytes()
+        }
+        (None, Some(private_key_hex)) => {
+            let bytes = hex::decode(private_key_hex).map_err(|e| e.to_string())?;
+            let signing_key = SigningKey::from_slice(&bytes).map_err(|e| e.to_string())?;
+            let point = signing_key.verifying_key().to_encoded_point(true);
+            point
+                .as_bytes()
+                .try_into()
+                .map_err(|_| "Unexpected public key length".to_string())?
+        }
+        // Nothing secret to re-derive from
+        (None, None) => return Ok(()),
+    };
+
+    if keypair
+        .public_key
+        .is_some_and(|stored| stored != public_key)
+    {
+        return Err("public key differs from the independent derivation".to_string());
+    }
+
+    let account_id: Vec<u8> = match keypair.scheme {
+        crate::AccountScheme::Cosmos => Ripemd160::digest(Sha256::digest(public_key)).to_vec(),
+        crate::AccountScheme::EthSecp256k1 => {
+            let point = k256::PublicKey::from_sec1_bytes(&public_key)
+                .map_err(|e| e.to_string())?
+                .to_encoded_point(false);
+            Keccak256::digest(&point.as_bytes()[1..])[12..].to_vec()
+        }
+    };
+    let (hrp, _, _) = bech32::decode(&keypair.address).map_err(|e| e.to_string())?;
+    let address =
+        bech32::encode(&hrp, account_id.to_base32(), Variant::Bech32).map_err(|e| e.to_string())?;
+    if address != keypair.address {
+        return Err(format!(
+            "address {} differs from the independent derivation {}",
+            keypair.address, address
+        ));
+    }
+    Ok(())
+}
+
+/// Without the `paranoid` feature there is no second implementation to ask
+#[cfg(not(feature = "paranoid"))]
+fn independent_check(_keypair: &Keypair) -> Result<(), String> {
+    Err(UNAVAILABLE.to_string())
+}
+
+/// Cross-checks a keypair about to be returned, when paranoid mode is on
+///
+/// Fails with `CrossCheckFailed` if the independent derivation disagrees, so
+/// a possibly wrong key is never handed out.
+pub fn paranoid_checked(keypair: Keypair) -> Result<Keypair, VanityError> {
+    if PARANOID_MODE.load(Ordering::Relaxed) {
+        if let Err(e) = independent_check(&keypair) {
+            return Err(VanityError::CrossCheckFailed(format!(
+                "Paranoid cross-check failed, refusing to return keypair: {}",
+                e
+            )));
+        }
+    }
+    Ok(keypair)
+}
+
+/// Reports whether this build includes the paranoid cross-check
+///
+/// # Returns
+/// * `bool` - true if `set_paranoid_mode(true)` can be enabled
+#[wasm_bindgen]
+pub fn paranoid_mode_available() -> bool {
+    cfg!(feature = "paranoid")
+}
+
+/// Turns paranoid mode on or off
+///
+/// While on, every keypair returned by the vanity searches and random
+/// generation is re-derived through a second, independent BIP32
+/// implementation and compared before it is returned; a mismatch throws
+/// instead. This roughly doubles the cost of each returned keypair but not of
+/// the candidates a search discards.
+///
+/// # Arguments
+/// * `enabled` - true to cross-check returned keypairs
+///
+/// # Returns
+/// * `()` - Or an error when enabling it in a build without the `paranoid` feature
+///
+/// # Example
+/// ```javascript
+/// if (paranoid_mode_available()) set_paranoid_mode(true);
+/// ```
+#[wasm_bindgen]
+pub fn set_paranoid_mode(enabled: bool) -> Result<(), VanityError> {
+    if enabled && !paranoid_mode_available() {
+        return Err(VanityError::Unsupported(UNAVAILABLE.to_string()));
+    }
+    PARANOID_MODE.store(enabled, Ordering::Relaxed);
+    Ok(())
+}
+
+#[wasm_bindgen]
+impl Keypair {
+    /// Re-derives this keypair through an independent implementation and compares
+    ///
+    /// Works regardless of paranoid mode, in builds with the `paranoid` feature.
+    ///
+    /// # Returns
+    /// * `()` - Or an error describing the mismatch, or that the check is not
+    ///   available in this build
+    ///
+    /// # Example
+    /// ```javascript
+    /// keypair.cross_check(); // throws if the independent derivation disagrees
+    /// ```
+    pub fn cross_check(&self) -> Result<(), VanityError> {
+        if !paranoid_mode_available() {
+            return Err(VanityError::Unsupported(UNAVAILABLE.to_string()));
+        }
+        independent_check(self)
+            .map_err(|e| VanityError::CrossCheckFailed(format!("Cross-check failed: {}", e)))
+    }
+}
@@ -0,0 +1,16 @@
+//! Constant-time comparison of secret material
+//!
+//! `==` on byte slices returns at the first difference, so the time a
+//! comparison takes reveals how much of a guess was right. Every comparison
+//! whose inputs derive from a key, seed, mnemonic or share goes through
+//! `secret_eq()` instead, which always examines every byte. Public values
+//! such as addresses and public keys keep using `==`.
+
+use subtle::ConstantTimeEq;
+
+/// Compares two secret byte strings in time independent of their contents
+///
+/// Only the lengths, which are not secret here, can end the comparison early.
+pub fn secret_eq(a: &[u8], b: &[u8]) -> bool {
+    a.ct_eq(b).into()
+}
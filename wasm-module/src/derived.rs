@@ -0,0 +1,367 @@
+//! Keyless account addresses derived by the Cosmos SDK
+//!
+//! Module accounts have no private key: their addresses are hashes of the
+//! module name (and, for ADR-028 derived accounts, of extra derivation keys).
+//! This module reproduces those derivations so the addresses can be computed
+//! offline, including CosmWasm `instantiate2` contract addresses (and vanity
+//! salts for them), interchain account addresses and IBC escrow addresses.
+
+use crate::address::{check_hrp, decode_bech32, encode_bech32};
+use crate::error::VanityError;
+use crate::{address_matches, check_target, VanityPosition};
+use rand::{rngs::OsRng, RngCore};
+use sha2::{Digest, Sha256};
+use wasm_bindgen::prelude::*;
+
+/// Computes the 20-byte address of a module account
+///
+/// Module accounts without derivation keys use the legacy rule: the first 20
+/// bytes of SHA256 over the module name.
+pub fn module_address_bytes(module_name: &str) -> [u8; 20] {
+    let hash = Sha256::digest(module_name.as_bytes());
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[..20]);
+    address
+}
+
+/// Computes the address of a Cosmos SDK module account
+///
+/// This matches `authtypes.NewModuleAddress(name)`, so e.g. the `gov`,
+/// `distribution` and `bonded_tokens_pool` accounts (or a custom module's
+/// account) can be looked up before any chain state exists.
+///
+/// # Arguments
+/// * `module_name` - The module account name (e.g. "gov", "distribution")
+/// * `hrp` - The bech32 human-readable part (e.g. "mantra")
+///
+/// # Returns
+/// * `String` - The bech32-encoded module account address, or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// const govAddress = module_account_address("gov", "mantra");
+/// console.log(`Governance module: ${govAddress}`);
+/// ```
+#[wasm_bindgen]
+pub fn module_account_address(module_name: &str, hrp: &str) -> Result<String, VanityError> {
+    if module_name.is_empty() {
+        return Err(VanityError::InvalidInput(
+            "Module name must not be empty".to_string(),
+        ));
+    }
+    check_hrp(hrp)?;
+
+    encode_bech32(&hrp.to_lowercase(), &module_address_bytes(module_name))
+        .map_err(|e| VanityError::DerivationFailed(format!("Error encoding address: {}", e)))
+}
+
+/// Computes the ADR-028 address hash: SHA256(SHA256(typ) || key)
+pub fn adr028_hash(typ: &str, key: &[u8]) -> [u8; 32] {
+    let type_hash = Sha256::digest(typ.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(type_hash);
+    hasher.update(key);
+    hasher.finalize().into()
+}
+
+/// Derives a sub-account address with ADR-028: Hash(address, key)
+pub fn adr028_derive(address: &[u8], key: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(Sha256::digest(address));
+    hasher.update(key);
+    hasher.finalize().into()
+}
+
+/// Computes the 32-byte CosmWasm `instantiate2` contract address
+///
+/// The key is `"wasm\0"` followed by the checksum, creator, salt and message,
+/// each prefixed with its length as a big-endian u64, hashed as a "module"
+/// ADR-028 address (the same algorithm as `cosmwasm_std::instantiate2_address`).
+pub fn instantiate2_address_bytes(
+    checksum: &[u8],
+    creator: &[u8],
+    salt: &[u8],
+    msg: &[u8],
+) -> [u8; 32] {
+    let mut key =
+        Vec::with_capacity(5 + 32 + checksum.len() + creator.len() + salt.len() + msg.len());
+    key.extend_from_slice(b"wasm\0");
+    for part in [checksum, creator, salt, msg] {
+        key.extend_from_slice(&(part.len() as u64).to_be_bytes());
+        key.extend_from_slice(part);
+    }
+
+    adr028_hash("module", &key)
+}
+
+/// Inputs to `instantiate2` after validation
+pub struct Instantiate2Params {
+    pub hrp: String,
+    pub checksum: Vec<u8>,
+    pub creator: Vec<u8>,
+    pub msg: Vec<u8>,
+}
+
+impl Instantiate2Params {
+    /// Validates the code checksum and creator address and decodes them to bytes
+    pub fn parse(
+        checksum_hex: &str,
+        creator: &str,
+        msg: Option<String>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let checksum = hex::decode(checksum_hex.trim())
+            .map_err(|e| format!("Invalid checksum: not valid hex: {}", e))?;
+        if checksum.len() != 32 {
+            return Err(format!(
+                "Invalid checksum: expected 32 bytes, got {}",
+                checksum.len()
+            )
+            .into());
+        }
+
+        let (hrp, creator) =
+            decode_bech32(creator.trim()).map_err(|e| format!("Invalid creator address: {}", e))?;
+
+        Ok(Instantiate2Params {
+            hrp,
+            checksum,
+            creator,
+            msg: msg.map(String::into_bytes).unwrap_or_default(),
+        })
+    }
+
+    /// Encodes the contract address for a salt under the creator's HRP
+    pub fn address(&self, salt: &[u8]) -> Result<String, Box<dyn std::error::Error>> {
+        if salt.is_empty() || salt.len() > 64 {
+            return Err(format!("Invalid salt: expected 1 to 64 bytes, got {}", salt.len()).into());
+        }
+
+        let address = instantiate2_address_bytes(&self.checksum, &self.creator, salt, &self.msg);
+        encode_bech32(&self.hrp, &address)
+    }
+}
+
+/// Predicts the address of a contract instantiated with `MsgInstantiateContract2`
+///
+/// The address depends only on the code checksum, the creator, the salt and
+/// (when `fix_msg` is used) the instantiate message, so it can be known, funded
+/// or referenced before the contract is deployed.
+///
+/// # Arguments
+/// * `checksum_hex` - The SHA256 checksum of the wasm code as hex (from `query wasm code-info`)
+/// * `creator` - The bech32 address that will instantiate the contract; its HRP is reused
+/// * `salt` - The salt bytes (1 to 64 bytes)
+/// * `msg` - The exact instantiate message JSON when `fix_msg` is set, otherwise undefined
+///
+/// # Returns
+/// * `String` - The predicted 32-byte contract address (e.g. `mantra1...`), or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// const salt = new TextEncoder().encode("my-salt");
+/// const address = predict_instantiate2_address(checksum, "mantra1...", salt, undefined);
+/// console.log(`Contract will be deployed at ${address}`);
+/// ```
+#[wasm_bindgen]
+pub fn predict_instantiate2_address(
+    checksum_hex: &str,
+    creator: &str,
+    salt: &[u8],
+    msg: Option<String>,
+) -> Result<String, VanityError> {
+    Instantiate2Params::parse(checksum_hex, creator, msg)
+        .and_then(|params| params.address(salt))
+        .map_err(|e| VanityError::InvalidInput(format!("Error predicting contract address: {}", e)))
+}
+
+/// A salt whose `instantiate2` contract address matches the vanity pattern
+#[wasm_bindgen]
+pub struct SaltMatch {
+    salt: String,
+    address: String,
+}
+
+#[wasm_bindgen]
+impl SaltMatch {
+    /// Gets the salt as a printable string (pass it to `instantiate2` as-is)
+    #[wasm_bindgen(getter)]
+    pub fn salt(&self) -> String {
+        self.salt.clone()
+    }
+
+    /// Gets the salt bytes as hex (for tools that take `--hex` salts)
+    #[wasm_bindgen(getter)]
+    pub fn salt_hex(&self) -> String {
+        hex::encode(self.salt.as_bytes())
+    }
+
+    /// Gets the predicted contract address
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+}
+
+/// Grind `instantiate2` salts in batches until the contract address matches
+///
+/// Each attempt uses a random 16-character hex string as the salt, so no
+/// private key is involved and batches from parallel workers never overlap.
+/// Call repeatedly (e.g. from Web Workers) like `generate_vanity_keypair_batch()`.
+///
+/// # Arguments
+/// * `checksum_hex` - The SHA256 checksum of the wasm code as hex
+/// * `creator` - The bech32 address that will instantiate the contract
+/// * `msg` - The exact instantiate message JSON when `fix_msg` is set, otherwise undefined
+/// * `target` - The substring pattern to search for in contract addresses
+/// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
+/// * `batch_size` - Number of salts to try in this batch
+///
+/// # Returns
+/// * `Option<SaltMatch>` - The first matching salt, None if no match found, or an error for invalid input
+#[wasm_bindgen]
+pub fn grind_instantiate2_salt_batch(
+    checksum_hex: &str,
+    creator: &str,
+    msg: Option<String>,
+    target: &str,
+    position: VanityPosition,
+    batch_size: u32,
+) -> Result<Option<SaltMatch>, VanityError> {
+    let target_lower = check_target(target)?;
+    grind_salt(
+        checksum_hex,
+        creator,
+        msg,
+        &target_lower,
+        position,
+        batch_size,
+    )
+    .map_err(|e| VanityError::InvalidInput(format!("Error grinding salt: {}", e)))
+}
+
+/// Tries random salts and returns the first whose contract address matches the lowercased target
+fn grind_salt(
+    checksum_hex: &str,
+    creator: &str,
+    msg: Option<String>,
+    target_lower: &str,
+    position: VanityPosition,
+    batch_size: u32,
+) -> Result<Option<SaltMatch>, Box<dyn std::error::Error>> {
+    let params = Instantiate2Params::parse(checksum_hex, creator, msg)?;
+    let mut salt_bytes = [0u8; 8];
+
+    for _ in 0..batch_size {
+        OsRng.fill_bytes(&mut salt_bytes);
+        let salt = hex::encode(salt_bytes);
+        let address = params.address(salt.as_bytes())?;
+
+        if address_matches(&address, target_lower, position) {
+            return Ok(Some(SaltMatch { salt, address }));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Module account name of the ICA host module, whose address roots ICA derivation
+const ICA_MODULE_NAME: &str = "interchainaccounts";
+
+/// Port ID prefix used by interchain account controllers
+const ICA_CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+
+/// Computes the address of an interchain account on the host chain
+///
+/// The host derives the account from its ICA module account with ADR-028,
+/// using the host-side connection ID followed by the controller port ID
+/// (`icacontroller-{owner}`) as the derivation key.
+///
+/// # Arguments
+/// * `connection_id` - The connection ID on the host chain (e.g. "connection-0")
+/// * `owner` - The owner address on the controller chain
+/// * `hrp` - The host chain's bech32 human-readable part (e.g. "mantra")
+///
+/// # Returns
+/// * `String` - The interchain account address, or an error for invalid input
+///
+/// # Note
+/// This is the deterministic derivation used by ibc-go v3 and v4 hosts. Newer
+/// ibc-go releases also mix the block app hash and data hash of the
+/// registration block into the key, so on those hosts the address cannot be
+/// known before the channel opens.
+///
+/// # Example
+/// ```javascript
+/// const ica = ica_host_address("connection-0", "osmo1...", "mantra");
+/// console.log(`Interchain account: ${ica}`);
+/// ```
+#[wasm_bindgen]
+pub fn ica_host_address(
+    connection_id: &str,
+    owner: &str,
+    hrp: &str,
+) -> Result<String, VanityError> {
+    let connection_id = connection_id.trim();
+    let owner = owner.trim();
+    if !connection_id.starts_with("connection-") {
+        return Err(VanityError::InvalidInput(format!(
+            "Invalid connection ID: {:?} (expected connection-N)",
+            connection_id
+        )));
+    }
+    if owner.is_empty() {
+        return Err(VanityError::InvalidInput(
+            "Owner must not be empty".to_string(),
+        ));
+    }
+    check_hrp(hrp)?;
+
+    let key = format!("{}{}{}", connection_id, ICA_CONTROLLER_PORT_PREFIX, owner);
+    let address = adr028_derive(&module_address_bytes(ICA_MODULE_NAME), key.as_bytes());
+
+    encode_bech32(&hrp.to_lowercase(), &address)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error encoding address: {}", e)))
+}
+
+/// ICS-20 version string that prefixes the escrow address preimage
+const ICS20_VERSION: &str = "ics20-1";
+
+/// Computes the ICS-20 transfer escrow address of a channel
+///
+/// Tokens sent out over a channel are locked in this keyless account, so it is
+/// where explorers and auditors look for bridged funds. The address is the
+/// first 20 bytes of SHA256("ics20-1" || 0x00 || "{port}/{channel}").
+///
+/// # Arguments
+/// * `port` - The port ID (usually "transfer")
+/// * `channel` - The channel ID (e.g. "channel-0")
+/// * `hrp` - The bech32 human-readable part of the chain holding the escrow (e.g. "mantra")
+///
+/// # Returns
+/// * `String` - The escrow account address, or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// const escrow = ibc_escrow_address("transfer", "channel-0", "mantra");
+/// console.log(`Escrow account: ${escrow}`);
+/// ```
+#[wasm_bindgen]
+pub fn ibc_escrow_address(port: &str, channel: &str, hrp: &str) -> Result<String, VanityError> {
+    let port = port.trim();
+    let channel = channel.trim();
+    if port.is_empty() || channel.is_empty() {
+        return Err(VanityError::InvalidInput(
+            "Port and channel must not be empty".to_string(),
+        ));
+    }
+    check_hrp(hrp)?;
+
+    let mut hasher = Sha256::new();
+    hasher.update(ICS20_VERSION.as_bytes());
+    hasher.update([0u8]);
+    hasher.update(format!("{}/{}", port, channel).as_bytes());
+    let hash = hasher.finalize();
+
+    encode_bech32(&hrp.to_lowercase(), &hash[..20])
+        .map_err(|e| VanityError::DerivationFailed(format!("Error encoding address: {}", e)))
+}
@@ -0,0 +1,199 @@
+//! Mnemonics from dice rolls and coin flips
+//!
+//! Users who trust no random number generator can supply all of the entropy
+//! by hand. Each fair die roll carries log2(6) ≈ 2.585 bits and each fair coin
+//! flip one bit, so a 24-word phrase (256 bits) needs at least 100 rolls or 256
+//! flips. Rolls in base 6 do not map evenly onto bits, and real dice and coins
+//! are never perfectly fair, so the input is not used as entropy directly: the
+//! canonical input string is hashed with SHA-256 and the hash, truncated to the
+//! phrase length, becomes the entropy. Extra rolls or flips beyond the minimum
+//! are all hashed in and make up for imperfect dice.
+//!
+//! The conditioning can be reproduced with any SHA-256 tool, e.g.
+//! `printf '%s' "$ROLLS" | sha256sum`; for 24 words it is the method Coldcard uses.
+
+use crate::error::VanityError;
+use crate::wordlist::language_or_default;
+use bip39::Mnemonic;
+use sha2::{Digest, Sha256};
+use wasm_bindgen::prelude::*;
+
+/// Entropy bits in one roll of a fair six-sided die
+const BITS_PER_DIE_ROLL: f64 = 2.584_962_500_721_156;
+
+/// Returns the entropy length in bits for a BIP39 word count
+fn entropy_bits(word_count: u32) -> Result<u32, String> {
+    match word_count {
+        12 | 15 | 18 | 21 | 24 => Ok(word_count * 32 / 3),
+        _ => Err(format!(
+            "Invalid word count: {} (expected 12, 15, 18, 21 or 24)",
+            word_count
+        )),
+    }
+}
+
+/// Returns the fewest fair die rolls that carry at least `bits` of entropy
+fn dice_rolls_for_bits(bits: u32) -> u32 {
+    (bits as f64 / BITS_PER_DIE_ROLL).ceil() as u32
+}
+
+/// Reads die rolls as the digits 1-6, ignoring whitespace and commas
+pub fn parse_dice_rolls(rolls: &str) -> Result<String, String> {
+    rolls
+        .chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != ',')
+        .enumerate()
+        .map(|(position, ch)| match ch {
+            '1'..='6' => Ok(ch),
+            _ => Err(format!(
+                "Invalid die roll '{}' at roll {} (expected 1-6)",
+                ch,
+                position + 1
+            )),
+        })
+        .collect()
+}
+
+/// Reads coin flips as H/T or 1/0, ignoring whitespace and commas
+///
+/// Returns the flips as a string of '1' (heads) and '0' (tails).
+pub fn parse_coin_flips(flips: &str) -> Result<String, String> {
+    flips
+        .chars()
+        .filter(|ch| !ch.is_whitespace() && *ch != ',')
+        .enumerate()
+        .map(|(position, ch)| match ch {
+            'H' | 'h' | '1' => Ok('1'),
+            'T' | 't' | '0' => Ok('0'),
+            _ => Err(format!(
+                "Invalid coin flip '{}' at flip {} (expected H, T, 1 or 0)",
+                ch,
+                position + 1
+            )),
+        })
+        .collect()
+}
+
+/// Rejects input too short for the phrase or made of one repeated outcome
+fn check_outcomes(outcomes: &str, required: u32, noun: &str) -> Result<(), String> {
+    let count = outcomes.chars().count() as u32;
+    if count < required {
+        return Err(format!(
+            "{} {}s provide too little entropy: at least {} are needed",
+            count, noun, required
+        ));
+    }
+    if outcomes.chars().all(|ch| outcomes.starts_with(ch)) {
+        return Err(format!("Every {} has the same outcome", noun));
+    }
+    Ok(())
+}
+
+/// Hashes the canonical outcome string and builds the mnemonic from the hash
+fn conditioned_mnemonic(
+    outcomes: &str,
+    word_count: u32,
+    language: Option<String>,
+) -> Result<String, String> {
+    let language = language_or_default(language)?;
+    let bytes = entropy_bits(word_count)? as usize / 8;
+    let hash = Sha256::digest(outcomes.as_bytes());
+
+    Mnemonic::from_entropy_in(language, &hash[..bytes])
+        .map(|mnemonic| mnemonic.to_string())
+        .map_err(|e| format!("Error building mnemonic: {}", e))
+}
+
+/// Gets the fewest die rolls `dice_to_mnemonic()` accepts for a phrase length
+///
+/// # Arguments
+/// * `word_count` - The phrase length (12, 15, 18, 21 or 24)
+///
+/// # Returns
+/// * `u32` - The minimum number of rolls (50 for 12 words, 100 for 24), or an
+///   error for an invalid word count
+#[wasm_bindgen]
+pub fn required_dice_rolls(word_count: u32) -> Result<u32, VanityError> {
+    entropy_bits(word_count)
+        .map(dice_rolls_for_bits)
+        .map_err(VanityError::InvalidInput)
+}
+
+/// Gets the fewest coin flips `coin_flips_to_mnemonic()` accepts for a phrase length
+///
+/// # Arguments
+/// * `word_count` - The phrase length (12, 15, 18, 21 or 24)
+///
+/// # Returns
+/// * `u32` - The minimum number of flips (128 for 12 words, 256 for 24), or an
+///   error for an invalid word count
+#[wasm_bindgen]
+pub fn required_coin_flips(word_count: u32) -> Result<u32, VanityError> {
+    entropy_bits(word_count).map_err(VanityError::InvalidInput)
+}
+
+/// Builds a mnemonic from six-sided die rolls
+///
+/// The rolls must carry at least as much entropy as the phrase (see
+/// `required_dice_rolls()`). They are hashed with SHA-256 as a string of the
+/// digits 1-6 and the hash is truncated to the phrase's entropy length.
+/// Rolling more than the minimum is encouraged; every roll is hashed in.
+///
+/// # Arguments
+/// * `rolls` - The rolls as digits 1-6 in the order rolled (spaces and commas are ignored)
+/// * `word_count` - The phrase length (12, 15, 18, 21 or 24)
+/// * `language` - Optional wordlist name (defaults to "english")
+///
+/// # Returns
+/// * `String` - The mnemonic phrase, or an error for invalid or insufficient rolls
+///
+/// # Example
+/// ```javascript
+/// const phrase = dice_to_mnemonic(rollsInput.value, 24, undefined);
+/// ```
+#[wasm_bindgen]
+pub fn dice_to_mnemonic(
+    rolls: &str,
+    word_count: u32,
+    language: Option<String>,
+) -> Result<String, VanityError> {
+    let rolls = parse_dice_rolls(rolls).map_err(VanityError::InvalidInput)?;
+    let required = entropy_bits(word_count)
+        .map(dice_rolls_for_bits)
+        .map_err(VanityError::InvalidInput)?;
+    check_outcomes(&rolls, required, "die roll").map_err(VanityError::InvalidInput)?;
+
+    conditioned_mnemonic(&rolls, word_count, language).map_err(VanityError::InvalidInput)
+}
+
+/// Builds a mnemonic from coin flips
+///
+/// At least one flip per bit of entropy is required (see
+/// `required_coin_flips()`). The flips are hashed with SHA-256 as a string of
+/// '1' (heads) and '0' (tails), so a biased coin is compensated for by
+/// flipping more than the minimum.
+///
+/// # Arguments
+/// * `flips` - The flips as H/T or 1/0 in the order flipped (spaces and commas are ignored)
+/// * `word_count` - The phrase length (12, 15, 18, 21 or 24)
+/// * `language` - Optional wordlist name (defaults to "english")
+///
+/// # Returns
+/// * `String` - The mnemonic phrase, or an error for invalid or insufficient flips
+///
+/// # Example
+/// ```javascript
+/// const phrase = coin_flips_to_mnemonic("HTTHHTHT...", 12, undefined);
+/// ```
+#[wasm_bindgen]
+pub fn coin_flips_to_mnemonic(
+    flips: &str,
+    word_count: u32,
+    language: Option<String>,
+) -> Result<String, VanityError> {
+    let flips = parse_coin_flips(flips).map_err(VanityError::InvalidInput)?;
+    let required = entropy_bits(word_count).map_err(VanityError::InvalidInput)?;
+    check_outcomes(&flips, required, "coin flip").map_err(VanityError::InvalidInput)?;
+
+    conditioned_mnemonic(&flips, word_count, language).map_err(VanityError::InvalidInput)
+}
@@ -0,0 +1,87 @@
+//! Mixing caller-supplied entropy into key generation
+//!
+//! Some users distrust the browser's random number generator. Rather than
+//! replace it, extra entropy they supply (a digest of mouse movements, a typed
+//! string) is combined with 32 fresh bytes from `OsRng` through HKDF-SHA256.
+//! HKDF is a sound extractor, so the result is at least as unpredictable as the
+//! stronger of the two inputs: weak or even attacker-chosen extra entropy
+//! cannot make a key worse than the default.
+
+use crate::address::check_hrp;
+use crate::crosscheck::paranoid_checked;
+use crate::error::VanityError;
+use crate::rng::fill_os_entropy;
+use crate::{hrp_or_default, keypair_from_mnemonic, AccountScheme, Keypair};
+use bip39::Mnemonic;
+use hkdf::Hkdf;
+use sha2::Sha256;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+/// HKDF salt separating this use from any other HKDF over the same input
+const MIXING_SALT: &[u8] = b"vanity-mantra entropy mixing v1";
+
+/// HKDF info naming the output
+const MIXING_INFO: &[u8] = b"bip39 entropy";
+
+/// Most extra entropy bytes accepted in one call
+const MAX_EXTRA_ENTROPY: usize = 1 << 20;
+
+/// Derives 32 bytes of mnemonic entropy from `OsRng` output and extra input
+///
+/// Fails if the `OsRng` output does not pass the continuous health tests.
+pub fn mixed_entropy(extra_entropy: &[u8]) -> Result<Zeroizing<[u8; 32]>, String> {
+    let mut input = Zeroizing::new(vec![0u8; 32]);
+    fill_os_entropy(&mut input)?;
+    input.extend_from_slice(extra_entropy);
+
+    let mut entropy = Zeroizing::new([0u8; 32]);
+    Hkdf::<Sha256>::new(Some(MIXING_SALT), &input)
+        .expand(MIXING_INFO, &mut *entropy)
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    Ok(entropy)
+}
+
+/// Generates a random keypair with caller-supplied entropy mixed in
+///
+/// The 24-word mnemonic is built from HKDF-SHA256 over 32 bytes of `OsRng`
+/// output followed by `extra_entropy`, so the key is never weaker than one
+/// from `generate_random_keypair_with_scheme()`, however poor the extra input.
+/// The extra input is not stored and cannot be recovered from the result.
+///
+/// # Arguments
+/// * `extra_entropy` - Bytes to mix in (e.g. a digest of mouse movements; at most 1 MiB)
+/// * `scheme` - The account scheme (Cosmos or EthSecp256k1)
+/// * `hrp` - Optional bech32 prefix for the address (defaults to "mantra")
+///
+/// # Returns
+/// * `Keypair` - A new keypair, or a `VanityError` for an invalid prefix, oversized input
+///   or a failed RNG health test
+///
+/// # Example
+/// ```javascript
+/// const extra = new TextEncoder().encode(mouseTrail.join(","));
+/// const keypair = generate_random_keypair_with_extra_entropy(extra, AccountScheme.Cosmos, undefined);
+/// ```
+#[wasm_bindgen]
+pub fn generate_random_keypair_with_extra_entropy(
+    extra_entropy: &[u8],
+    scheme: AccountScheme,
+    hrp: Option<String>,
+) -> Result<Keypair, VanityError> {
+    if extra_entropy.len() > MAX_EXTRA_ENTROPY {
+        return Err(VanityError::InvalidInput(format!(
+            "Extra entropy must be at most {} bytes",
+            MAX_EXTRA_ENTROPY
+        )));
+    }
+    let hrp = hrp_or_default(hrp);
+    check_hrp(&hrp)?;
+
+    let entropy = mixed_entropy(extra_entropy).map_err(VanityError::RngFailure)?;
+    let mnemonic = Mnemonic::from_entropy(&*entropy)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error building mnemonic: {}", e)))?;
+    let keypair = keypair_from_mnemonic(&mnemonic, scheme, &hrp)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))?;
+    paranoid_checked(keypair)
+}
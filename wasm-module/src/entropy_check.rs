@@ -0,0 +1,252 @@
+//! Quality checks for caller-supplied entropy
+//!
+//! The manual-entropy paths (`keypair_from_entropy()`, `entropy_to_mnemonic()`,
+//! `dice_to_mnemonic()` and `coin_flips_to_mnemonic()`) trust their input
+//! completely. No test can prove input random, but the usual mistakes (too few
+//! rolls, a typed sentence instead of random bytes, a repeated or counting
+//! pattern, a loaded die) leave clear statistical traces. These functions
+//! report them as warnings before the input is turned into a mnemonic.
+
+use crate::dice::{parse_coin_flips, parse_dice_rolls};
+use crate::error::VanityError;
+use wasm_bindgen::prelude::*;
+
+/// Fewest entropy bits a BIP39 phrase takes
+const MIN_MNEMONIC_BITS: f64 = 128.0;
+
+/// Chance below which an observed pattern is reported as suspicious
+const SUSPICION_THRESHOLD: f64 = 1e-4;
+
+/// Standard normal quantile for the chi-square test (p ≈ 0.001)
+const CHI_SQUARE_Z: f64 = 3.09;
+
+/// Findings about a piece of caller-supplied entropy
+#[wasm_bindgen]
+pub struct EntropyReport {
+    symbol_count: u32,
+    alphabet_size: u32,
+    warnings: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl EntropyReport {
+    /// Gets the number of bytes, rolls or flips analyzed
+    #[wasm_bindgen(getter)]
+    pub fn symbol_count(&self) -> u32 {
+        self.symbol_count
+    }
+
+    /// Gets the number of possible values per symbol (256, 6 or 2)
+    #[wasm_bindgen(getter)]
+    pub fn alphabet_size(&self) -> u32 {
+        self.alphabet_size
+    }
+
+    /// Gets the entropy the input carries if every symbol is uniformly random
+    #[wasm_bindgen(getter)]
+    pub fn estimated_bits(&self) -> f64 {
+        self.symbol_count as f64 * (self.alphabet_size as f64).log2()
+    }
+
+    /// Gets whether any check raised a warning
+    #[wasm_bindgen(getter)]
+    pub fn suspicious(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    /// Gets a description of each problem found
+    #[wasm_bindgen(getter)]
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.clone()
+    }
+}
+
+/// Natural log of the binomial coefficient C(n, k)
+fn ln_binomial(n: usize, k: usize) -> f64 {
+    (0..k)
+        .map(|i| ((n - i) as f64).ln() - ((i + 1) as f64).ln())
+        .sum()
+}
+
+/// Upper bound on the chance that some value occurs `count` or more times in
+/// `n` uniform symbols
+fn repeat_chance(n: usize, count: usize, alphabet: u32) -> f64 {
+    let alphabet = alphabet as f64;
+    (alphabet.ln() + ln_binomial(n, count) - count as f64 * alphabet.ln()).exp()
+}
+
+/// Chi-square critical value at p ≈ 0.001 (Wilson–Hilferty approximation)
+fn chi_square_critical(degrees_of_freedom: f64) -> f64 {
+    let spread = 2.0 / (9.0 * degrees_of_freedom);
+    degrees_of_freedom * (1.0 - spread + CHI_SQUARE_Z * spread.sqrt()).powi(3)
+}
+
+/// Runs the checks that apply to any alphabet
+///
+/// `symbols` holds values below `alphabet`.
+fn symbol_warnings(symbols: &[u8], alphabet: u32) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let n = symbols.len();
+    let bits = n as f64 * (alphabet as f64).log2();
+    if bits < MIN_MNEMONIC_BITS {
+        warnings.push(format!(
+            "Only {:.1} bits of entropy; a mnemonic needs at least {}",
+            bits, MIN_MNEMONIC_BITS
+        ));
+    }
+    if n < 2 {
+        return warnings;
+    }
+
+    if symbols.iter().all(|&symbol| symbol == symbols[0]) {
+        warnings.push("Every value is the same".to_string());
+        return warnings;
+    }
+
+    if let Some(period) =
+        (2..=n / 2).find(|&period| (period..n).all(|i| symbols[i] == symbols[i - period]))
+    {
+        warnings.push(format!("The input repeats every {} values", period));
+    }
+
+    let step = (symbols[1] as u32 + alphabet - symbols[0] as u32) % alphabet;
+    if n >= 3
+        && symbols
+            .windows(2)
+            .all(|pair| (pair[1] as u32 + alphabet - pair[0] as u32) % alphabet == step)
+    {
+        warnings.push("The values count up or down in a fixed step".to_string());
+    }
+
+    let longest_run = symbols
+        .chunk_by(|a, b| a == b)
+        .map(<[u8]>::len)
+        .max()
+        .unwrap_or(0);
+    let run_chance = n as f64 * (1.0 / alphabet as f64).powi(longest_run as i32 - 1);
+    if longest_run > 2 && run_chance < SUSPICION_THRESHOLD {
+        warnings.push(format!("{} identical values in a row", longest_run));
+    }
+
+    let mut counts = vec![0usize; alphabet as usize];
+    for &symbol in symbols {
+        counts[symbol as usize] += 1;
+    }
+    let most_common = counts.iter().copied().max().unwrap_or(0);
+    let expected = n as f64 / alphabet as f64;
+    if n >= 5 * alphabet as usize {
+        let chi_square: f64 = counts
+            .iter()
+            .map(|&count| (count as f64 - expected).powi(2) / expected)
+            .sum();
+        if chi_square > chi_square_critical((alphabet - 1) as f64) {
+            warnings.push(format!(
+                "Values are unevenly distributed (chi-square {:.1} with {} degrees of freedom); \
+                 the source may be biased",
+                chi_square,
+                alphabet - 1
+            ));
+        }
+    } else if most_common as f64 > expected
+        && repeat_chance(n, most_common, alphabet) < SUSPICION_THRESHOLD
+    {
+        warnings.push(format!(
+            "One value occurs {} times in {}; the source may be biased",
+            most_common, n
+        ));
+    }
+
+    warnings
+}
+
+/// Analyzes raw entropy bytes before they are turned into a mnemonic
+///
+/// Checks the length, repeats and counting patterns, runs of one value, the
+/// byte distribution, and whether the bytes are really text (such as a typed
+/// phrase or a hex string passed without decoding). A report without warnings
+/// does not prove the bytes random, only that they show none of these flaws.
+///
+/// # Arguments
+/// * `entropy` - The entropy bytes, as they would be passed to `keypair_from_entropy()`
+///
+/// # Returns
+/// * `EntropyReport` - The estimated entropy and any warnings
+///
+/// # Example
+/// ```javascript
+/// const report = analyze_entropy(entropy);
+/// if (report.suspicious) report.warnings.forEach((w) => console.warn(w));
+/// ```
+#[wasm_bindgen]
+pub fn analyze_entropy(entropy: &[u8]) -> EntropyReport {
+    let mut warnings = Vec::new();
+    if !matches!(entropy.len(), 16 | 20 | 24 | 28 | 32) {
+        warnings.push(format!(
+            "{} bytes is not a BIP39 entropy length (16, 20, 24, 28 or 32)",
+            entropy.len()
+        ));
+    }
+    warnings.extend(symbol_warnings(entropy, 256));
+    if entropy.len() >= 16 {
+        if entropy.iter().all(u8::is_ascii_hexdigit) {
+            warnings.push("The bytes are hex digits; decode the hex string first".to_string());
+        } else if entropy
+            .iter()
+            .all(|byte| byte.is_ascii_graphic() || *byte == b' ')
+        {
+            warnings.push("The bytes are printable text rather than random data".to_string());
+        }
+    }
+
+    EntropyReport {
+        symbol_count: entropy.len() as u32,
+        alphabet_size: 256,
+        warnings,
+    }
+}
+
+/// Analyzes die rolls before they are passed to `dice_to_mnemonic()`
+///
+/// # Arguments
+/// * `rolls` - The rolls as digits 1-6 (spaces and commas are ignored)
+///
+/// # Returns
+/// * `EntropyReport` - The estimated entropy and any warnings, or an error for
+///   characters that are not rolls
+///
+/// # Example
+/// ```javascript
+/// const report = analyze_dice_rolls(rollsInput.value);
+/// rollsHint.textContent = `${report.estimated_bits.toFixed(0)} bits`;
+/// ```
+#[wasm_bindgen]
+pub fn analyze_dice_rolls(rolls: &str) -> Result<EntropyReport, VanityError> {
+    let rolls = parse_dice_rolls(rolls).map_err(VanityError::InvalidInput)?;
+    let symbols: Vec<u8> = rolls.bytes().map(|digit| digit - b'1').collect();
+
+    Ok(EntropyReport {
+        symbol_count: symbols.len() as u32,
+        alphabet_size: 6,
+        warnings: symbol_warnings(&symbols, 6),
+    })
+}
+
+/// Analyzes coin flips before they are passed to `coin_flips_to_mnemonic()`
+///
+/// # Arguments
+/// * `flips` - The flips as H/T or 1/0 (spaces and commas are ignored)
+///
+/// # Returns
+/// * `EntropyReport` - The estimated entropy and any warnings, or an error for
+///   characters that are not flips
+#[wasm_bindgen]
+pub fn analyze_coin_flips(flips: &str) -> Result<EntropyReport, VanityError> {
+    let flips = parse_coin_flips(flips).map_err(VanityError::InvalidInput)?;
+    let symbols: Vec<u8> = flips.bytes().map(|bit| bit - b'0').collect();
+
+    Ok(EntropyReport {
+        symbol_count: symbols.len() as u32,
+        alphabet_size: 2,
+        warnings: symbol_warnings(&symbols, 2),
+    })
+}
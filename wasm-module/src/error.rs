@@ -0,0 +1,128 @@
+//! Errors thrown to JavaScript with a machine-readable code
+//!
+//! WASM builds abort on panic: the instance traps, JavaScript sees an opaque
+//! `RuntimeError: unreachable`, and any state the panic interrupted (a
+//! borrowed `RefCell`, a half-built batch) stays broken, so the worker has to
+//! be thrown away. Failures that can happen at run time (an invalid prefix, a
+//! failed RNG health test, a paranoid cross-check mismatch) are returned as
+//! `VanityError` instead. It becomes an ordinary `Error` with `name`
+//! `"VanityError"` and a stable `code` property that frontends can branch on
+//! without parsing the message. Every exported function that can fail
+//! reports its failures this way.
+//!
+//! ```javascript
+//! try {
+//!   derive_address_from_mnemonic(input, undefined);
+//! } catch (e) {
+//!   if (e.code === "InvalidMnemonic") highlightMnemonicField();
+//!   else throw e;
+//! }
+//! ```
+
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// A failure reported to JavaScript as an `Error` with a `code` property
+///
+/// The code is the variant name (e.g. `"InvalidMnemonic"`). Codes are part of
+/// the public API: new ones may be added, but existing ones keep their name
+/// and meaning. Messages are for people and may change between releases.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VanityError {
+    /// The bech32 prefix is not valid for account addresses
+    InvalidHrp(String),
+    /// An argument is out of range or malformed
+    InvalidInput(String),
+    /// The mnemonic has an unknown word, wrong word count or bad checksum
+    InvalidMnemonic(String),
+    /// The vanity target cannot appear in an address
+    InvalidTarget(String),
+    /// The derivation path is malformed
+    InvalidPath(String),
+    /// The address is not valid bech32 or has the wrong length
+    InvalidAddress(String),
+    /// A private key, public key, seed or extended key is malformed
+    InvalidKey(String),
+    /// The password or passphrase is too short
+    WeakPassword(String),
+    /// Decryption failed: wrong password, or a malformed or tampered blob
+    DecryptionFailed(String),
+    /// A sensitive operation was called without its acknowledgement flag
+    NotAcknowledged(String),
+    /// The export audit hook threw, so the export was aborted
+    AuditHookFailed(String),
+    /// The random number generator failed its continuous health tests
+    RngFailure(String),
+    /// Paranoid mode's independent re-derivation disagreed
+    CrossCheckFailed(String),
+    /// Deriving a key, encoding an address or encrypting failed
+    DerivationFailed(String),
+    /// Hardened mode refused a plaintext secret export
+    SecretsDisabled(String),
+    /// The operation needs a Cargo feature this build was compiled without
+    Unsupported(String),
+}
+
+impl VanityError {
+    /// Gets the stable code exposed to JavaScript as `error.code`
+    pub fn code(&self) -> &'static str {
+        match self {
+            VanityError::InvalidHrp(_) => "InvalidHrp",
+            VanityError::InvalidInput(_) => "InvalidInput",
+            VanityError::InvalidMnemonic(_) => "InvalidMnemonic",
+            VanityError::InvalidTarget(_) => "InvalidTarget",
+            VanityError::InvalidPath(_) => "InvalidPath",
+            VanityError::InvalidAddress(_) => "InvalidAddress",
+            VanityError::InvalidKey(_) => "InvalidKey",
+            VanityError::WeakPassword(_) => "WeakPassword",
+            VanityError::DecryptionFailed(_) => "DecryptionFailed",
+            VanityError::NotAcknowledged(_) => "NotAcknowledged",
+            VanityError::AuditHookFailed(_) => "AuditHookFailed",
+            VanityError::RngFailure(_) => "RngFailure",
+            VanityError::CrossCheckFailed(_) => "CrossCheckFailed",
+            VanityError::DerivationFailed(_) => "DerivationFailed",
+            VanityError::SecretsDisabled(_) => "SecretsDisabled",
+            VanityError::Unsupported(_) => "Unsupported",
+        }
+    }
+
+    /// Gets the human-readable message
+    pub fn message(&self) -> &str {
+        match self {
+            VanityError::InvalidHrp(message)
+            | VanityError::InvalidInput(message)
+            | VanityError::InvalidMnemonic(message)
+            | VanityError::InvalidTarget(message)
+            | VanityError::InvalidPath(message)
+            | VanityError::InvalidAddress(message)
+            | VanityError::InvalidKey(message)
+            | VanityError::WeakPassword(message)
+            | VanityError::DecryptionFailed(message)
+            | VanityError::NotAcknowledged(message)
+            | VanityError::AuditHookFailed(message)
+            | VanityError::RngFailure(message)
+            | VanityError::CrossCheckFailed(message)
+            | VanityError::DerivationFailed(message)
+            | VanityError::SecretsDisabled(message)
+            | VanityError::Unsupported(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for VanityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for VanityError {}
+
+impl From<VanityError> for JsValue {
+    fn from(error: VanityError) -> Self {
+        let js_error = js_sys::Error::new(error.message());
+        js_error.set_name("VanityError");
+        // Setting a property on a fresh Error object cannot fail
+        let _ = js_sys::Reflect::set(&js_error, &"code".into(), &error.code().into());
+        js_error.into()
+    }
+}
@@ -0,0 +1,67 @@
+//! Hardened mode: no plaintext secrets leave WASM memory
+//!
+//! A kiosk or hosted generator may want a guarantee that no script on the
+//! page, however compromised, can read a mnemonic or private key, only the
+//! encrypted archives built from them. Hardened mode provides that. It is on
+//! for the whole module when built with the `hardened` feature, or from the
+//! moment `enable_hardened_mode()` is called; once on it cannot be turned off
+//! again short of reloading the module.
+//!
+//! While hardened:
+//!
+//! - Plaintext exports (revealing a mnemonic, `to_json(true)` records, bulk
+//!   exports with mnemonics, `include_secrets` search results, raw and
+//!   extended private keys, seeds, mnemonic entropy, derivation traces,
+//!   MetaMask, Tendermint key files, SLIP-39 and BIP85 output, paper wallets,
+//!   mnemonic QR codes and mnemonic decryption) throw a `VanityError` with
+//!   code `"SecretsDisabled"`
+//! - Encrypted paths (`KeypairArchive`, `Keypair.encrypt_mnemonic()` and
+//!   `search_vanity()` with a `password`) keep working, since the secrets
+//!   never leave WASM unencrypted; `search_vanity()` requires the password
+
+use crate::error::VanityError;
+use std::sync::atomic::{AtomicBool, Ordering};
+use wasm_bindgen::prelude::*;
+
+/// Set once `enable_hardened_mode()` has been called
+static HARDENED_AT_RUNTIME: AtomicBool = AtomicBool::new(false);
+
+/// Gets whether plaintext secret exports are disabled
+///
+/// # Returns
+/// * `bool` - true in a `hardened` build or after `enable_hardened_mode()`
+#[wasm_bindgen]
+pub fn is_hardened_mode() -> bool {
+    cfg!(feature = "hardened") || HARDENED_AT_RUNTIME.load(Ordering::SeqCst)
+}
+
+/// Disables every plaintext secret getter and export until the module is reloaded
+///
+/// Call this at start-up, before any untrusted script runs. There is no way to
+/// turn hardened mode back off, so a script injected later cannot undo it.
+///
+/// # Example
+/// ```javascript
+/// await init();
+/// enable_hardened_mode();
+/// const archive = new KeypairArchive();
+/// archive.add(generate_random_keypair(undefined));
+/// const blob = archive.encrypt(password, KdfOptions.argon2id());
+/// ```
+#[wasm_bindgen]
+pub fn enable_hardened_mode() {
+    HARDENED_AT_RUNTIME.store(true, Ordering::SeqCst);
+}
+
+/// Refuses a plaintext secret export while hardened mode is on
+///
+/// `what` names the refused export in the error message.
+pub fn check_plaintext_secrets(what: &str) -> Result<(), VanityError> {
+    if is_hardened_mode() {
+        return Err(VanityError::SecretsDisabled(format!(
+            "Hardened mode is on; {} is only available encrypted",
+            what
+        )));
+    }
+    Ok(())
+}
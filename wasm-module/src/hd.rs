@@ -0,0 +1,840 @@
+//! BIP32 hierarchical deterministic key derivation
+//!
+//! This module holds the secp256k1 extended key type used by every derivation in the
+//! crate, along with the standard xprv serialization used when exporting keys to
+//! wallets that import extended keys instead of mnemonics.
+
+use crate::redact::{redact_option, Redacted};
+use hmac::{Hmac, Mac};
+use k256::{
+    ecdsa::SigningKey,
+    elliptic_curve::{sec1::ToEncodedPoint, PrimeField},
+    ProjectivePoint, PublicKey, Scalar,
+};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Offset applied to child indexes that use hardened derivation
+pub const HARDENED_OFFSET: u32 = 0x80000000;
+
+/// Version bytes for a mainnet extended private key (`xprv...`)
+const XPRV_VERSION: [u8; 4] = [0x04, 0x88, 0xAD, 0xE4];
+
+/// Version bytes for a mainnet extended public key (`xpub...`)
+const XPUB_VERSION: [u8; 4] = [0x04, 0x88, 0xB2, 0x1E];
+
+/// Standard Cosmos account path used for MANTRA: m/44'/118'/0'
+pub const COSMOS_ACCOUNT_PATH: [u32; 3] = [
+    44 + HARDENED_OFFSET,  // purpose (hardened) - BIP44
+    118 + HARDENED_OFFSET, // coin type for Cosmos (hardened)
+    HARDENED_OFFSET,       // account 0 (hardened)
+];
+
+/// Standard Cosmos address path used for MANTRA: m/44'/118'/0'/0/0
+pub const COSMOS_ADDRESS_PATH: [u32; 5] = [
+    44 + HARDENED_OFFSET,  // purpose (hardened) - BIP44
+    118 + HARDENED_OFFSET, // coin type for Cosmos (hardened)
+    HARDENED_OFFSET,       // account 0 (hardened)
+    0,                     // change 0 (non-hardened)
+    0,                     // address_index 0 (non-hardened)
+];
+
+/// Ethereum-style path used for MANTRA EVM (ethsecp256k1) accounts: m/44'/60'/0'/0/0
+pub const EVM_ADDRESS_PATH: [u32; 5] = [
+    44 + HARDENED_OFFSET, // purpose (hardened) - BIP44
+    60 + HARDENED_OFFSET, // coin type for Ethereum (hardened)
+    HARDENED_OFFSET,      // account 0 (hardened)
+    0,                    // change 0 (non-hardened)
+    0,                    // address_index 0 (non-hardened)
+];
+
+/// Formats child indexes as a BIP32 path string (e.g. `m/44'/118'/0'/0/0`)
+pub fn format_derivation_path(path: &[u32]) -> String {
+    let mut formatted = String::from("m");
+    for &index in path {
+        if index >= HARDENED_OFFSET {
+            formatted.push_str(&format!("/{}'", index - HARDENED_OFFSET));
+        } else {
+            formatted.push_str(&format!("/{}", index));
+        }
+    }
+    formatted
+}
+
+/// Maximum path depth representable in BIP32 serialization (one depth byte)
+pub const MAX_PATH_DEPTH: usize = 255;
+
+/// A derivation path error, pointing at the offending segment when there is one
+#[derive(Debug)]
+pub struct PathError {
+    message: String,
+    segment: Option<String>,
+    segment_index: Option<usize>,
+}
+
+impl PathError {
+    fn new(message: impl Into<String>) -> Self {
+        PathError {
+            message: message.into(),
+            segment: None,
+            segment_index: None,
+        }
+    }
+
+    fn at_segment(message: impl Into<String>, segment: &str, segment_index: usize) -> Self {
+        PathError {
+            message: message.into(),
+            segment: Some(segment.to_string()),
+            segment_index: Some(segment_index),
+        }
+    }
+}
+
+impl std::fmt::Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.segment, self.segment_index) {
+            (Some(segment), Some(index)) => {
+                write!(
+                    f,
+                    "{} (segment {}: \"{}\")",
+                    self.message,
+                    index + 1,
+                    segment
+                )
+            }
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// Parses a BIP32 path string (e.g. `m/44'/118'/0'/0/0`) into child indexes
+///
+/// Hardened components may be marked with `'`, `h` or `H`. Each segment must be
+/// a decimal index below 2^31, and the path may be at most `MAX_PATH_DEPTH` deep.
+pub fn parse_derivation_path(path: &str) -> Result<Vec<u32>, PathError> {
+    let mut components = path.trim().split('/');
+    if components.next() != Some("m") {
+        return Err(PathError::new(format!(
+            "Derivation path must start with \"m\": {}",
+            path
+        )));
+    }
+
+    let indexes = components
+        .enumerate()
+        .map(|(segment_index, component)| {
+            let (digits, hardened) = match component.strip_suffix(['\'', 'h', 'H']) {
+                Some(digits) => (digits, true),
+                None => (component, false),
+            };
+            if digits.is_empty() || !digits.chars().all(|ch| ch.is_ascii_digit()) {
+                return Err(PathError::at_segment(
+                    "Path segment is not a decimal index",
+                    component,
+                    segment_index,
+                ));
+            }
+
+            let index = digits
+                .parse::<u32>()
+                .ok()
+                .filter(|&index| index < HARDENED_OFFSET)
+                .ok_or_else(|| {
+                    PathError::at_segment(
+                        "Path index must be below 2^31 (use ' to mark hardened indexes)",
+                        component,
+                        segment_index,
+                    )
+                })?;
+            Ok(if hardened {
+                index + HARDENED_OFFSET
+            } else {
+                index
+            })
+        })
+        .collect::<Result<Vec<u32>, PathError>>()?;
+
+    if indexes.len() > MAX_PATH_DEPTH {
+        return Err(PathError::new(format!(
+            "Derivation path is {} levels deep (maximum is {})",
+            indexes.len(),
+            MAX_PATH_DEPTH
+        )));
+    }
+
+    Ok(indexes)
+}
+
+/// Parses a path in which every segment must be hardened (required by SLIP-0010 ed25519)
+pub fn parse_hardened_derivation_path(path: &str) -> Result<Vec<u32>, PathError> {
+    let indexes = parse_derivation_path(path)?;
+    let segments: Vec<&str> = path.trim().split('/').skip(1).collect();
+
+    match indexes.iter().position(|&index| index < HARDENED_OFFSET) {
+        Some(segment_index) => Err(PathError::at_segment(
+            "Every segment must be hardened for ed25519 derivation",
+            segments[segment_index],
+            segment_index,
+        )),
+        None => Ok(indexes),
+    }
+}
+
+/// Returns warnings for a path that no standard Cosmos or MANTRA wallet would reproduce
+pub fn cosmos_path_warnings(path: &[u32]) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    if path.len() != 5 {
+        warnings.push(format!(
+            "Path has {} levels; standard Cosmos paths have 5 (m/44'/118'/account'/change/index)",
+            path.len()
+        ));
+    }
+    if let Some(&purpose) = path.first() {
+        if purpose != 44 + HARDENED_OFFSET {
+            warnings.push("Purpose is not 44' (BIP44)".to_string());
+        }
+    }
+    if let Some(&coin_type) = path.get(1) {
+        if coin_type != 118 + HARDENED_OFFSET && coin_type != 60 + HARDENED_OFFSET {
+            warnings.push(
+                "Coin type is neither 118' (Cosmos) nor 60' (MANTRA EVM accounts)".to_string(),
+            );
+        }
+    }
+    if let Some(&account) = path.get(2) {
+        if account < HARDENED_OFFSET {
+            warnings.push("Account level is not hardened".to_string());
+        }
+    }
+    if let Some(&change) = path.get(3) {
+        if change != 0 {
+            warnings.push("Change level is not 0; Cosmos wallets only use 0".to_string());
+        }
+    }
+    if let Some(&address_index) = path.get(4) {
+        if address_index >= HARDENED_OFFSET {
+            warnings
+                .push("Address index is hardened; wallets expect a non-hardened index".to_string());
+        }
+    }
+
+    warnings
+}
+
+/// Result of validating a user-supplied derivation path
+#[wasm_bindgen]
+pub struct PathValidation {
+    valid: bool,
+    error: Option<String>,
+    offending_segment: Option<String>,
+    segment_index: Option<u32>,
+    warnings: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl PathValidation {
+    /// Gets whether the path can be derived at all
+    #[wasm_bindgen(getter)]
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Gets the error message for an invalid path
+    #[wasm_bindgen(getter)]
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    /// Gets the text of the segment that made the path invalid
+    #[wasm_bindgen(getter)]
+    pub fn offending_segment(&self) -> Option<String> {
+        self.offending_segment.clone()
+    }
+
+    /// Gets the zero-based position of the offending segment (after "m")
+    #[wasm_bindgen(getter)]
+    pub fn segment_index(&self) -> Option<u32> {
+        self.segment_index
+    }
+
+    /// Gets warnings for valid but non-standard Cosmos paths
+    #[wasm_bindgen(getter)]
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.clone()
+    }
+}
+
+/// Validates a derivation path and reports errors or non-standard choices
+///
+/// Checks hardening markers, index ranges and depth, returning the offending
+/// segment for invalid paths. Valid paths that no standard Cosmos wallet would
+/// reproduce (wrong purpose, coin type, hardening or depth) come back with
+/// warnings so users don't derive an address they can never restore elsewhere.
+///
+/// # Arguments
+/// * `path` - The BIP32 path string (e.g. `m/44'/118'/0'/0/0`)
+///
+/// # Returns
+/// * `PathValidation` - The structured verdict
+///
+/// # Example
+/// ```javascript
+/// const result = validate_derivation_path("m/44'/118'/0'/0/0");
+/// if (!result.valid) console.log(result.error, result.offending_segment);
+/// result.warnings.forEach((w) => console.warn(w));
+/// ```
+#[wasm_bindgen]
+pub fn validate_derivation_path(path: &str) -> PathValidation {
+    match parse_derivation_path(path) {
+        Ok(indexes) => PathValidation {
+            valid: true,
+            error: None,
+            offending_segment: None,
+            segment_index: None,
+            warnings: cosmos_path_warnings(&indexes),
+        },
+        Err(e) => PathValidation {
+            valid: false,
+            error: Some(e.message.clone()),
+            offending_segment: e.segment.clone(),
+            segment_index: e.segment_index.map(|index| index as u32),
+            warnings: Vec::new(),
+        },
+    }
+}
+
+/// Computes RIPEMD160(SHA256(data)), the hash used for addresses and key fingerprints
+pub fn hash160(data: &[u8]) -> [u8; 20] {
+    let sha256_hash = Sha256::digest(data);
+    Ripemd160::digest(sha256_hash).into()
+}
+
+/// Parses 32 big-endian bytes as a secp256k1 scalar, rejecting zero and values >= n
+fn nonzero_scalar(bytes: &[u8; 32]) -> Option<Scalar> {
+    Option::<Scalar>::from(Scalar::from_repr((*bytes).into()))
+        .filter(|scalar| !bool::from(scalar.is_zero()))
+}
+
+/// Returns the index to try after an invalid child, staying within the same
+/// (hardened or non-hardened) range
+fn next_child_index(index: u32) -> Result<u32, Box<dyn std::error::Error>> {
+    if index == HARDENED_OFFSET - 1 || index == u32::MAX {
+        return Err("No valid child key remains in the index range".into());
+    }
+    Ok(index + 1)
+}
+
+/// Derives the child at `index`, moving on to the next index while `try_child`
+/// reports the child as invalid
+fn first_valid_child<T>(
+    index: u32,
+    mut try_child: impl FnMut(u32) -> Result<Option<T>, Box<dyn std::error::Error>>,
+) -> Result<T, Box<dyn std::error::Error>> {
+    let mut index = index;
+    loop {
+        if let Some(child) = try_child(index)? {
+            return Ok(child);
+        }
+        index = next_child_index(index)?;
+    }
+}
+
+/// A BIP32 extended private key (private key plus chain code and tree metadata)
+#[derive(Clone)]
+pub struct ExtendedPrivateKey {
+    depth: u8,
+    // The parent fingerprint is only needed for serialization, so the parent key is
+    // kept and hashed on demand instead of on every step of the search loop
+    parent_private_key: Option<Scalar>,
+    child_number: u32,
+    chain_code: [u8; 32],
+    private_key: Scalar,
+}
+
+impl ExtendedPrivateKey {
+    /// Creates the master key from a BIP39 seed using HMAC-SHA512 with "Bitcoin seed"
+    pub fn master(seed: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(b"Bitcoin seed")
+            .map_err(|e| format!("Failed to create HMAC: {}", e))?;
+        mac.update(seed);
+        let result = Zeroizing::new(mac.finalize().into_bytes());
+
+        // Split into master private key (left 32 bytes) and chain code (right 32 bytes)
+        let mut private_key_bytes = Zeroizing::new([0u8; 32]);
+        let mut chain_code = [0u8; 32];
+        private_key_bytes.copy_from_slice(&result[0..32]);
+        chain_code.copy_from_slice(&result[32..64]);
+
+        // BIP32: if IL is 0 or >= n, the master key is invalid for this seed
+        let private_key =
+            nonzero_scalar(&private_key_bytes).ok_or("Invalid seed: master key is out of range")?;
+
+        Ok(ExtendedPrivateKey {
+            depth: 0,
+            parent_private_key: None,
+            child_number: 0,
+            chain_code,
+            private_key,
+        })
+    }
+
+    /// Parses a base58check root (depth 0) `xprv...` string
+    pub fn from_root_xprv(xprv: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let data = bs58::decode(xprv.trim())
+            .with_check(None)
+            .into_vec()
+            .map_err(|e| format!("Invalid base58check encoding: {}", e))?;
+
+        if data.len() != 78 {
+            return Err(format!("Invalid extended key length: {} bytes", data.len()).into());
+        }
+        if data[0..4] != XPRV_VERSION {
+            return Err("Not an extended private key (expected xprv version bytes)".into());
+        }
+        if data[4] != 0 {
+            return Err("Expected a root extended private key (depth 0)".into());
+        }
+        if data[45] != 0x00 {
+            return Err("Invalid private key padding in xprv".into());
+        }
+
+        let mut chain_code = [0u8; 32];
+        let mut private_key_bytes = Zeroizing::new([0u8; 32]);
+        chain_code.copy_from_slice(&data[13..45]);
+        private_key_bytes.copy_from_slice(&data[46..78]);
+        let private_key =
+            nonzero_scalar(&private_key_bytes).ok_or("Private key in xprv is out of range")?;
+
+        Ok(ExtendedPrivateKey {
+            depth: 0,
+            parent_private_key: None,
+            child_number: 0,
+            chain_code,
+            private_key,
+        })
+    }
+
+    /// Derives a single child key using proper BIP32 secp256k1 derivation
+    ///
+    /// Per BIP32, if IL >= n or the resulting key is zero, the child at `index` is
+    /// invalid and derivation proceeds with the next index, which is then recorded
+    /// as the key's serialized child number. This is astronomically rare
+    /// (probability below 2^-127), but it must never panic or yield a wrong key.
+    pub fn derive_child(&self, index: u32) -> Result<Self, Box<dyn std::error::Error>> {
+        first_valid_child(index, |index| self.try_derive_child(index))
+    }
+
+    /// Derives the child at exactly `index`, or None if that child is invalid
+    fn try_derive_child(&self, index: u32) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        Ok(self.child_from_hmac(&*self.child_hmac(index)?, index))
+    }
+
+    /// Computes the BIP32 HMAC-SHA512 output (IL || IR) for the child at `index`
+    fn child_hmac(&self, index: u32) -> Result<Zeroizing<[u8; 64]>, Box<dyn std::error::Error>> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(&self.chain_code)
+            .map_err(|e| format!("Failed to create HMAC for derivation: {}", e))?;
+
+        if index >= HARDENED_OFFSET {
+            // Hardened derivation: use 0x00 + private_key + index
+            mac.update(&[0x00]);
+            mac.update(&self.private_key.to_bytes());
+        } else {
+            // Non-hardened derivation: use compressed_public_key + index
+            mac.update(&self.public_key()?);
+        }
+
+        mac.update(&index.to_be_bytes());
+        Ok(Zeroizing::new(mac.finalize().into_bytes().into()))
+    }
+
+    /// Builds the child at `index` from its HMAC output, or None if IL >= n or the key is zero
+    fn child_from_hmac(&self, derived: &[u8; 64], index: u32) -> Option<Self> {
+        // Parse left 32 bytes as the derived key scalar (IL), rejecting IL >= n
+        let mut derived_key_bytes = Zeroizing::new([0u8; 32]);
+        derived_key_bytes.copy_from_slice(&derived[0..32]);
+        let derived_scalar =
+            Option::<Scalar>::from(Scalar::from_repr((*derived_key_bytes).into()))?;
+
+        // BIP32 key derivation: new_key = (parent_key + derived_key) mod n, rejecting zero
+        let private_key = self.private_key.add(&derived_scalar);
+        if bool::from(private_key.is_zero()) {
+            return None;
+        }
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&derived[32..64]);
+
+        Some(ExtendedPrivateKey {
+            depth: self.depth.saturating_add(1),
+            parent_private_key: Some(self.private_key),
+            child_number: index,
+            chain_code,
+            private_key,
+        })
+    }
+
+    /// Derives through each component of a path, starting from this key
+    pub fn derive_path(&self, path: &[u32]) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut key = self.clone();
+        for &index in path {
+            key = key.derive_child(index)?;
+        }
+        Ok(key)
+    }
+
+    /// Derives along a path like `derive_path`, also returning the child number
+    /// each node was actually derived at
+    ///
+    /// These differ from `path` only where an invalid child was skipped.
+    pub fn derive_path_recorded(
+        &self,
+        path: &[u32],
+    ) -> Result<(Self, Vec<u32>), Box<dyn std::error::Error>> {
+        let mut key = self.clone();
+        let mut child_numbers = Vec::with_capacity(path.len());
+        for &index in path {
+            key = key.derive_child(index)?;
+            child_numbers.push(key.child_number());
+        }
+        Ok((key, child_numbers))
+    }
+
+    /// Returns the signing key for this node
+    pub fn signing_key(&self) -> Result<SigningKey, Box<dyn std::error::Error>> {
+        SigningKey::from_bytes(&self.private_key.to_bytes())
+            .map_err(|e| format!("Failed to create signing key: {}", e).into())
+    }
+
+    /// Returns the raw 32-byte private key
+    pub fn private_key_bytes(&self) -> [u8; 32] {
+        self.private_key.to_bytes().into()
+    }
+
+    /// Returns the 32-byte chain code
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    /// Returns the index of this key below its parent
+    ///
+    /// This differs from the requested index only if that child was invalid
+    /// and derivation moved on to the next one.
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    /// Returns the 33-byte compressed public key (starts with 0x02 or 0x03)
+    pub fn public_key(&self) -> Result<[u8; 33], Box<dyn std::error::Error>> {
+        let pubkey = self.signing_key()?.verifying_key().to_encoded_point(true);
+        let mut bytes = [0u8; 33];
+        bytes.copy_from_slice(pubkey.as_bytes());
+        Ok(bytes)
+    }
+
+    /// Returns the fingerprint of the parent key, or all zeros for the master key
+    pub fn parent_fingerprint(&self) -> Result<[u8; 4], Box<dyn std::error::Error>> {
+        match self.parent_private_key {
+            Some(parent_key) => {
+                let parent = SigningKey::from_bytes(&parent_key.to_bytes())
+                    .map_err(|e| format!("Failed to create parent signing key: {}", e))?;
+                let identifier = hash160(parent.verifying_key().to_encoded_point(true).as_bytes());
+                let mut fingerprint = [0u8; 4];
+                fingerprint.copy_from_slice(&identifier[0..4]);
+                Ok(fingerprint)
+            }
+            None => Ok([0u8; 4]),
+        }
+    }
+
+    /// Serializes the key in the standard base58check `xprv...` format
+    pub fn to_xprv(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let mut data = Vec::with_capacity(78);
+        data.extend_from_slice(&XPRV_VERSION);
+        data.push(self.depth);
+        data.extend_from_slice(&self.parent_fingerprint()?);
+        data.extend_from_slice(&self.child_number.to_be_bytes());
+        data.extend_from_slice(&self.chain_code);
+        data.push(0x00);
+        data.extend_from_slice(&self.private_key.to_bytes());
+
+        Ok(bs58::encode(data).with_check().into_string())
+    }
+
+    /// Serializes the matching public key in the standard base58check `xpub...` format
+    pub fn to_xpub(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let mut data = Vec::with_capacity(78);
+        data.extend_from_slice(&XPUB_VERSION);
+        data.push(self.depth);
+        data.extend_from_slice(&self.parent_fingerprint()?);
+        data.extend_from_slice(&self.child_number.to_be_bytes());
+        data.extend_from_slice(&self.chain_code);
+        data.extend_from_slice(&self.public_key()?);
+
+        Ok(bs58::encode(data).with_check().into_string())
+    }
+}
+
+/// Wipes the private key, its parent and the chain code when the key goes out of scope
+impl Drop for ExtendedPrivateKey {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+        self.parent_private_key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+/// Shows the tree position only; the keys and chain code print as `[REDACTED]`
+impl fmt::Debug for ExtendedPrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedPrivateKey")
+            .field("depth", &self.depth)
+            .field("child_number", &self.child_number)
+            .field(
+                "parent_private_key",
+                &redact_option(&self.parent_private_key),
+            )
+            .field("chain_code", &Redacted)
+            .field("private_key", &Redacted)
+            .finish()
+    }
+}
+
+/// A BIP32 extended public key, used for watch-only derivation without private material
+pub struct ExtendedPublicKey {
+    child_number: u32,
+    chain_code: [u8; 32],
+    public_key: PublicKey,
+}
+
+impl ExtendedPublicKey {
+    /// Parses a base58check `xpub...` string
+    pub fn from_xpub(xpub: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let data = bs58::decode(xpub.trim())
+            .with_check(None)
+            .into_vec()
+            .map_err(|e| format!("Invalid base58check encoding: {}", e))?;
+
+        if data.len() != 78 {
+            return Err(format!("Invalid extended key length: {} bytes", data.len()).into());
+        }
+        if data[0..4] != XPUB_VERSION {
+            return Err("Not an extended public key (expected xpub version bytes)".into());
+        }
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&data[13..45]);
+        let public_key = PublicKey::from_sec1_bytes(&data[45..78])
+            .map_err(|e| format!("Invalid public key in xpub: {}", e))?;
+
+        Ok(ExtendedPublicKey {
+            child_number: u32::from_be_bytes([data[9], data[10], data[11], data[12]]),
+            chain_code,
+            public_key,
+        })
+    }
+
+    /// Derives a non-hardened child public key (hardened indexes require the private key)
+    ///
+    /// As with private derivation, an invalid child (IL >= n or the point at
+    /// infinity) is skipped in favour of the next index; see `child_number()`.
+    pub fn derive_child(&self, index: u32) -> Result<Self, Box<dyn std::error::Error>> {
+        if index >= HARDENED_OFFSET {
+            return Err("Cannot derive a hardened child from an extended public key".into());
+        }
+
+        first_valid_child(index, |index| self.try_derive_child(index))
+    }
+
+    /// Derives the child public key at exactly `index`, or None if that child is invalid
+    fn try_derive_child(&self, index: u32) -> Result<Option<Self>, Box<dyn std::error::Error>> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(&self.chain_code)
+            .map_err(|e| format!("Failed to create HMAC for derivation: {}", e))?;
+        mac.update(&self.public_key_bytes());
+        mac.update(&index.to_be_bytes());
+        Ok(self.child_from_hmac(&mac.finalize().into_bytes().into(), index))
+    }
+
+    /// Builds the child at `index` from its HMAC output, or None if IL >= n or
+    /// the point is at infinity
+    fn child_from_hmac(&self, derived: &[u8; 64], index: u32) -> Option<Self> {
+        let mut derived_key_bytes = [0u8; 32];
+        derived_key_bytes.copy_from_slice(&derived[0..32]);
+        let derived_scalar = Option::<Scalar>::from(Scalar::from_repr(derived_key_bytes.into()))?;
+
+        // BIP32 public derivation: child_point = IL * G + parent_point
+        let child_point =
+            ProjectivePoint::GENERATOR * derived_scalar + self.public_key.to_projective();
+        let public_key = PublicKey::from_affine(child_point.to_affine()).ok()?;
+
+        let mut chain_code = [0u8; 32];
+        chain_code.copy_from_slice(&derived[32..64]);
+
+        Some(ExtendedPublicKey {
+            child_number: index,
+            chain_code,
+            public_key,
+        })
+    }
+
+    /// Returns the index of this key below its parent
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    /// Returns the 33-byte compressed public key
+    pub fn public_key_bytes(&self) -> [u8; 33] {
+        let mut bytes = [0u8; 33];
+        bytes.copy_from_slice(self.public_key.to_encoded_point(true).as_bytes());
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Seed of BIP32 test vector 1
+    const VECTOR_1_SEED: &str = "000102030405060708090a0b0c0d0e0f";
+
+    /// The secp256k1 group order n, the smallest invalid IL
+    const CURVE_ORDER: [u8; 32] = [
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFE, 0xBA, 0xAE, 0xDC, 0xE6, 0xAF, 0x48, 0xA0, 0x3B, 0xBF, 0xD2, 0x5E, 0x8C, 0xD0, 0x36,
+        0x41, 0x41,
+    ];
+
+    fn vector_1_master() -> ExtendedPrivateKey {
+        ExtendedPrivateKey::master(&hex::decode(VECTOR_1_SEED).unwrap()).unwrap()
+    }
+
+    /// HMAC output with the given IL and an arbitrary IR
+    fn hmac_with_il(il: [u8; 32]) -> [u8; 64] {
+        let mut derived = [0x42u8; 64];
+        derived[..32].copy_from_slice(&il);
+        derived
+    }
+
+    #[test]
+    fn derives_bip32_vector_1() {
+        let master = vector_1_master();
+        assert_eq!(
+            master.to_xprv().unwrap(),
+            "xprv9s21ZrQH143K3QTDL4LXw2F7HEK3wJUD2nW2nRk4stbPy6cq3jPPqjiChkVvvNKmPGJxWUtg6LnF5kejMRNNU3TGtRBeJgk33yuGBxrMPHi"
+        );
+        assert_eq!(
+            master.to_xpub().unwrap(),
+            "xpub661MyMwAqRbcFtXgS5sYJABqqG9YLmC4Q1Rdap9gSE8NqtwybGhePY2gZ29ESFjqJoCu1Rupje8YtGqsefD265TMg7usUDFdp6W1EGMcet8"
+        );
+
+        let chain = [
+            (
+                vec![HARDENED_OFFSET],
+                "xprv9uHRZZhk6KAJC1avXpDAp4MDc3sQKNxDiPvvkX8Br5ngLNv1TxvUxt4cV1rGL5hj6KCesnDYUhd7oWgT11eZG7XnxHrnYeSvkzY7d2bhkJ7",
+            ),
+            (
+                vec![HARDENED_OFFSET, 1],
+                "xprv9wTYmMFdV23N2TdNG573QoEsfRrWKQgWeibmLntzniatZvR9BmLnvSxqu53Kw1UmYPxLgboyZQaXwTCg8MSY3H2EU4pWcQDnRnrVA1xe8fs",
+            ),
+            (
+                vec![HARDENED_OFFSET, 1, 2 + HARDENED_OFFSET],
+                "xprv9z4pot5VBttmtdRTWfWQmoH1taj2axGVzFqSb8C9xaxKymcFzXBDptWmT7FwuEzG3ryjH4ktypQSAewRiNMjANTtpgP4mLTj34bhnZX7UiM",
+            ),
+            (
+                vec![HARDENED_OFFSET, 1, 2 + HARDENED_OFFSET, 2],
+                "xprvA2JDeKCSNNZky6uBCviVfJSKyQ1mDYahRjijr5idH2WwLsEd4Hsb2Tyh8RfQMuPh7f7RtyzTtdrbdqqsunu5Mm3wDvUAKRHSC34sJ7in334",
+            ),
+            (
+                vec![HARDENED_OFFSET, 1, 2 + HARDENED_OFFSET, 2, 1_000_000_000],
+                "xprvA41z7zogVVwxVSgdKUHDy1SKmdb533PjDz7J6N6mV6uS3ze1ai8FHa8kmHScGpWmj4WggLyQjgPie1rFSruoUihUZREPSL39UNdE3BBDu76",
+            ),
+        ];
+        for (path, xprv) in chain {
+            assert_eq!(master.derive_path(&path).unwrap().to_xprv().unwrap(), xprv);
+        }
+    }
+
+    #[test]
+    fn public_derivation_matches_private_derivation() {
+        let account = vector_1_master().derive_child(HARDENED_OFFSET).unwrap();
+        let watch_only = ExtendedPublicKey::from_xpub(&account.to_xpub().unwrap()).unwrap();
+
+        let private_child = account.derive_child(1).unwrap();
+        let public_child = watch_only.derive_child(1).unwrap();
+        assert_eq!(
+            public_child.public_key_bytes(),
+            private_child.public_key().unwrap()
+        );
+        assert_eq!(public_child.child_number(), 1);
+    }
+
+    #[test]
+    fn rejects_il_at_or_above_curve_order() {
+        let master = vector_1_master();
+        assert!(master
+            .child_from_hmac(&hmac_with_il(CURVE_ORDER), 0)
+            .is_none());
+        assert!(master
+            .child_from_hmac(&hmac_with_il([0xFF; 32]), 0)
+            .is_none());
+
+        // IL = n - k makes the child key zero
+        let zeroing_il: [u8; 32] = (-master.private_key).to_bytes().into();
+        assert!(master
+            .child_from_hmac(&hmac_with_il(zeroing_il), 0)
+            .is_none());
+
+        let watch_only = ExtendedPublicKey::from_xpub(&master.to_xpub().unwrap()).unwrap();
+        assert!(watch_only
+            .child_from_hmac(&hmac_with_il(CURVE_ORDER), 0)
+            .is_none());
+    }
+
+    #[test]
+    fn invalid_child_moves_to_next_index() {
+        let master = vector_1_master();
+        let child = first_valid_child(7, |index| {
+            if index == 7 {
+                Ok(master.child_from_hmac(&hmac_with_il(CURVE_ORDER), index))
+            } else {
+                master.try_derive_child(index)
+            }
+        })
+        .unwrap();
+
+        assert_eq!(child.child_number(), 8);
+        assert_eq!(
+            child.to_xprv().unwrap(),
+            master.derive_child(8).unwrap().to_xprv().unwrap()
+        );
+    }
+
+    #[test]
+    fn recorded_path_matches_derive_path() {
+        let master = vector_1_master();
+        let path = [HARDENED_OFFSET, 1, HARDENED_OFFSET + 2, 2];
+        let (key, child_numbers) = master.derive_path_recorded(&path).unwrap();
+
+        assert_eq!(child_numbers, path);
+        assert_eq!(
+            key.to_xprv().unwrap(),
+            master.derive_path(&path).unwrap().to_xprv().unwrap()
+        );
+    }
+
+    #[test]
+    fn exhausted_index_range_is_an_error() {
+        assert_eq!(next_child_index(5).unwrap(), 6);
+        assert!(next_child_index(HARDENED_OFFSET - 1).is_err());
+        assert!(next_child_index(u32::MAX).is_err());
+
+        let always_invalid = |_| Ok(None::<ExtendedPrivateKey>);
+        assert!(first_valid_child(HARDENED_OFFSET - 2, always_invalid).is_err());
+        assert!(first_valid_child(u32::MAX - 1, always_invalid).is_err());
+    }
+}
@@ -0,0 +1,129 @@
+//! Continuous health tests on key-generation randomness
+//!
+//! A broken entropy source (a stubbed `crypto.getRandomValues`, a polyfill
+//! returning zeros, a VM snapshot replaying its state) still produces
+//! well-formed mnemonics, so nothing downstream would notice. Every block of
+//! `OsRng` output used for a key is therefore run through lightweight
+//! continuous tests modelled on NIST SP 800-90B section 4.4 before it is used:
+//!
+//! - Repetition count: the same byte repeated `REPETITION_CUTOFF` times in a row
+//! - Adaptive proportion: one byte value taking up too much of a 512-byte window
+//! - Stuck output: a block identical to the previous one (FIPS 140-2 style)
+//!
+//! Cutoffs assume 8 bits of entropy per byte with a false-alarm rate of 2^-40.
+//! A failure is latched: key generation keeps failing until the module is
+//! reloaded, since a source that failed once cannot be trusted again.
+
+use sha2::{Digest, Sha256};
+use std::cell::RefCell;
+use wasm_bindgen::prelude::*;
+
+/// Consecutive identical bytes that fail the repetition count test
+const REPETITION_CUTOFF: u32 = 6;
+
+/// Bytes per adaptive proportion window
+const PROPORTION_WINDOW: u32 = 512;
+
+/// Occurrences of a window's first byte that fail the adaptive proportion test
+const PROPORTION_CUTOFF: u32 = 19;
+
+/// Running state of the continuous tests
+#[derive(Default)]
+struct HealthState {
+    /// Most recent byte and how many times in a row it has appeared
+    last_byte: Option<u8>,
+    repetitions: u32,
+    /// Byte the current window counts, its count and the window position
+    window_byte: u8,
+    window_count: u32,
+    window_seen: u32,
+    /// Digest of the previous block, so no past entropy is kept
+    previous_digest: Option<[u8; 32]>,
+    /// The first failure, latched for the life of the module
+    failure: Option<String>,
+}
+
+impl HealthState {
+    /// Feeds one byte through the repetition count and adaptive proportion tests
+    fn check_byte(&mut self, byte: u8) -> Result<(), String> {
+        if self.last_byte == Some(byte) {
+            self.repetitions += 1;
+            if self.repetitions >= REPETITION_CUTOFF {
+                return Err(format!(
+                    "repetition count test failed: byte 0x{:02x} repeated {} times",
+                    byte, self.repetitions
+                ));
+            }
+        } else {
+            self.last_byte = Some(byte);
+            self.repetitions = 1;
+        }
+
+        if self.window_seen == 0 {
+            self.window_byte = byte;
+            self.window_count = 1;
+        } else if byte == self.window_byte {
+            self.window_count += 1;
+            if self.window_count >= PROPORTION_CUTOFF {
+                return Err(format!(
+                    "adaptive proportion test failed: byte 0x{:02x} seen {} times in {} bytes",
+                    byte, self.window_count, PROPORTION_WINDOW
+                ));
+            }
+        }
+        self.window_seen = (self.window_seen + 1) % PROPORTION_WINDOW;
+        Ok(())
+    }
+
+    /// Runs all tests over one block of output
+    fn check_block(&mut self, bytes: &[u8]) -> Result<(), String> {
+        let digest: [u8; 32] = Sha256::digest(bytes).into();
+        if self.previous_digest == Some(digest) {
+            return Err(
+                "stuck output test failed: block identical to the previous one".to_string(),
+            );
+        }
+        self.previous_digest = Some(digest);
+
+        bytes.iter().try_for_each(|&byte| self.check_byte(byte))
+    }
+}
+
+thread_local! {
+    static HEALTH: RefCell<HealthState> = RefCell::new(HealthState::default());
+}
+
+/// Runs the continuous health tests over a block of fresh RNG output
+///
+/// Returns an error, and keeps returning it, once any test has failed.
+pub fn check_rng_output(bytes: &[u8]) -> Result<(), String> {
+    HEALTH.with(|cell| {
+        let mut state = cell.borrow_mut();
+        if let Some(failure) = &state.failure {
+            return Err(format!("RNG health test failed earlier: {}", failure));
+        }
+        state.check_block(bytes).map_err(|failure| {
+            state.failure = Some(failure.clone());
+            format!("RNG health test failed: {}", failure)
+        })
+    })
+}
+
+/// Reports why the RNG health tests stopped key generation, if they have
+///
+/// Once a health test fails, every key-generating function throws until the
+/// page or worker is reloaded. A frontend can check this to explain the
+/// failure instead of showing a generic error.
+///
+/// # Returns
+/// * `Option<String>` - The latched failure, or undefined if the RNG is healthy
+///
+/// # Example
+/// ```javascript
+/// const failure = rng_health_failure();
+/// if (failure) showError(`This browser's random number generator looks broken: ${failure}`);
+/// ```
+#[wasm_bindgen]
+pub fn rng_health_failure() -> Option<String> {
+    HEALTH.with(|cell| cell.borrow().failure.clone())
+}
@@ -0,0 +1,232 @@
+//! Versioned JSON records of keypairs
+//!
+//! Frontends and the CLI persist search results between sessions. The record
+//! layout is fixed and carries a schema version, so results saved by one
+//! release can be reloaded by the next. Loading re-derives the address from
+//! the stored key material and refuses records that do not add up.
+
+use crate::address::decode_bech32;
+use crate::audit::audit_export;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::hd::parse_derivation_path;
+use crate::mnemonic::parse_mnemonic;
+use crate::redact::redact_option;
+use crate::schema::{upgrade_document, SCHEMA_VERSION};
+use crate::{derive_key_at_path, AccountScheme, Keypair};
+use k256::ecdsa::SigningKey;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+/// On-disk form of a keypair
+#[derive(Serialize, Deserialize, Tsify)]
+pub struct KeypairRecord {
+    /// Schema version of the record
+    schema_version: u64,
+    /// Bech32 address
+    address: String,
+    /// Account scheme ("cosmos" or "ethsecp256k1")
+    scheme: String,
+    /// BIP39 mnemonic, absent for imported raw keys
+    mnemonic: Option<String>,
+    /// Derivation path (e.g. "m/44'/118'/0'/0/0"), when known
+    derivation_path: Option<String>,
+    /// Compressed public key as hex, when known
+    public_key: Option<String>,
+    /// Raw private key as hex, only for an imported raw key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    private_key: Option<String>,
+}
+
+/// Shows public fields only; the mnemonic and private key print as `[REDACTED]`
+impl fmt::Debug for KeypairRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeypairRecord")
+            .field("schema_version", &self.schema_version)
+            .field("address", &self.address)
+            .field("scheme", &self.scheme)
+            .field("mnemonic", &redact_option(&self.mnemonic))
+            .field("derivation_path", &self.derivation_path)
+            .field("public_key", &self.public_key)
+            .field("private_key", &redact_option(&self.private_key))
+            .finish()
+    }
+}
+
+/// Parses a stored account scheme name
+fn parse_scheme(name: &str) -> Result<AccountScheme, String> {
+    match name {
+        "cosmos" => Ok(AccountScheme::Cosmos),
+        "ethsecp256k1" => Ok(AccountScheme::EthSecp256k1),
+        other => Err(format!("Unknown account scheme: {:?}", other)),
+    }
+}
+
+/// Rebuilds a keypair from a record, checking that its fields agree
+fn keypair_from_record(record: KeypairRecord) -> Result<Keypair, String> {
+    let scheme = parse_scheme(&record.scheme)?;
+    let (hrp, _) = decode_bech32(&record.address).map_err(|e| format!("Invalid address: {}", e))?;
+    let derivation_path = record
+        .derivation_path
+        .as_deref()
+        .map(parse_derivation_path)
+        .transpose()
+        .map_err(|e| format!("Invalid derivation path: {}", e))?;
+
+    let public_key = match (&record.mnemonic, &record.public_key) {
+        (Some(mnemonic), _) => {
+            let mnemonic =
+                parse_mnemonic(mnemonic).map_err(|e| format!("Invalid mnemonic: {}", e))?;
+            let path = derivation_path
+                .as_deref()
+                .unwrap_or(scheme.derivation_path());
+            let derived = derive_key_at_path(&mnemonic, path)
+                .and_then(|key| key.public_key())
+                .map_err(|e| format!("Error deriving key: {}", e))?;
+            if let Some(stored) = &record.public_key {
+                if *stored != hex::encode(derived) {
+                    return Err("Public key does not match the mnemonic".to_string());
+                }
+            }
+            derived
+        }
+        (None, Some(public_key)) => hex::decode(public_key)
+            .ok()
+            .and_then(|bytes| <[u8; 33]>::try_from(bytes).ok())
+            .ok_or("Public key must be 33 bytes of hex")?,
+        (None, None) => return Err("Record needs a mnemonic or a public key".to_string()),
+    };
+
+    if let Some(private_key) = &record.private_key {
+        let signing_key = hex::decode(private_key)
+            .ok()
+            .and_then(|bytes| SigningKey::from_slice(&bytes).ok())
+            .ok_or("Private key must be a valid 32-byte hex key")?;
+        if signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes()
+            != public_key
+        {
+            return Err("Private key does not match the public key".to_string());
+        }
+    }
+
+    let address = scheme
+        .address(&public_key, &hrp)
+        .map_err(|e| format!("Error encoding address: {}", e))?;
+    if address != record.address {
+        return Err(format!(
+            "Address {} does not match its key material (expected {})",
+            record.address, address
+        ));
+    }
+
+    Ok(Keypair {
+        address,
+        mnemonic: record.mnemonic.map(|mnemonic| mnemonic.trim().to_string()),
+        public_key: Some(public_key),
+        derivation_path,
+        scheme,
+        private_key_hex: record.private_key,
+    })
+}
+
+/// Parses, upgrades and checks a keypair record
+pub fn keypair_from_json(json: &str) -> Result<Keypair, String> {
+    let record = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let record: KeypairRecord =
+        serde_json::from_value(upgrade_document(record)?).map_err(|e| e.to_string())?;
+    keypair_from_record(record)
+}
+
+#[wasm_bindgen]
+impl Keypair {
+    /// Serializes the keypair as a versioned JSON record
+    ///
+    /// The record holds the address, scheme, derivation path and public key
+    /// (hex). Only with `include_secrets` does it also carry the mnemonic, and
+    /// the raw private key of an imported raw key; that export is refused in
+    /// hardened mode and reported to the hook registered with
+    /// `set_export_audit_hook()`. Without secrets the record reloads as a
+    /// watch-only keypair.
+    ///
+    /// # Arguments
+    /// * `include_secrets` - true to include the mnemonic and any imported private key
+    ///
+    /// # Returns
+    /// * `String` - The JSON record, or an error if secrets were requested in
+    ///   hardened mode or the audit hook failed
+    ///
+    /// # Example
+    /// ```javascript
+    /// localStorage.setItem("watch-only", keypair.to_json(false));
+    /// backupFile.write(keypair.to_json(userConfirmed));
+    /// ```
+    pub fn to_json(&self, include_secrets: bool) -> Result<String, VanityError> {
+        let record = if include_secrets {
+            self.to_secret_record("keypair_json")?
+        } else {
+            self.to_public_record()
+        };
+        serde_json::to_string(&record)
+            .map_err(|e| VanityError::DerivationFailed(format!("Error serializing keypair: {}", e)))
+    }
+
+    /// Loads a keypair from a JSON record written by `to_json()`
+    ///
+    /// The address is re-derived from the mnemonic (or public key) and must
+    /// match the stored one, so corrupted or hand-edited records are rejected.
+    /// Records written by older builds are upgraded to the current schema.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON record
+    ///
+    /// # Returns
+    /// * `Keypair` - The keypair, or an error if the record is invalid or inconsistent
+    ///
+    /// # Example
+    /// ```javascript
+    /// const keypair = Keypair.from_json(localStorage.getItem("result"));
+    /// ```
+    pub fn from_json(json: &str) -> Result<Keypair, VanityError> {
+        keypair_from_json(json)
+            .map_err(|e| VanityError::InvalidInput(format!("Invalid keypair record: {}", e)))
+    }
+}
+
+impl Keypair {
+    /// Builds the versioned record of this keypair
+    pub fn to_record(&self) -> KeypairRecord {
+        KeypairRecord {
+            schema_version: SCHEMA_VERSION,
+            address: self.address.clone(),
+            scheme: self.scheme.name().to_string(),
+            mnemonic: self.mnemonic.clone(),
+            derivation_path: self.derivation_path(),
+            public_key: self.public_key.map(hex::encode),
+            private_key: self.private_key_hex.clone(),
+        }
+    }
+
+    /// Builds the record without the mnemonic or private key
+    pub fn to_public_record(&self) -> KeypairRecord {
+        KeypairRecord {
+            mnemonic: None,
+            private_key: None,
+            ..self.to_record()
+        }
+    }
+
+    /// Builds the full record for export to JavaScript
+    ///
+    /// Refused in hardened mode; `event` is reported to the audit hook.
+    pub fn to_secret_record(&self, event: &str) -> Result<KeypairRecord, VanityError> {
+        check_plaintext_secrets("a keypair record with secrets")?;
+        audit_export(event, Some(&self.address))?;
+        Ok(self.to_record())
+    }
+}
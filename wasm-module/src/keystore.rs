@@ -0,0 +1,1236 @@
+//! Encrypted wallet exports
+//!
+//! Handing a raw mnemonic to another wallet means pasting it in plaintext.
+//! This module instead encrypts it under a user password in formats other
+//! wallet software understands: Keplr's keystore, CosmJS's wallet
+//! serialization, the Cosmos SDK's armored key file and the CryptoJS formats
+//! of Cosmostation and Leap. It also has a small blob format of its own for
+//! saving search results, either a single mnemonic or an archive of keypairs.
+
+use crate::address::check_hrp;
+use crate::audit::audit_export;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::hd::{format_derivation_path, COSMOS_ADDRESS_PATH};
+use crate::keypair_json::keypair_from_json;
+use crate::mnemonic::parse_mnemonic;
+use crate::schema::{upgrade_document, SCHEMA_VERSION};
+use crate::{derive_key_at_path, hrp_or_default, Keypair};
+use aes::Aes256;
+use aes_gcm::{Aes256Gcm, Nonce};
+use argon2::{Algorithm, Argon2, Params, Version};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use cbc::cipher::{block_padding::Pkcs7, BlockEncryptMut};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::XChaCha20Poly1305;
+use crypto_secretbox::XSalsa20Poly1305;
+use ctr::cipher::{KeyIvInit, StreamCipher};
+use md5::Md5;
+use rand::{rngs::OsRng, RngCore};
+use serde_json::{json, Value};
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+/// AES-CTR stream cipher with a 128-bit big-endian counter
+type Aes256Ctr = ctr::Ctr128BE<Aes256>;
+
+/// AES-256 in CBC mode, as used by CryptoJS
+type Aes256CbcEnc = cbc::Encryptor<Aes256>;
+
+/// Keplr keystore format version
+const KEPLR_KEYSTORE_VERSION: &str = "1.2";
+
+/// scrypt cost parameters used by Keplr (log2(N) = 17, r = 8, p = 1)
+const KEPLR_SCRYPT_LOG_N: u8 = 17;
+const KEPLR_SCRYPT_R: u32 = 8;
+const KEPLR_SCRYPT_P: u32 = 1;
+
+/// Fixed Argon2id salt CosmJS uses for password-based wallet serialization
+const COSMJS_SALT: &[u8] = b"The CosmJS salt.";
+
+/// Argon2id cost parameters of CosmJS `basicPasswordHashingOptions`
+const COSMJS_ARGON2_OPS_LIMIT: u32 = 24;
+const COSMJS_ARGON2_MEM_LIMIT_KIB: u32 = 12 * 1024;
+
+/// Length of the XChaCha20-Poly1305 nonce prepended to the ciphertext
+const XCHACHA20_NONCE_LENGTH: usize = 24;
+
+/// Armor block type of Cosmos SDK private key files
+const ARMOR_PRIVATE_KEY_BLOCK: &str = "TENDERMINT PRIVATE KEY";
+
+/// bcrypt cost the Cosmos SDK uses to stretch the armor passphrase
+const ARMOR_BCRYPT_COST: u32 = 12;
+
+/// Amino prefix of `tendermint/PrivKeySecp256k1`
+const SECP256K1_PRIVATE_KEY_AMINO_PREFIX: [u8; 4] = [0xE1, 0xB0, 0xF7, 0x9B];
+
+/// Prefix of OpenSSL (and CryptoJS) passphrase-encrypted data
+const OPENSSL_SALTED_MAGIC: &[u8] = b"Salted__";
+
+/// PBKDF2 iterations of Leap's keychain encryption
+const LEAP_PBKDF2_ITERATIONS: u32 = 100;
+
+/// Shortest password accepted for any encrypted export, in characters
+pub const MIN_PASSWORD_CHARS: usize = 8;
+
+/// Content tag of blobs holding a keypair archive rather than a mnemonic
+const KEYPAIRS_CONTENT: &str = "keypairs";
+
+/// Argon2id cost parameters of the encrypted mnemonic blob (64 MiB, 3 passes)
+const BLOB_ARGON2_MEM_KIB: u32 = 64 * 1024;
+const BLOB_ARGON2_TIME_COST: u32 = 3;
+const BLOB_ARGON2_PARALLELISM: u32 = 1;
+
+/// Smallest scrypt cost accepted for exports (N = 16384)
+const MIN_SCRYPT_LOG_N: u8 = 14;
+
+/// Largest scrypt costs accepted (N = 1048576, r = 32, p = 16)
+const MAX_SCRYPT_LOG_N: u8 = 20;
+const MAX_SCRYPT_R: u32 = 32;
+const MAX_SCRYPT_P: u32 = 16;
+
+/// Smallest Argon2id memory cost accepted for exports (19 MiB)
+const MIN_ARGON2_MEM_KIB: u32 = 19 * 1024;
+
+/// Largest Argon2id costs accepted, so an untrusted blob cannot demand
+/// unbounded memory or time (1 GiB, 16 passes, 4 lanes)
+const MAX_ARGON2_MEM_KIB: u32 = 1024 * 1024;
+const MAX_ARGON2_TIME_COST: u32 = 16;
+const MAX_ARGON2_PARALLELISM: u32 = 4;
+
+/// Password key derivation settings for the encrypted exports
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Kdf {
+    Argon2id {
+        m_cost: u32,
+        t_cost: u32,
+        p_cost: u32,
+    },
+    Scrypt {
+        log_n: u8,
+        r: u32,
+        p: u32,
+    },
+}
+
+/// Key derivation function and cost parameters for an encrypted export
+///
+/// `encrypt_mnemonic()` accepts either KDF; the Keplr keystore format only
+/// supports scrypt. Omitting the options selects each format's default.
+#[wasm_bindgen]
+#[derive(Copy, Clone, Debug)]
+pub struct KdfOptions {
+    kdf: Kdf,
+}
+
+#[wasm_bindgen]
+impl KdfOptions {
+    /// Argon2id with the given memory (KiB), passes and lanes
+    ///
+    /// Memory must be between 19456 KiB (19 MiB) and 1048576 KiB (1 GiB), with
+    /// at most 16 passes and 4 lanes.
+    pub fn argon2id(
+        memory_kib: u32,
+        iterations: u32,
+        parallelism: u32,
+    ) -> Result<KdfOptions, VanityError> {
+        if memory_kib < MIN_ARGON2_MEM_KIB {
+            return Err(VanityError::InvalidInput(format!(
+                "Argon2id memory must be at least {} KiB",
+                MIN_ARGON2_MEM_KIB
+            )));
+        }
+        Params::new(memory_kib, iterations, parallelism, Some(32))
+            .map_err(|e| VanityError::InvalidInput(format!("Invalid Argon2 parameters: {}", e)))?;
+
+        let kdf = Kdf::Argon2id {
+            m_cost: memory_kib,
+            t_cost: iterations,
+            p_cost: parallelism,
+        };
+        kdf.check_limits().map_err(VanityError::InvalidInput)?;
+        Ok(KdfOptions { kdf })
+    }
+
+    /// scrypt with cost N = 2^log_n, block size r and parallelism p
+    ///
+    /// `log_n` must be between 14 and 20, `r` at most 32 and `p` at most 16;
+    /// Keplr itself uses 17 with r = 8 and p = 1.
+    pub fn scrypt(log_n: u8, r: u32, p: u32) -> Result<KdfOptions, VanityError> {
+        if log_n < MIN_SCRYPT_LOG_N {
+            return Err(VanityError::InvalidInput(format!(
+                "scrypt log_n must be at least {}",
+                MIN_SCRYPT_LOG_N
+            )));
+        }
+        scrypt::Params::new(log_n, r, p, 32)
+            .map_err(|e| VanityError::InvalidInput(format!("Invalid scrypt parameters: {}", e)))?;
+
+        let kdf = Kdf::Scrypt { log_n, r, p };
+        kdf.check_limits().map_err(VanityError::InvalidInput)?;
+        Ok(KdfOptions { kdf })
+    }
+
+    /// Gets the KDF name as stored in exports ("argon2id" or "scrypt")
+    #[wasm_bindgen(getter)]
+    pub fn algorithm(&self) -> String {
+        self.kdf.name().to_string()
+    }
+}
+
+impl Kdf {
+    /// Name of the KDF as stored in exports
+    fn name(&self) -> &'static str {
+        match self {
+            Kdf::Argon2id { .. } => "argon2id",
+            Kdf::Scrypt { .. } => "scrypt",
+        }
+    }
+
+    /// Rejects costs above the accepted maximums
+    fn check_limits(&self) -> Result<(), String> {
+        match *self {
+            Kdf::Argon2id {
+                m_cost,
+                t_cost,
+                p_cost,
+            } => {
+                if m_cost > MAX_ARGON2_MEM_KIB {
+                    return Err(format!(
+                        "Argon2id memory must be at most {} KiB, got {}",
+                        MAX_ARGON2_MEM_KIB, m_cost
+                    ));
+                }
+                if t_cost > MAX_ARGON2_TIME_COST {
+                    return Err(format!(
+                        "Argon2id iterations must be at most {}, got {}",
+                        MAX_ARGON2_TIME_COST, t_cost
+                    ));
+                }
+                if p_cost > MAX_ARGON2_PARALLELISM {
+                    return Err(format!(
+                        "Argon2id parallelism must be at most {}, got {}",
+                        MAX_ARGON2_PARALLELISM, p_cost
+                    ));
+                }
+                Ok(())
+            }
+            Kdf::Scrypt { log_n, r, p } => {
+                if log_n > MAX_SCRYPT_LOG_N {
+                    return Err(format!(
+                        "scrypt log_n must be at most {}, got {}",
+                        MAX_SCRYPT_LOG_N, log_n
+                    ));
+                }
+                if r > MAX_SCRYPT_R {
+                    return Err(format!(
+                        "scrypt r must be at most {}, got {}",
+                        MAX_SCRYPT_R, r
+                    ));
+                }
+                if p > MAX_SCRYPT_P {
+                    return Err(format!(
+                        "scrypt p must be at most {}, got {}",
+                        MAX_SCRYPT_P, p
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Derives a 32-byte key from the password
+    fn derive_key(&self, password: &str, salt: &[u8]) -> Result<[u8; 32], String> {
+        match *self {
+            Kdf::Argon2id {
+                m_cost,
+                t_cost,
+                p_cost,
+            } => argon2id_key(password, salt, m_cost, t_cost, p_cost),
+            Kdf::Scrypt { log_n, r, p } => {
+                let params = scrypt::Params::new(log_n, r, p, 32)
+                    .map_err(|e| format!("Invalid scrypt parameters: {}", e))?;
+                let mut key = [0u8; 32];
+                scrypt::scrypt(password.as_bytes(), salt, &params, &mut key)
+                    .map_err(|e| format!("Error deriving key: {}", e))?;
+                Ok(key)
+            }
+        }
+    }
+
+    /// Writes the salt and cost parameters as a `kdfparams` object
+    fn params_json(&self, salt: &[u8]) -> Value {
+        match *self {
+            Kdf::Argon2id {
+                m_cost,
+                t_cost,
+                p_cost,
+            } => json!({
+                "salt": hex::encode(salt),
+                "m_cost": m_cost,
+                "t_cost": t_cost,
+                "p_cost": p_cost,
+            }),
+            Kdf::Scrypt { log_n, r, p } => json!({
+                "salt": hex::encode(salt),
+                "dklen": 32,
+                "n": 1u64 << log_n,
+                "r": r,
+                "p": p,
+            }),
+        }
+    }
+
+    /// Reads the KDF named `name` from a `kdfparams` object, rejecting costs
+    /// above the accepted maximums before any key is derived
+    fn from_json(name: &Value, params: &Value) -> Result<Kdf, String> {
+        let kdf = match name.as_str() {
+            Some("argon2id") => Kdf::Argon2id {
+                m_cost: u32_field(params, "m_cost")?,
+                t_cost: u32_field(params, "t_cost")?,
+                p_cost: u32_field(params, "p_cost")?,
+            },
+            Some("scrypt") => {
+                let n = u32_field(params, "n")?;
+                if !n.is_power_of_two() || n < 2 {
+                    return Err(format!("scrypt n must be a power of two, got {}", n));
+                }
+                Kdf::Scrypt {
+                    log_n: n.trailing_zeros() as u8,
+                    r: u32_field(params, "r")?,
+                    p: u32_field(params, "p")?,
+                }
+            }
+            _ => return Err(format!("Unsupported KDF: {}", name)),
+        };
+        kdf.check_limits()?;
+        Ok(kdf)
+    }
+}
+
+/// Exports a mnemonic as a Keplr keystore, encrypted under a password
+///
+/// Produces the version 1.2 `KeyStore` JSON Keplr uses for mnemonic
+/// accounts: the phrase is encrypted with AES-CTR under a 32-byte scrypt key
+/// (N = 131072, r = 8, p = 1, random 32-byte salt, random 16-byte IV), and the
+/// MAC is SHA256(second half of the key || ciphertext). Keplr labels the
+/// cipher "aes-128-ctr" even though the full 32-byte key is used, and that
+/// label is kept for compatibility. Key derivation needs about 128 MiB of
+/// memory and takes a few seconds, so run it in a worker. Other scrypt costs
+/// can be chosen with `KdfOptions.scrypt()`.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase to encrypt
+/// * `password` - The password protecting the keystore (at least 8 characters)
+/// * `name` - Optional account name stored in the keystore metadata
+/// * `kdf` - Optional scrypt settings (defaults to N = 131072, r = 8, p = 1)
+///
+/// # Returns
+/// * `String` - The keystore JSON, or an error for an invalid mnemonic or weak password
+///
+/// # Example
+/// ```javascript
+/// const keystore = export_keplr_keystore(keypair.reveal_mnemonic(false), password, "Vanity wallet", undefined);
+/// download("keystore.json", keystore);
+/// ```
+#[wasm_bindgen]
+pub fn export_keplr_keystore(
+    mnemonic_str: &str,
+    password: &str,
+    name: Option<String>,
+    kdf: Option<KdfOptions>,
+) -> Result<String, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    check_password(password)?;
+    let kdf = kdf.map_or(
+        Kdf::Scrypt {
+            log_n: KEPLR_SCRYPT_LOG_N,
+            r: KEPLR_SCRYPT_R,
+            p: KEPLR_SCRYPT_P,
+        },
+        |options| options.kdf,
+    );
+    if !matches!(kdf, Kdf::Scrypt { .. }) {
+        return Err(VanityError::InvalidInput(
+            "Keplr keystores only support the scrypt KDF".to_string(),
+        ));
+    }
+
+    let mut salt = [0u8; 32];
+    let mut iv = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut iv);
+
+    let derived_key = kdf
+        .derive_key(password, &salt)
+        .map_err(VanityError::DerivationFailed)?;
+
+    let mut ciphertext = mnemonic.to_string().into_bytes();
+    Aes256Ctr::new(&derived_key.into(), &iv.into()).apply_keystream(&mut ciphertext);
+
+    let mut hasher = Sha256::new();
+    hasher.update(&derived_key[16..]);
+    hasher.update(&ciphertext);
+    let mac = hasher.finalize();
+
+    let keystore = json!({
+        "version": KEPLR_KEYSTORE_VERSION,
+        "type": "mnemonic",
+        "coinTypeForChain": {},
+        "bip44HDPath": { "account": 0, "change": 0, "addressIndex": 0 },
+        "meta": { "name": name.unwrap_or_default() },
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": hex::encode(iv) },
+            "ciphertext": hex::encode(&ciphertext),
+            "kdf": kdf.name(),
+            "kdfparams": kdf.params_json(&salt),
+            "mac": hex::encode(mac),
+        },
+    });
+
+    Ok(keystore.to_string())
+}
+
+/// Serializes a mnemonic the way CosmJS `Secp256k1HdWallet.serialize(password)` does
+///
+/// The result loads directly with `Secp256k1HdWallet.deserialize(json, password)`
+/// (or `DirectSecp256k1HdWallet.deserialize` when `direct` is true), so dapp
+/// code never handles the plaintext phrase. The encryption key is Argon2id over
+/// the password with CosmJS's fixed salt (24 passes, 12 MiB), and the wallet
+/// data is sealed with XChaCha20-Poly1305 under a random nonce. The single
+/// account uses m/44'/118'/0'/0/0.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase to encrypt
+/// * `password` - The password protecting the serialization
+/// * `prefix` - Optional bech32 prefix of the account (defaults to "mantra")
+/// * `direct` - true for `DirectSecp256k1HdWallet`, false for the amino `Secp256k1HdWallet`
+///
+/// # Returns
+/// * `String` - The serialized wallet JSON, or an error for an invalid mnemonic or prefix
+///
+/// # Example
+/// ```javascript
+/// const serialized = export_cosmjs_wallet(keypair.reveal_mnemonic(false), password, "mantra", true);
+/// const wallet = await DirectSecp256k1HdWallet.deserialize(serialized, password);
+/// ```
+#[wasm_bindgen]
+pub fn export_cosmjs_wallet(
+    mnemonic_str: &str,
+    password: &str,
+    prefix: Option<String>,
+    direct: bool,
+) -> Result<String, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    let prefix = hrp_or_default(prefix);
+    check_hrp(&prefix)?;
+
+    let key = argon2id_key(
+        password,
+        COSMJS_SALT,
+        COSMJS_ARGON2_MEM_LIMIT_KIB,
+        COSMJS_ARGON2_OPS_LIMIT,
+        1,
+    )
+    .map_err(VanityError::DerivationFailed)?;
+
+    let data = json!({
+        "mnemonic": mnemonic.to_string(),
+        "accounts": [{
+            "hdPath": format_derivation_path(&COSMOS_ADDRESS_PATH),
+            "prefix": prefix,
+        }],
+    });
+    let mut nonce = [0u8; XCHACHA20_NONCE_LENGTH];
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext = XChaCha20Poly1305::new(&key.into())
+        .encrypt(&nonce.into(), data.to_string().as_bytes())
+        .map_err(|_| VanityError::DerivationFailed("Error encrypting wallet".to_string()))?;
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+
+    let serialization = json!({
+        "type": if direct { "directsecp256k1hdwallet-v1" } else { "secp256k1wallet-v1" },
+        "kdf": {
+            "algorithm": "argon2id",
+            "params": {
+                "outputLength": 32,
+                "opsLimit": COSMJS_ARGON2_OPS_LIMIT,
+                "memLimitKib": COSMJS_ARGON2_MEM_LIMIT_KIB,
+            },
+        },
+        "encryption": { "algorithm": "xchacha20poly1305-ietf" },
+        "data": BASE64_STANDARD.encode(sealed),
+    });
+
+    Ok(serialization.to_string())
+}
+
+/// Exports the account key of a mnemonic as a Cosmos SDK armored key file
+///
+/// Produces the passphrase-encrypted format written by `keys export` and read
+/// by `mantrachaind keys import <name> <file>`: the amino-encoded secp256k1
+/// key at m/44'/118'/0'/0/0 is sealed with XSalsa20-Poly1305 under
+/// SHA256(bcrypt(passphrase, random salt, cost 12)) and wrapped in an OpenPGP
+/// style ASCII armor block with `kdf`, `salt` and `type` headers.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase whose account key is exported
+/// * `passphrase` - The passphrase `keys import` will ask for (at least 8 characters)
+///
+/// # Returns
+/// * `String` - The armored key file, or an error for an invalid mnemonic or weak passphrase
+///
+/// # Example
+/// ```javascript
+/// const armor = export_armored_private_key(keypair.reveal_mnemonic(false), passphrase);
+/// download("vanity.key", armor); // then: mantrachaind keys import vanity vanity.key
+/// ```
+#[wasm_bindgen]
+pub fn export_armored_private_key(
+    mnemonic_str: &str,
+    passphrase: &str,
+) -> Result<String, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    check_password(passphrase)?;
+    let derived = derive_key_at_path(&mnemonic, &COSMOS_ADDRESS_PATH)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving key: {}", e)))?;
+
+    let mut salt = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    let bcrypt_hash = bcrypt::hash_with_salt(passphrase, ARMOR_BCRYPT_COST, salt)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving key: {}", e)))?
+        .format_for_version(bcrypt::Version::TwoA);
+    let key: [u8; 32] = Sha256::digest(bcrypt_hash.as_bytes()).into();
+
+    let private_key = Zeroizing::new(derived.private_key_bytes());
+    let mut amino_key = Zeroizing::new(Vec::with_capacity(37));
+    amino_key.extend_from_slice(&SECP256K1_PRIVATE_KEY_AMINO_PREFIX);
+    amino_key.push(32);
+    amino_key.extend_from_slice(&*private_key);
+
+    let mut nonce = [0u8; 24];
+    OsRng.fill_bytes(&mut nonce);
+    let ciphertext = XSalsa20Poly1305::new(&key.into())
+        .encrypt(&nonce.into(), amino_key.as_slice())
+        .map_err(|_| VanityError::DerivationFailed("Error encrypting key".to_string()))?;
+    let mut sealed = nonce.to_vec();
+    sealed.extend_from_slice(&ciphertext);
+
+    let headers = [
+        ("kdf", "bcrypt".to_string()),
+        ("salt", hex::encode_upper(salt)),
+        ("type", "secp256k1".to_string()),
+    ];
+    Ok(encode_armor(ARMOR_PRIVATE_KEY_BLOCK, &headers, &sealed))
+}
+
+/// Wraps data in an OpenPGP-style ASCII armor block with a CRC-24 checksum
+fn encode_armor(block_type: &str, headers: &[(&str, String)], data: &[u8]) -> String {
+    let mut armor = format!("-----BEGIN {}-----\n", block_type);
+    for (key, value) in headers {
+        armor.push_str(&format!("{}: {}\n", key, value));
+    }
+    armor.push('\n');
+
+    let body = BASE64_STANDARD.encode(data);
+    for line in body.as_bytes().chunks(64) {
+        armor.push_str(&String::from_utf8_lossy(line));
+        armor.push('\n');
+    }
+
+    let crc = crc24(data).to_be_bytes();
+    armor.push_str(&format!("={}\n", BASE64_STANDARD.encode(&crc[1..])));
+    armor.push_str(&format!("-----END {}-----", block_type));
+    armor
+}
+
+/// Computes the OpenPGP CRC-24 checksum (RFC 4880, section 6.1)
+fn crc24(data: &[u8]) -> u32 {
+    let mut crc: u32 = 0xB704CE;
+    for &byte in data {
+        crc ^= u32::from(byte) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x1000000 != 0 {
+                crc ^= 0x1864CFB;
+            }
+        }
+    }
+    crc & 0xFFFFFF
+}
+
+/// Exports a mnemonic in the CryptoJS passphrase format Cosmostation stores
+///
+/// This is `CryptoJS.AES.encrypt(mnemonic, password)`: an OpenSSL-compatible
+/// `Salted__` blob (AES-256-CBC, key and IV from EVP_BytesToKey with MD5 and
+/// a random 8-byte salt), base64-encoded. The key derivation is fast, so the
+/// result is only as strong as a long password makes it; keep it inside the
+/// import flow rather than using it as a backup.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase to encrypt
+/// * `password` - The wallet password (at least 8 characters)
+///
+/// # Returns
+/// * `String` - The base64 ciphertext (starts with "U2FsdGVkX1"), or an error
+///   for an invalid mnemonic or weak password
+///
+/// # Example
+/// ```javascript
+/// const encrypted = export_cosmostation_encrypted_mnemonic(keypair.reveal_mnemonic(false), password);
+/// ```
+#[wasm_bindgen]
+pub fn export_cosmostation_encrypted_mnemonic(
+    mnemonic_str: &str,
+    password: &str,
+) -> Result<String, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    check_password(password)?;
+
+    let mut salt = [0u8; 8];
+    OsRng.fill_bytes(&mut salt);
+    let (key, iv) = evp_bytes_to_key(password.as_bytes(), &salt);
+    let ciphertext = Aes256CbcEnc::new(&key.into(), &iv.into())
+        .encrypt_padded_vec_mut::<Pkcs7>(mnemonic.to_string().as_bytes());
+
+    let mut sealed = OPENSSL_SALTED_MAGIC.to_vec();
+    sealed.extend_from_slice(&salt);
+    sealed.extend_from_slice(&ciphertext);
+    Ok(BASE64_STANDARD.encode(sealed))
+}
+
+/// Exports a mnemonic in the CryptoJS PBKDF2 format of Leap's keychain
+///
+/// The key is PBKDF2-HMAC-SHA1 (100 iterations, random 16-byte salt) over the
+/// password and the phrase is encrypted with AES-256-CBC under a random IV.
+/// The output is the salt and IV as hex (64 characters) followed by the base64
+/// ciphertext. As with the Cosmostation format, the key derivation is cheap,
+/// so use a long password and treat the result as transient.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase to encrypt
+/// * `password` - The wallet password (at least 8 characters)
+///
+/// # Returns
+/// * `String` - The encrypted mnemonic, or an error for an invalid mnemonic or weak password
+///
+/// # Example
+/// ```javascript
+/// const encrypted = export_leap_encrypted_mnemonic(keypair.reveal_mnemonic(false), password);
+/// ```
+#[wasm_bindgen]
+pub fn export_leap_encrypted_mnemonic(
+    mnemonic_str: &str,
+    password: &str,
+) -> Result<String, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    check_password(password)?;
+
+    let mut salt = [0u8; 16];
+    let mut iv = [0u8; 16];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut iv);
+    let mut key = [0u8; 32];
+    pbkdf2::pbkdf2_hmac::<Sha1>(password.as_bytes(), &salt, LEAP_PBKDF2_ITERATIONS, &mut key);
+    let ciphertext = Aes256CbcEnc::new(&key.into(), &iv.into())
+        .encrypt_padded_vec_mut::<Pkcs7>(mnemonic.to_string().as_bytes());
+
+    Ok(format!(
+        "{}{}{}",
+        hex::encode(salt),
+        hex::encode(iv),
+        BASE64_STANDARD.encode(ciphertext)
+    ))
+}
+
+/// Derives an AES-256 key and IV from a password the way OpenSSL's EVP_BytesToKey does (MD5, one round)
+fn evp_bytes_to_key(password: &[u8], salt: &[u8]) -> ([u8; 32], [u8; 16]) {
+    let mut material = Vec::with_capacity(48);
+    let mut block: Vec<u8> = Vec::new();
+    while material.len() < 48 {
+        let mut hasher = Md5::new();
+        hasher.update(&block);
+        hasher.update(password);
+        hasher.update(salt);
+        block = hasher.finalize().to_vec();
+        material.extend_from_slice(&block);
+    }
+
+    let mut key = [0u8; 32];
+    let mut iv = [0u8; 16];
+    key.copy_from_slice(&material[..32]);
+    iv.copy_from_slice(&material[32..48]);
+    (key, iv)
+}
+
+/// Encrypts a mnemonic under a password as a small versioned JSON blob
+///
+/// The key is Argon2id (64 MiB, 3 passes, random 16-byte salt) over the
+/// password by default, or scrypt when chosen with `KdfOptions.scrypt()`, and
+/// the phrase is sealed with AES-256-GCM under a random 12-byte nonce. The
+/// KDF and its parameters are stored in the blob, so `decrypt_mnemonic()`
+/// keeps working if the defaults change in a later version.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase to encrypt
+/// * `password` - The password protecting the blob (at least 8 characters)
+/// * `kdf` - Optional KDF settings (defaults to Argon2id with 64 MiB and 3 passes)
+///
+/// # Returns
+/// * `String` - The encrypted blob as JSON, or an error for an invalid mnemonic or weak password
+///
+/// # Example
+/// ```javascript
+/// const blob = encrypt_mnemonic(keypair.reveal_mnemonic(false), password, KdfOptions.scrypt(17, 8, 1));
+/// localStorage.setItem("vanity-result", blob);
+/// ```
+#[wasm_bindgen]
+pub fn encrypt_mnemonic(
+    mnemonic_str: &str,
+    password: &str,
+    kdf: Option<KdfOptions>,
+) -> Result<String, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    check_password(password)?;
+
+    seal_blob(mnemonic.to_string().as_bytes(), password, kdf, None)
+        .map(|blob| blob.to_string())
+        .map_err(VanityError::DerivationFailed)
+}
+
+#[wasm_bindgen]
+impl Keypair {
+    /// Encrypts this keypair's mnemonic as an `encrypt_mnemonic()` blob
+    ///
+    /// The phrase goes straight from WASM memory into the blob, so this works
+    /// in hardened mode, where `reveal_mnemonic()` is disabled.
+    ///
+    /// # Arguments
+    /// * `password` - The password protecting the blob (at least 8 characters)
+    /// * `kdf` - Optional KDF settings (defaults to Argon2id with 64 MiB and 3 passes)
+    ///
+    /// # Returns
+    /// * `String` - The encrypted blob as JSON, or an error if the keypair has
+    ///   no mnemonic or the password is weak
+    ///
+    /// # Example
+    /// ```javascript
+    /// localStorage.setItem("vanity-result", keypair.encrypt_mnemonic(password, undefined));
+    /// ```
+    pub fn encrypt_mnemonic(
+        &self,
+        password: &str,
+        kdf: Option<KdfOptions>,
+    ) -> Result<String, VanityError> {
+        let mnemonic = self.mnemonic.as_deref().ok_or_else(|| {
+            VanityError::InvalidInput("Keypair has no mnemonic to encrypt".to_string())
+        })?;
+        encrypt_mnemonic(mnemonic, password, kdf)
+    }
+}
+
+/// Decrypts a blob produced by `encrypt_mnemonic()`
+///
+/// Disabled in hardened mode, since it returns the phrase in plaintext.
+///
+/// # Arguments
+/// * `blob` - The encrypted blob JSON
+/// * `password` - The password the blob was encrypted with
+///
+/// # Returns
+/// * `String` - The mnemonic phrase, or an error for a wrong password,
+///   malformed blob or hardened mode
+///
+/// # Example
+/// ```javascript
+/// const mnemonic = decrypt_mnemonic(localStorage.getItem("vanity-result"), password);
+/// ```
+#[wasm_bindgen]
+pub fn decrypt_mnemonic(blob: &str, password: &str) -> Result<String, VanityError> {
+    check_plaintext_secrets("decrypting a mnemonic")?;
+    let mnemonic = open_mnemonic_blob(blob, password).map_err(VanityError::DecryptionFailed)?;
+    audit_export("decrypted_mnemonic", None)?;
+    Ok(mnemonic)
+}
+
+/// Checks the header of an encrypted mnemonic blob and returns it as compact JSON
+pub fn compact_mnemonic_blob(blob: &str) -> Result<String, String> {
+    let blob: Value =
+        serde_json::from_str(blob).map_err(|e| format!("Invalid blob JSON: {}", e))?;
+    let blob = upgrade_document(blob)?;
+
+    if !blob["ciphertext"].is_string() {
+        return Err("Missing or invalid field: ciphertext".to_string());
+    }
+    Ok(blob.to_string())
+}
+
+/// Parses and decrypts a mnemonic blob, describing the first problem found
+fn open_mnemonic_blob(blob: &str, password: &str) -> Result<String, String> {
+    let blob: Value =
+        serde_json::from_str(blob).map_err(|e| format!("Invalid blob JSON: {}", e))?;
+    if !blob["content"].is_null() {
+        return Err(format!(
+            "Blob holds {} rather than a mnemonic",
+            blob["content"]
+        ));
+    }
+
+    let plaintext = open_blob(blob, password)?;
+    String::from_utf8(plaintext).map_err(|_| "Decrypted data is not a mnemonic".to_string())
+}
+
+/// Encrypts data into a versioned blob, tagging non-mnemonic content
+///
+/// Blobs default to Argon2id (64 MiB, 3 passes); the salt and nonce are random.
+pub fn seal_blob(
+    plaintext: &[u8],
+    password: &str,
+    kdf: Option<KdfOptions>,
+    content: Option<&str>,
+) -> Result<Value, String> {
+    let mut salt = [0u8; 16];
+    let mut nonce = [0u8; 12];
+    OsRng.fill_bytes(&mut salt);
+    OsRng.fill_bytes(&mut nonce);
+
+    let kdf = kdf.map_or(
+        Kdf::Argon2id {
+            m_cost: BLOB_ARGON2_MEM_KIB,
+            t_cost: BLOB_ARGON2_TIME_COST,
+            p_cost: BLOB_ARGON2_PARALLELISM,
+        },
+        |options| options.kdf,
+    );
+    let key = kdf.derive_key(password, &salt)?;
+    let ciphertext = Aes256Gcm::new(&key.into())
+        .encrypt(Nonce::from_slice(&nonce), plaintext)
+        .map_err(|_| "Error encrypting data".to_string())?;
+
+    let mut blob = json!({
+        "schema_version": SCHEMA_VERSION,
+        "kdf": kdf.name(),
+        "kdfparams": kdf.params_json(&salt),
+        "cipher": "aes-256-gcm",
+        "nonce": hex::encode(nonce),
+        "ciphertext": BASE64_STANDARD.encode(ciphertext),
+    });
+    if let Some(content) = content {
+        blob["content"] = json!(content);
+    }
+    Ok(blob)
+}
+
+/// Decrypts a parsed blob, describing the first problem found
+pub fn open_blob(blob: Value, password: &str) -> Result<Vec<u8>, String> {
+    let blob = upgrade_document(blob)?;
+    if blob["cipher"] != "aes-256-gcm" {
+        return Err(format!("Unsupported cipher: {}", blob["cipher"]));
+    }
+
+    let params = &blob["kdfparams"];
+    let salt = hex_field(params, "salt")?;
+    let key = Kdf::from_json(&blob["kdf"], params)?.derive_key(password, &salt)?;
+
+    let nonce = hex_field(&blob, "nonce")?;
+    if nonce.len() != 12 {
+        return Err("Nonce must be 12 bytes".to_string());
+    }
+    let ciphertext = blob["ciphertext"]
+        .as_str()
+        .and_then(|ciphertext| BASE64_STANDARD.decode(ciphertext).ok())
+        .ok_or("Missing or invalid field: ciphertext")?;
+
+    Aes256Gcm::new(&key.into())
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|_| "Wrong password or corrupted blob".to_string())
+}
+
+/// Builder for a password-protected results archive
+///
+/// The archive is an `encrypt_mnemonic()`-style blob (same KDF choices and
+/// AES-256-GCM) tagged `"content": "keypairs"`, whose plaintext is a JSON
+/// array of `Keypair.to_json()` records. It suits saving the output of a long
+/// multi-result or batch search in one file. Keypairs are added by
+/// reference, so the JavaScript handles stay usable afterwards.
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct KeypairArchive {
+    records: Vec<Zeroizing<String>>,
+}
+
+#[wasm_bindgen]
+impl KeypairArchive {
+    /// Creates an empty archive
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> KeypairArchive {
+        KeypairArchive::default()
+    }
+
+    /// Gets the number of keypairs added so far
+    #[wasm_bindgen(getter)]
+    pub fn length(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Adds a keypair's full record, secrets included even in hardened mode
+    ///
+    /// # Arguments
+    /// * `keypair` - The keypair to archive
+    pub fn add(&mut self, keypair: &Keypair) -> Result<(), VanityError> {
+        let record = serde_json::to_string(&keypair.to_record()).map_err(|e| {
+            VanityError::DerivationFailed(format!("Error serializing keypair: {}", e))
+        })?;
+        self.records.push(Zeroizing::new(record));
+        Ok(())
+    }
+
+    /// Encrypts the added keypairs into one archive
+    ///
+    /// # Arguments
+    /// * `password` - The password protecting the archive (at least 8 characters)
+    /// * `kdf` - Optional KDF settings (defaults to Argon2id with 64 MiB and 3 passes)
+    ///
+    /// # Returns
+    /// * `String` - The encrypted archive as JSON, or an error for an empty
+    ///   archive or weak password
+    ///
+    /// # Example
+    /// ```javascript
+    /// const archive = new KeypairArchive();
+    /// found.forEach((keypair) => archive.add(keypair));
+    /// download("hunt-results.json", archive.encrypt(password, undefined));
+    /// ```
+    pub fn encrypt(&self, password: &str, kdf: Option<KdfOptions>) -> Result<String, VanityError> {
+        if self.records.is_empty() {
+            return Err(VanityError::InvalidInput(
+                "Archive needs at least one keypair".to_string(),
+            ));
+        }
+        check_password(password)?;
+
+        let records: Vec<&str> = self.records.iter().map(|record| record.as_str()).collect();
+        let plaintext = Zeroizing::new(format!("[{}]", records.join(",")));
+        seal_blob(plaintext.as_bytes(), password, kdf, Some(KEYPAIRS_CONTENT))
+            .map(|blob| blob.to_string())
+            .map_err(VanityError::DerivationFailed)
+    }
+}
+
+/// Decrypts a results archive produced by `KeypairArchive.encrypt()`
+///
+/// Every record is checked as in `Keypair.from_json()`, so a tampered or
+/// inconsistent archive is rejected as a whole.
+///
+/// # Arguments
+/// * `archive` - The encrypted archive JSON
+/// * `password` - The password the archive was encrypted with
+///
+/// # Returns
+/// * `Vec<Keypair>` - The archived keypairs, or an error for a wrong password or malformed archive
+///
+/// # Example
+/// ```javascript
+/// const keypairs = decrypt_keypairs(archiveText, password);
+/// keypairs.forEach((keypair) => console.log(keypair.address));
+/// ```
+#[wasm_bindgen]
+pub fn decrypt_keypairs(archive: &str, password: &str) -> Result<Vec<Keypair>, VanityError> {
+    open_keypair_archive(archive, password).map_err(VanityError::DecryptionFailed)
+}
+
+/// Parses, decrypts and checks a results archive
+fn open_keypair_archive(archive: &str, password: &str) -> Result<Vec<Keypair>, String> {
+    let blob: Value =
+        serde_json::from_str(archive).map_err(|e| format!("Invalid archive JSON: {}", e))?;
+    if blob["content"] != KEYPAIRS_CONTENT {
+        return Err("Blob is not a keypair archive".to_string());
+    }
+
+    let plaintext = open_blob(blob, password)?;
+    let records: Vec<Value> = serde_json::from_slice(&plaintext)
+        .map_err(|e| format!("Archive does not hold a list of records: {}", e))?;
+    records
+        .iter()
+        .enumerate()
+        .map(|(position, record)| {
+            keypair_from_json(&record.to_string())
+                .map_err(|e| format!("Record {}: {}", position + 1, e))
+        })
+        .collect()
+}
+
+/// Rejects a password shorter than `MIN_PASSWORD_CHARS` characters
+pub fn check_password(password: &str) -> Result<(), VanityError> {
+    if password.chars().count() < MIN_PASSWORD_CHARS {
+        return Err(VanityError::WeakPassword(format!(
+            "Password must be at least {} characters",
+            MIN_PASSWORD_CHARS
+        )));
+    }
+    Ok(())
+}
+
+/// Derives a 32-byte key with Argon2id (version 0x13)
+fn argon2id_key(
+    password: &str,
+    salt: &[u8],
+    m_cost: u32,
+    t_cost: u32,
+    p_cost: u32,
+) -> Result<[u8; 32], String> {
+    let params = Params::new(m_cost, t_cost, p_cost, Some(32))
+        .map_err(|e| format!("Invalid Argon2 parameters: {}", e))?;
+    let mut key = [0u8; 32];
+    Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+        .hash_password_into(password.as_bytes(), salt, &mut key)
+        .map_err(|e| format!("Error deriving key: {}", e))?;
+    Ok(key)
+}
+
+/// Reads a hex-encoded field of a JSON object
+fn hex_field(object: &Value, name: &str) -> Result<Vec<u8>, String> {
+    object[name]
+        .as_str()
+        .and_then(|value| hex::decode(value).ok())
+        .ok_or_else(|| format!("Missing or invalid field: {}", name))
+}
+
+/// Reads a u32 field of a JSON object
+fn u32_field(object: &Value, name: &str) -> Result<u32, String> {
+    object[name]
+        .as_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .ok_or_else(|| format!("Missing or invalid field: {}", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cbc::cipher::BlockDecryptMut;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    const PASSWORD: &str = "correct horse battery";
+
+    /// Keplr keystore of `MNEMONIC` made with Python's hashlib scrypt (N = 16384)
+    /// and the `cryptography` package's AES-CTR
+    const KEPLR_VECTOR: &str = r#"{"version":"1.2","type":"mnemonic","crypto":{"cipher":"aes-128-ctr","cipherparams":{"iv":"202122232425262728292a2b2c2d2e2f"},"ciphertext":"cb71632fd8c044427a132fcd6241e0c1fbdaddecb26006478c7d633cbc60f15df5ea0ca77ec15651da99ce23256e00d4552698485c3b270b7271c8f01422d7ecc8640e84512e84ddee92b98cbd8b208a17468f8c6eb59d7de5c39a96f0","kdf":"scrypt","kdfparams":{"salt":"000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f","dklen":32,"n":16384,"r":8,"p":1},"mac":"9db40a66e01544cc983570a2dafd751a9c39b793b2314e4de836f54bf952ab24"}}"#;
+
+    /// CosmJS wallet key for `PASSWORD`, from the `cryptography` package's Argon2id
+    const COSMJS_KEY_VECTOR: &str =
+        "5e651a1f32cd5c612ed06b67fee1128597aee3d2cae3c165cfbdd6f4551cb37e";
+
+    /// `openssl enc -aes-256-cbc -md md5 -S 0001020304050607` of `MNEMONIC` behind
+    /// the `Salted__` header, the format `CryptoJS.AES.encrypt()` produces
+    const COSMOSTATION_VECTOR: &str = "U2FsdGVkX18AAQIDBAUGBx9JXW3bu0EhwGval/eOmgcWV4STWxxgxGZRh2b8zQnMv6ovcCmWBvGxapySDvbB1Hqthuryg54TTI2SuAKr2OnrBEgnzEtYxNbO2950cnItavhlcSQk1JyVXWhWG0Gfbw==";
+
+    /// Leap keychain encryption of `MNEMONIC` made with Python's hashlib PBKDF2
+    /// and the `cryptography` package's AES-CBC
+    const LEAP_VECTOR: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1fdJutBxYMZu3m2M/yP18F2i9JNefIagWJqEDKOYNssnQaSTg1nE6gn6wGcy/ooWjh+MIyDrsTfih8yHvr3zq3FskGciI8H1gVUgO6yo3rSxf2n/eISxS6T/1PLquTBgQV";
+
+    type Aes256CbcDec = cbc::Decryptor<Aes256>;
+
+    fn decrypt_keplr(keystore: &str, password: &str) -> String {
+        let keystore: Value = serde_json::from_str(keystore).unwrap();
+        let crypto = &keystore["crypto"];
+        let salt = hex_field(&crypto["kdfparams"], "salt").unwrap();
+        let key = Kdf::from_json(&crypto["kdf"], &crypto["kdfparams"])
+            .unwrap()
+            .derive_key(password, &salt)
+            .unwrap();
+        let iv = hex_field(&crypto["cipherparams"], "iv").unwrap();
+        let mut plaintext = hex_field(crypto, "ciphertext").unwrap();
+
+        let mut hasher = Sha256::new();
+        hasher.update(&key[16..]);
+        hasher.update(&plaintext);
+        assert_eq!(hex::encode(hasher.finalize()), crypto["mac"]);
+
+        Aes256Ctr::new(&key.into(), iv.as_slice().into()).apply_keystream(&mut plaintext);
+        String::from_utf8(plaintext).unwrap()
+    }
+
+    fn decrypt_cosmjs(serialization: &str, password: &str) -> Value {
+        let serialization: Value = serde_json::from_str(serialization).unwrap();
+        let params = &serialization["kdf"]["params"];
+        let key = argon2id_key(
+            password,
+            COSMJS_SALT,
+            u32_field(params, "memLimitKib").unwrap(),
+            u32_field(params, "opsLimit").unwrap(),
+            1,
+        )
+        .unwrap();
+        let sealed = BASE64_STANDARD
+            .decode(serialization["data"].as_str().unwrap())
+            .unwrap();
+        let (nonce, ciphertext) = sealed.split_at(XCHACHA20_NONCE_LENGTH);
+        let plaintext = XChaCha20Poly1305::new(&key.into())
+            .decrypt(nonce.into(), ciphertext)
+            .unwrap();
+        serde_json::from_slice(&plaintext).unwrap()
+    }
+
+    fn decrypt_armor(armor: &str, passphrase: &str) -> Vec<u8> {
+        let mut lines = armor.lines();
+        assert_eq!(
+            lines.next(),
+            Some(format!("-----BEGIN {}-----", ARMOR_PRIVATE_KEY_BLOCK).as_str())
+        );
+        let headers: Vec<&str> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+        let salt_header = headers
+            .iter()
+            .find_map(|header| header.strip_prefix("salt: "))
+            .unwrap();
+        let mut body = String::new();
+        let mut checksum = "";
+        for line in lines.by_ref() {
+            match line.strip_prefix('=') {
+                Some(crc) => {
+                    checksum = crc;
+                    break;
+                }
+                None => body.push_str(line),
+            }
+        }
+        let sealed = BASE64_STANDARD.decode(body).unwrap();
+        assert_eq!(
+            BASE64_STANDARD.encode(&crc24(&sealed).to_be_bytes()[1..]),
+            checksum
+        );
+
+        let salt: [u8; 16] = hex::decode(salt_header).unwrap().try_into().unwrap();
+        let bcrypt_hash = bcrypt::hash_with_salt(passphrase, ARMOR_BCRYPT_COST, salt)
+            .unwrap()
+            .format_for_version(bcrypt::Version::TwoA);
+        let key: [u8; 32] = Sha256::digest(bcrypt_hash.as_bytes()).into();
+        let (nonce, ciphertext) = sealed.split_at(24);
+        XSalsa20Poly1305::new(&key.into())
+            .decrypt(nonce.into(), ciphertext)
+            .unwrap()
+    }
+
+    fn decrypt_cosmostation(encrypted: &str, password: &str) -> String {
+        let sealed = BASE64_STANDARD.decode(encrypted).unwrap();
+        assert_eq!(&sealed[..8], OPENSSL_SALTED_MAGIC);
+        let (key, iv) = evp_bytes_to_key(password.as_bytes(), &sealed[8..16]);
+        let plaintext = Aes256CbcDec::new(&key.into(), &iv.into())
+            .decrypt_padded_vec_mut::<Pkcs7>(&sealed[16..])
+            .unwrap();
+        String::from_utf8(plaintext).unwrap()
+    }
+
+    fn decrypt_leap(encrypted: &str, password: &str) -> String {
+        let salt = hex::decode(&encrypted[..32]).unwrap();
+        let iv: [u8; 16] = hex::decode(&encrypted[32..64]).unwrap().try_into().unwrap();
+        let ciphertext = BASE64_STANDARD.decode(&encrypted[64..]).unwrap();
+        let mut key = [0u8; 32];
+        pbkdf2::pbkdf2_hmac::<Sha1>(password.as_bytes(), &salt, LEAP_PBKDF2_ITERATIONS, &mut key);
+        let plaintext = Aes256CbcDec::new(&key.into(), &iv.into())
+            .decrypt_padded_vec_mut::<Pkcs7>(&ciphertext)
+            .unwrap();
+        String::from_utf8(plaintext).unwrap()
+    }
+
+    #[test]
+    fn keplr_vector_decrypts() {
+        assert_eq!(decrypt_keplr(KEPLR_VECTOR, PASSWORD), MNEMONIC);
+    }
+
+    #[test]
+    fn keplr_keystore_round_trips() {
+        let kdf = KdfOptions::scrypt(14, 8, 1).unwrap();
+        let keystore = export_keplr_keystore(MNEMONIC, PASSWORD, None, Some(kdf)).unwrap();
+        assert_eq!(decrypt_keplr(&keystore, PASSWORD), MNEMONIC);
+    }
+
+    #[test]
+    fn cosmjs_key_matches_reference_argon2id() {
+        let key = argon2id_key(
+            PASSWORD,
+            COSMJS_SALT,
+            COSMJS_ARGON2_MEM_LIMIT_KIB,
+            COSMJS_ARGON2_OPS_LIMIT,
+            1,
+        )
+        .unwrap();
+        assert_eq!(hex::encode(key), COSMJS_KEY_VECTOR);
+    }
+
+    #[test]
+    fn cosmjs_wallet_round_trips() {
+        let serialization = export_cosmjs_wallet(MNEMONIC, PASSWORD, None, true).unwrap();
+        let data = decrypt_cosmjs(&serialization, PASSWORD);
+        assert_eq!(data["mnemonic"], MNEMONIC);
+        assert_eq!(data["accounts"][0]["hdPath"], "m/44'/118'/0'/0/0");
+        assert_eq!(data["accounts"][0]["prefix"], "mantra");
+    }
+
+    #[test]
+    fn crc24_matches_the_openpgp_check_value() {
+        assert_eq!(crc24(b"123456789"), 0x21CF02);
+    }
+
+    #[test]
+    fn armored_private_key_round_trips() {
+        let armor = export_armored_private_key(MNEMONIC, PASSWORD).unwrap();
+        let amino_key = decrypt_armor(&armor, PASSWORD);
+
+        let mnemonic = parse_mnemonic(MNEMONIC).unwrap();
+        let derived = derive_key_at_path(&mnemonic, &COSMOS_ADDRESS_PATH).unwrap();
+        assert_eq!(amino_key[..4], SECP256K1_PRIVATE_KEY_AMINO_PREFIX);
+        assert_eq!(amino_key[4], 32);
+        assert_eq!(amino_key[5..], derived.private_key_bytes());
+    }
+
+    #[test]
+    fn cosmostation_vector_decrypts() {
+        assert_eq!(
+            decrypt_cosmostation(COSMOSTATION_VECTOR, PASSWORD),
+            MNEMONIC
+        );
+    }
+
+    #[test]
+    fn cosmostation_export_round_trips() {
+        let encrypted = export_cosmostation_encrypted_mnemonic(MNEMONIC, PASSWORD).unwrap();
+        assert!(encrypted.starts_with("U2FsdGVkX1"));
+        assert_eq!(decrypt_cosmostation(&encrypted, PASSWORD), MNEMONIC);
+    }
+
+    #[test]
+    fn leap_vector_decrypts() {
+        assert_eq!(decrypt_leap(LEAP_VECTOR, PASSWORD), MNEMONIC);
+    }
+
+    #[test]
+    fn leap_export_round_trips() {
+        let encrypted = export_leap_encrypted_mnemonic(MNEMONIC, PASSWORD).unwrap();
+        assert_eq!(decrypt_leap(&encrypted, PASSWORD), MNEMONIC);
+    }
+
+    #[test]
+    fn mnemonic_blob_round_trips() {
+        let kdf = KdfOptions::scrypt(14, 8, 1).unwrap();
+        let blob = encrypt_mnemonic(MNEMONIC, PASSWORD, Some(kdf)).unwrap();
+        assert_eq!(open_mnemonic_blob(&blob, PASSWORD).unwrap(), MNEMONIC);
+        assert!(open_mnemonic_blob(&blob, "wrong password").is_err());
+    }
+
+    #[test]
+    fn short_passwords_are_rejected() {
+        assert!(check_password("seven!!").is_err());
+        assert!(check_password("eight!!!").is_ok());
+    }
+}
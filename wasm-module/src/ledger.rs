@@ -0,0 +1,181 @@
+//! Ledger hardware wallet compatibility checks
+//!
+//! The Ledger Cosmos app only derives a restricted set of paths, so a vanity
+//! address found at an unusual path can never be shown on the device. This
+//! module checks a path/address combination against those constraints and
+//! against known-good derivation vectors.
+
+use crate::error::VanityError;
+use crate::hd::{format_derivation_path, parse_derivation_path, HARDENED_OFFSET};
+use crate::mnemonic::parse_mnemonic;
+use crate::{derive_key_at_path, public_key_to_address};
+use bip39::Mnemonic;
+use wasm_bindgen::prelude::*;
+
+/// Known-good (mnemonic, path, address) vectors matching Ledger/CosmJS output
+const LEDGER_VECTORS: [(&str, &str, &str); 1] = [(
+    "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about",
+    "m/44'/118'/0'/0/0",
+    "mantra19rl4cm2hmr8afy4kldpxz3fka4jguq0aht8eu0",
+)];
+
+/// Verdict on whether a Ledger device would show the same address
+#[wasm_bindgen]
+pub struct LedgerCompatibility {
+    path_allowed: bool,
+    vectors_passed: bool,
+    address_matches: bool,
+    derived_address: String,
+    issues: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl LedgerCompatibility {
+    /// Gets whether a Ledger would show the expected address at this path
+    #[wasm_bindgen(getter)]
+    pub fn compatible(&self) -> bool {
+        self.path_allowed && self.vectors_passed && self.address_matches
+    }
+
+    /// Gets whether the Ledger Cosmos app accepts the path
+    #[wasm_bindgen(getter)]
+    pub fn path_allowed(&self) -> bool {
+        self.path_allowed
+    }
+
+    /// Gets whether this build reproduces the known-good Ledger derivation vectors
+    #[wasm_bindgen(getter)]
+    pub fn vectors_passed(&self) -> bool {
+        self.vectors_passed
+    }
+
+    /// Gets whether the mnemonic derives the expected address at the path
+    #[wasm_bindgen(getter)]
+    pub fn address_matches(&self) -> bool {
+        self.address_matches
+    }
+
+    /// Gets the address this module derives at the path
+    #[wasm_bindgen(getter)]
+    pub fn derived_address(&self) -> String {
+        self.derived_address.clone()
+    }
+
+    /// Gets human-readable reasons for any incompatibility
+    #[wasm_bindgen(getter)]
+    pub fn issues(&self) -> Vec<String> {
+        self.issues.clone()
+    }
+}
+
+/// Checks whether a Ledger running the Cosmos app would show the same address
+///
+/// Three things must hold for the device to display `expected_address`:
+/// - The path is one the Ledger Cosmos app derives: m/44'/118'/account'/change/index
+///   (change 0 is standard; other values need the app's expert mode)
+/// - This build reproduces the embedded known-good derivation vectors
+/// - The mnemonic actually derives `expected_address` at the path
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `path` - The BIP32 path string (e.g. `m/44'/118'/0'/0/0`)
+/// * `expected_address` - The MANTRA address the user expects to see
+///
+/// # Returns
+/// * `LedgerCompatibility` - The structured verdict, or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// const result = check_ledger_compatibility(mnemonic, "m/44'/118'/0'/0/0", address);
+/// if (!result.compatible) result.issues.forEach((issue) => console.warn(issue));
+/// ```
+#[wasm_bindgen]
+pub fn check_ledger_compatibility(
+    mnemonic_str: &str,
+    path: &str,
+    expected_address: &str,
+) -> Result<LedgerCompatibility, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    let indexes = parse_derivation_path(path)
+        .map_err(|e| VanityError::InvalidPath(format!("Invalid derivation path: {}", e)))?;
+
+    let derived_address = derive_address_at(&mnemonic, &indexes)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))?;
+
+    let mut issues = ledger_path_issues(&indexes);
+    let path_allowed = issues.is_empty();
+
+    let vectors_passed = ledger_vectors_pass();
+    if !vectors_passed {
+        issues.push("This build does not reproduce the known-good Ledger vectors".to_string());
+    }
+
+    let address_matches = derived_address == expected_address.trim().to_lowercase();
+    if !address_matches {
+        issues.push(format!(
+            "Mnemonic derives {} at {}, not the expected address",
+            derived_address,
+            format_derivation_path(&indexes)
+        ));
+    }
+
+    Ok(LedgerCompatibility {
+        path_allowed,
+        vectors_passed,
+        address_matches,
+        derived_address,
+        issues,
+    })
+}
+
+/// Returns the reasons the Ledger Cosmos app would refuse or restrict a path
+fn ledger_path_issues(path: &[u32]) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    if path.len() != 5 {
+        issues.push(format!(
+            "Ledger requires 5 path levels (m/44'/118'/account'/change/index), got {}",
+            path.len()
+        ));
+        return issues;
+    }
+    if path[0] != 44 + HARDENED_OFFSET {
+        issues.push("Ledger requires purpose 44'".to_string());
+    }
+    if path[1] != 118 + HARDENED_OFFSET {
+        issues.push("The Ledger Cosmos app only derives coin type 118'".to_string());
+    }
+    if path[2] < HARDENED_OFFSET {
+        issues.push("Ledger requires a hardened account level".to_string());
+    }
+    if path[3] >= HARDENED_OFFSET || path[4] >= HARDENED_OFFSET {
+        issues.push("Ledger requires non-hardened change and address index levels".to_string());
+    } else if path[3] != 0 {
+        issues.push("Change levels other than 0 require Ledger expert mode".to_string());
+    }
+
+    issues
+}
+
+/// Runs the embedded known-good vectors through this build's derivation
+fn ledger_vectors_pass() -> bool {
+    LEDGER_VECTORS.iter().all(|&(mnemonic_str, path, address)| {
+        let Ok(mnemonic) = parse_mnemonic(mnemonic_str) else {
+            return false;
+        };
+        let Ok(indexes) = parse_derivation_path(path) else {
+            return false;
+        };
+        derive_address_at(&mnemonic, &indexes).is_ok_and(|derived| derived == address)
+    })
+}
+
+/// Derives the classic Cosmos-scheme MANTRA address at a path
+fn derive_address_at(
+    mnemonic: &Mnemonic,
+    path: &[u32],
+) -> Result<String, Box<dyn std::error::Error>> {
+    let derived = derive_key_at_path(mnemonic, path)?;
+    public_key_to_address(&derived.public_key()?)
+}
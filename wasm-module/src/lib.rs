@@ -6,18 +6,141 @@
 //! - Open/Closed: Easy to extend with new address types or validation rules
 //! - Dependency Inversion: Uses trait-based abstractions where applicable
 
-use bech32::{encode as bech32_encode, ToBase32, Variant};
+mod address;
+mod address_book;
+mod audit;
+mod bip85;
+mod brainwallet;
+mod bulk;
+mod chains;
+mod crosscheck;
+mod ct;
+mod derived;
+mod dice;
+mod entropy;
+mod entropy_check;
+mod error;
+mod hardened;
+mod hd;
+mod health;
+mod keypair_json;
+mod keystore;
+mod ledger;
+mod metamask;
+mod mnemonic;
+mod multisig;
+mod network;
+mod ownership;
+mod paper;
+mod qr;
+mod recovery;
+mod redact;
+mod rng;
+mod schema;
+mod scrub;
+mod search;
+mod selftest;
+mod signing;
+mod similarity;
+mod slip10;
+mod slip39;
+mod tendermint;
+mod trace;
+mod vectors;
+mod watch_only;
+mod wordlist;
+
+pub use address::{
+    account_id_from_address, address_from_pubkey, check_hrp, consensus_address_from_pubkey,
+    convert_address_prefix, suggest_address_corrections, uppercase_address, validate_address,
+    validate_hrp, AddressValidation, Bech32Variant,
+};
+pub use address_book::{AddressBook, AddressBookEntry};
+pub use audit::set_export_audit_hook;
+pub use bip85::derive_bip85_mnemonic;
+pub use brainwallet::derive_brainwallet_keypair;
+pub use bulk::{generate_batch, ExportFormat, KeypairExport};
+pub use chains::{list_chains, preset, ChainPreset};
+pub use crosscheck::{paranoid_mode_available, set_paranoid_mode};
+pub use derived::{
+    grind_instantiate2_salt_batch, ibc_escrow_address, ica_host_address, module_account_address,
+    predict_instantiate2_address, SaltMatch,
+};
+pub use dice::{
+    coin_flips_to_mnemonic, dice_to_mnemonic, required_coin_flips, required_dice_rolls,
+};
+pub use entropy::generate_random_keypair_with_extra_entropy;
+pub use entropy_check::{analyze_coin_flips, analyze_dice_rolls, analyze_entropy, EntropyReport};
+pub use error::VanityError;
+pub use hardened::{enable_hardened_mode, is_hardened_mode};
+pub use hd::{validate_derivation_path, PathValidation};
+pub use health::rng_health_failure;
+pub use keystore::{
+    decrypt_keypairs, decrypt_mnemonic, encrypt_mnemonic, export_armored_private_key,
+    export_cosmjs_wallet, export_cosmostation_encrypted_mnemonic, export_keplr_keystore,
+    export_leap_encrypted_mnemonic, KdfOptions, KeypairArchive,
+};
+pub use ledger::{check_ledger_compatibility, LedgerCompatibility};
+pub use metamask::{export_metamask_private_key, MetaMaskExport};
+pub use mnemonic::{
+    analyze_mnemonic_checksum, entropy_to_mnemonic, mnemonic_to_entropy, normalize_mnemonic,
+    normalize_passphrase, translate_mnemonic, ChecksumAnalysis, InputNormalization,
+    MnemonicEntropy, MnemonicTranslation,
+};
+pub use multisig::{generate_multisig_vanity_batch, multisig_address, MultisigVanityMatch};
+pub use network::{is_address_for_network, Network, NetworkKind};
+pub use ownership::{mnemonic_owns_address, mnemonic_owns_addresses, AddressOwnership};
+pub use paper::paper_wallet_svg;
+pub use qr::{
+    address_qr_matrix, address_qr_svg, encrypted_mnemonic_qr_svg, mnemonic_qr_svg, QrMatrix,
+};
+pub use recovery::{
+    detect_swapped_words, diagnose_mnemonic, recover_missing_words, suggest_mnemonic_corrections,
+    valid_last_words, MissingWordSearch, MnemonicCorrection, MnemonicDiagnostics, UnknownWord,
+    WordOrderFix,
+};
+#[cfg(feature = "deterministic-tests")]
+pub use rng::set_deterministic_seed;
+pub use rng::{is_deterministic_build, KeyRng};
+pub use schema::migrate_json;
+pub use scrub::scrub_memory;
+pub use search::{search_vanity, VanitySearchOutcome, VanitySearchSpec, VanitySearchStats};
+pub use selftest::{self_test, startup_self_test_passed, SelfTestReport};
+pub use signing::{
+    sign_adr36, sign_bytes, verify_signature, Adr36Signature, ByteSignature, SignatureVerification,
+};
+pub use similarity::{
+    address_similarity, generate_screened_vanity_keypair_batch, screen_vanity_match,
+    AddressSimilarity, ScreenedVanityMatch, ScreeningVerdict,
+};
+pub use slip10::{derive_ed25519_keypair, Ed25519Keypair};
+pub use slip39::{combine_slip39_shares, split_mnemonic_slip39};
+pub use tendermint::{
+    generate_consensus_keypair, generate_consensus_vanity_keypair_batch,
+    generate_node_id_vanity_batch, generate_node_keypair, ConsensusKeypair, NodeKeypair,
+};
+pub use trace::{trace_derivation, DerivationStep, DerivationTrace};
+pub use vectors::{verify_derivation_vectors, VectorResult};
+pub use watch_only::export_watch_only;
+pub use wordlist::suggest_words;
+
+use address::MANTRA_HRP;
+use audit::audit_export;
+use base64::prelude::{Engine, BASE64_STANDARD};
 use bip39::Mnemonic;
-use hmac::{Hmac, Mac};
-use k256::{
-    ecdsa::SigningKey,
-    elliptic_curve::PrimeField,
-    Scalar,
+use crosscheck::paranoid_checked;
+use hardened::check_plaintext_secrets;
+use hd::{
+    format_derivation_path, ExtendedPrivateKey, ExtendedPublicKey, COSMOS_ACCOUNT_PATH,
+    COSMOS_ADDRESS_PATH, EVM_ADDRESS_PATH, HARDENED_OFFSET,
 };
-use rand::{rngs::OsRng, RngCore};
-use ripemd::Ripemd160;
-use sha2::{Digest, Sha256, Sha512};
+use k256::{ecdsa::SigningKey, elliptic_curve::sec1::ToEncodedPoint, PublicKey};
+use mnemonic::parse_mnemonic;
+use rand::{CryptoRng, RngCore};
+use redact::{redact_option, Redacted};
+use std::fmt;
 use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
 
 // When the `console_error_panic_hook` feature is enabled, we can call the
 // `set_panic_hook` function at least once during initialization, and then
@@ -25,21 +148,79 @@ use wasm_bindgen::prelude::*;
 //
 // For more details see
 // https://github.com/rustwasm/console_error_panic_hook#readme
-#[cfg(feature = "console_error_panic_hook")]
+//
+// The known-answer self-test also runs here, so a corrupted or miscompiled
+// module is reported before it generates anything.
 #[wasm_bindgen(start)]
 pub fn main() {
+    #[cfg(feature = "console_error_panic_hook")]
     console_error_panic_hook::set_once();
+
+    let report = selftest::startup_self_test();
+    if !report.passed() {
+        web_sys::console::error_1(
+            &format!(
+                "vanity-wasm self-test failed: {}",
+                report.failures().join("; ")
+            )
+            .into(),
+        );
+    }
+}
+
+/// Account key scheme used to derive a keypair and encode its address
+#[wasm_bindgen]
+#[derive(Copy, Clone, PartialEq, Default, Debug)]
+pub enum AccountScheme {
+    /// Classic Cosmos secp256k1: coin type 118, SHA256+RIPEMD160 of the compressed pubkey
+    #[default]
+    Cosmos = 0,
+    /// Evmos-style ethsecp256k1: coin type 60, keccak256 of the uncompressed pubkey
+    EthSecp256k1 = 1,
+}
+
+impl AccountScheme {
+    /// Returns the scheme name used in exported records ("cosmos" or "ethsecp256k1")
+    fn name(self) -> &'static str {
+        match self {
+            AccountScheme::Cosmos => "cosmos",
+            AccountScheme::EthSecp256k1 => "ethsecp256k1",
+        }
+    }
+
+    /// Returns the standard derivation path for the scheme
+    fn derivation_path(self) -> &'static [u32] {
+        match self {
+            AccountScheme::Cosmos => &COSMOS_ADDRESS_PATH,
+            AccountScheme::EthSecp256k1 => &EVM_ADDRESS_PATH,
+        }
+    }
+
+    /// Encodes a compressed public key as a bech32 address under the scheme
+    fn address(self, pubkey_bytes: &[u8], hrp: &str) -> Result<String, Box<dyn std::error::Error>> {
+        match self {
+            AccountScheme::Cosmos => address::public_key_to_address(pubkey_bytes, hrp),
+            AccountScheme::EthSecp256k1 => address::evm_public_key_to_address(pubkey_bytes, hrp),
+        }
+    }
 }
 
 /// Represents a cryptographic keypair with its associated MANTRA address
 ///
 /// This struct encapsulates the core data needed for a blockchain identity:
 /// - The bech32-encoded address for receiving funds
-/// - The BIP39 mnemonic phrase for wallet recovery
+/// - The BIP39 mnemonic phrase for wallet recovery (absent for imported raw keys)
+/// - The compressed secp256k1 public key, when known
+/// - The derivation path used to produce the address, when known
 #[wasm_bindgen]
+#[derive(Clone)]
 pub struct Keypair {
     address: String,
-    mnemonic: String,
+    mnemonic: Option<String>,
+    public_key: Option<[u8; 33]>,
+    derivation_path: Option<Vec<u32>>,
+    scheme: AccountScheme,
+    private_key_hex: Option<String>,
 }
 
 #[wasm_bindgen]
@@ -51,7 +232,14 @@ impl Keypair {
     /// * `mnemonic` - The BIP39 mnemonic phrase
     #[wasm_bindgen(constructor)]
     pub fn new(address: String, mnemonic: String) -> Keypair {
-        Keypair { address, mnemonic }
+        Keypair {
+            address,
+            mnemonic: Some(mnemonic),
+            public_key: None,
+            derivation_path: None,
+            scheme: AccountScheme::Cosmos,
+            private_key_hex: None,
+        }
     }
 
     /// Gets the address field (getter for JavaScript)
@@ -60,10 +248,331 @@ impl Keypair {
         self.address.clone()
     }
 
-    /// Gets the mnemonic field (getter for JavaScript)
+    /// Gets the address in all-uppercase bech32 form (e.g. `MANTRA1...`)
+    ///
+    /// Equivalent to `address`; the uppercase form makes denser QR codes.
+    #[wasm_bindgen(getter)]
+    pub fn address_uppercase(&self) -> String {
+        self.address.to_uppercase()
+    }
+
+    /// Gets the raw account ID (the 20-byte hash behind `address`) as hex
+    #[wasm_bindgen(getter)]
+    pub fn account_id_hex(&self) -> Option<String> {
+        self.account_id_bytes().map(hex::encode)
+    }
+
+    /// Gets the raw account ID (the 20-byte hash behind `address`) as bytes
+    ///
+    /// This is the canonical binary address indexers and contracts store.
+    #[wasm_bindgen(getter)]
+    pub fn account_id_bytes(&self) -> Option<Vec<u8>> {
+        address::decode_bech32(&self.address)
+            .ok()
+            .map(|(_, data)| data)
+    }
+
+    /// Gets the validator operator address of the same account (e.g. `mantravaloper1...`)
+    ///
+    /// Validators need this form alongside `address` when creating or editing
+    /// their validator; both encode the same account ID.
+    #[wasm_bindgen(getter)]
+    pub fn valoper_address(&self) -> Option<String> {
+        address::operator_address(&self.address).ok()
+    }
+
+    /// Gets whether the keypair still holds a mnemonic that can be revealed
+    #[wasm_bindgen(getter)]
+    pub fn has_mnemonic(&self) -> bool {
+        self.mnemonic.is_some()
+    }
+
+    /// Hands the mnemonic to JavaScript on an explicit user action
+    ///
+    /// A search result is an opaque handle: the phrase has no getter, so until
+    /// this (or a deliberate export such as `to_json(true)`) is called it exists
+    /// only in WASM memory, and the UI can show the address alone. With
+    /// `one_shot` the phrase is wiped from the keypair once returned, so it can
+    /// be revealed exactly once and later `to_json(true)` records no longer carry
+    /// it. Each reveal is reported to the hook registered with
+    /// `set_export_audit_hook()`.
+    ///
+    /// # Arguments
+    /// * `one_shot` - true to wipe the mnemonic from the keypair after revealing it
+    ///
+    /// # Returns
+    /// * `String` - The mnemonic phrase, or an error if the keypair has none
+    ///   (imported raw key, or already revealed one-shot), hardened mode is on
+    ///   or the audit hook failed
+    ///
+    /// # Example
+    /// ```javascript
+    /// revealButton.onclick = () => (mnemonicBox.textContent = keypair.reveal_mnemonic(true));
+    /// ```
+    pub fn reveal_mnemonic(&mut self, one_shot: bool) -> Result<String, VanityError> {
+        let Some(mnemonic) = &self.mnemonic else {
+            return Err(VanityError::InvalidInput(
+                "Keypair has no mnemonic to reveal".to_string(),
+            ));
+        };
+        check_plaintext_secrets("the mnemonic")?;
+        audit_export("mnemonic", Some(&self.address))?;
+
+        let mnemonic = mnemonic.clone();
+        if one_shot {
+            // Wipes the phrase and leaves `None`
+            self.mnemonic.zeroize();
+        }
+        Ok(mnemonic)
+    }
+
+    /// Gets the BIP32 derivation path used to produce the address (e.g. `m/44'/118'/0'/0/0`)
+    ///
+    /// Import the mnemonic elsewhere and select this account/index to get the same address.
+    #[wasm_bindgen(getter)]
+    pub fn derivation_path(&self) -> Option<String> {
+        self.derivation_path.as_deref().map(format_derivation_path)
+    }
+
+    /// Gets the account scheme used to derive the address
+    #[wasm_bindgen(getter)]
+    pub fn scheme(&self) -> AccountScheme {
+        self.scheme
+    }
+
+    /// Gets the EIP-55 checksummed `0x...` address for EVM-style (ethsecp256k1) keypairs
+    ///
+    /// This is the same account as `address`, shown the way MetaMask displays it.
+    /// It is `undefined` for keypairs using the classic Cosmos scheme, whose
+    /// account ID is not an Ethereum address.
+    #[wasm_bindgen(getter)]
+    pub fn evm_address(&self) -> Option<String> {
+        if self.scheme != AccountScheme::EthSecp256k1 {
+            return None;
+        }
+
+        let account_id = address::evm_account_id(&self.public_key?).ok()?;
+        Some(address::to_checksum_hex_address(&account_id))
+    }
+
+    /// Gets the public key in legacy amino JSON form
+    ///
+    /// This is `{"type":"tendermint/PubKeySecp256k1","value":"<base64>"}`, as
+    /// required by older CLIs and multisig setup. It is `undefined` when the
+    /// public key is not known or the keypair does not use the Cosmos scheme.
+    #[wasm_bindgen(getter)]
+    pub fn public_key_amino_json(&self) -> Option<String> {
+        if self.scheme != AccountScheme::Cosmos {
+            return None;
+        }
+
+        let amino = serde_json::json!({
+            "type": "tendermint/PubKeySecp256k1",
+            "value": BASE64_STANDARD.encode(self.public_key?),
+        });
+        Some(amino.to_string())
+    }
+
+    /// Gets the public key as protobuf `Any` JSON
+    ///
+    /// This is `{"@type":"/cosmos.crypto.secp256k1.PubKey","key":"<base64>"}`,
+    /// the form modern SDK commands (e.g. `keys add --pubkey`, `tx staking
+    /// create-validator`) accept. It is `undefined` when the public key is not
+    /// known or the keypair does not use the Cosmos scheme.
     #[wasm_bindgen(getter)]
-    pub fn mnemonic(&self) -> String {
-        self.mnemonic.clone()
+    pub fn public_key_any_json(&self) -> Option<String> {
+        if self.scheme != AccountScheme::Cosmos {
+            return None;
+        }
+
+        let any = serde_json::json!({
+            "@type": "/cosmos.crypto.secp256k1.PubKey",
+            "key": BASE64_STANDARD.encode(self.public_key?),
+        });
+        Some(any.to_string())
+    }
+
+    /// Gets the public key in legacy bech32 form (e.g. `mantrapub1...`)
+    ///
+    /// Older Cosmos tooling and explorers display public keys this way. It is
+    /// `undefined` when the public key is not known or the keypair does not use
+    /// the Cosmos scheme.
+    #[wasm_bindgen(getter)]
+    pub fn bech32_public_key(&self) -> Option<String> {
+        if self.scheme != AccountScheme::Cosmos {
+            return None;
+        }
+
+        let (hrp, _, _) = bech32::decode(&self.address).ok()?;
+        address::bech32_public_key(&self.public_key?, &hrp).ok()
+    }
+
+    /// Gets the public key as hex
+    ///
+    /// # Arguments
+    /// * `compressed` - true for the 33-byte compressed form, false for the 65-byte uncompressed form
+    ///
+    /// # Returns
+    /// * `Option<String>` - The hex-encoded public key, or undefined if it is not known
+    pub fn public_key_hex(&self, compressed: bool) -> Option<String> {
+        self.encoded_public_key(compressed).map(hex::encode)
+    }
+
+    /// Gets the public key as standard base64 (the encoding used by Cosmos SDK tooling)
+    ///
+    /// # Arguments
+    /// * `compressed` - true for the 33-byte compressed form, false for the 65-byte uncompressed form
+    ///
+    /// # Returns
+    /// * `Option<String>` - The base64-encoded public key, or undefined if it is not known
+    pub fn public_key_base64(&self, compressed: bool) -> Option<String> {
+        self.encoded_public_key(compressed)
+            .map(|bytes| BASE64_STANDARD.encode(bytes))
+    }
+
+    /// Returns the raw private key as hex, guarded by an explicit confirmation
+    ///
+    /// This is the only way to read a raw key out of a keypair. A key derived
+    /// from the mnemonic is not kept on the keypair, so it does not leak into
+    /// later `to_json(true)` records. The export is refused unless `confirm` is
+    /// true, and it is reported to the hook registered with
+    /// `set_export_audit_hook()` before the key is returned.
+    ///
+    /// # Arguments
+    /// * `confirm` - Must be true to confirm the raw key should be exposed
+    ///
+    /// # Returns
+    /// * `String` - The hex-encoded 32-byte private key, or an error if not
+    ///   confirmed, the key cannot be derived, hardened mode is on or the
+    ///   audit hook failed
+    ///
+    /// # Example
+    /// ```javascript
+    /// const privateKey = keypair.export_private_key_hex(userConfirmed);
+    /// ```
+    pub fn export_private_key_hex(&self, confirm: bool) -> Result<String, VanityError> {
+        if !confirm {
+            return Err(VanityError::NotAcknowledged(
+                "Refusing to export a raw private key without confirm = true".to_string(),
+            ));
+        }
+
+        let private_key_hex = self.raw_private_key_hex()?;
+        audit_export("private_key_hex", Some(&self.address))?;
+        Ok(private_key_hex)
+    }
+
+    /// Describes the keypair by its address, never its secrets
+    ///
+    /// Template literals and `String(keypair)` call this, so a keypair that
+    /// ends up in a log message shows as `Keypair(mantra1...)`.
+    ///
+    /// # Returns
+    /// * `String` - `Keypair(<address>)`
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_display_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Shows public fields only; the mnemonic and private key print as `[REDACTED]`
+impl fmt::Debug for Keypair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Keypair")
+            .field("address", &self.address)
+            .field("mnemonic", &redact_option(&self.mnemonic))
+            .field("public_key", &self.public_key.map(hex::encode))
+            .field("derivation_path", &self.derivation_path())
+            .field("scheme", &self.scheme)
+            .field("private_key_hex", &redact_option(&self.private_key_hex))
+            .finish()
+    }
+}
+
+impl fmt::Display for Keypair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Keypair({})", self.address)
+    }
+}
+
+/// Wipes the mnemonic and any imported private key, including when the
+/// keypair is freed from JavaScript
+impl Drop for Keypair {
+    fn drop(&mut self) {
+        self.mnemonic.zeroize();
+        self.private_key_hex.zeroize();
+    }
+}
+
+impl Keypair {
+    /// Creates a keypair for a key derived from a mnemonic at a known path
+    fn derived(
+        address: String,
+        mnemonic: String,
+        public_key: [u8; 33],
+        scheme: AccountScheme,
+        path: &[u32],
+    ) -> Keypair {
+        Keypair {
+            address,
+            mnemonic: Some(mnemonic),
+            public_key: Some(public_key),
+            derivation_path: Some(path.to_vec()),
+            scheme,
+            private_key_hex: None,
+        }
+    }
+
+    /// Creates a keypair for an imported raw private key (no mnemonic or path)
+    fn from_raw_key(address: String, public_key: [u8; 33], private_key_hex: String) -> Keypair {
+        Keypair {
+            address,
+            mnemonic: None,
+            public_key: Some(public_key),
+            derivation_path: None,
+            scheme: AccountScheme::Cosmos,
+            private_key_hex: Some(private_key_hex),
+        }
+    }
+
+    /// Returns the raw private key as hex, deriving it from the mnemonic if it was not imported
+    ///
+    /// The key is derived at the recorded derivation path, or the standard
+    /// MANTRA path (m/44'/118'/0'/0/0) when no path is recorded, and is not kept
+    /// on the keypair. Callers confirm and audit the export themselves.
+    fn raw_private_key_hex(&self) -> Result<String, VanityError> {
+        check_plaintext_secrets("the raw private key")?;
+        if let Some(private_key_hex) = &self.private_key_hex {
+            return Ok(private_key_hex.clone());
+        }
+
+        let mnemonic_str = self.mnemonic.as_deref().ok_or_else(|| {
+            VanityError::InvalidInput(
+                "Keypair has no mnemonic to derive a private key from".to_string(),
+            )
+        })?;
+        let mnemonic = parse_mnemonic(mnemonic_str)
+            .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+        let path = self
+            .derivation_path
+            .as_deref()
+            .unwrap_or(&COSMOS_ADDRESS_PATH);
+        let derived = derive_key_at_path(&mnemonic, path).map_err(|e| {
+            VanityError::DerivationFailed(format!("Error deriving private key: {}", e))
+        })?;
+
+        Ok(hex::encode(Zeroizing::new(derived.private_key_bytes())))
+    }
+
+    /// Returns the SEC1-encoded public key in compressed or uncompressed form
+    fn encoded_public_key(&self, compressed: bool) -> Option<Vec<u8>> {
+        let public_key = self.public_key?;
+        if compressed {
+            return Some(public_key.to_vec());
+        }
+
+        let point = PublicKey::from_sec1_bytes(&public_key).ok()?;
+        Some(point.to_encoded_point(false).as_bytes().to_vec())
     }
 }
 
@@ -74,92 +583,88 @@ impl Keypair {
 /// 2. Derive master key using HMAC-SHA512 with "Bitcoin seed"
 /// 3. Follow derivation path m/44'/118'/0'/0/0 with proper secp256k1 arithmetic
 /// 4. Hash derived public key with SHA256 then RIPEMD160
-/// 5. Encode with bech32 using "mantra" prefix
+/// 5. Encode with bech32 using the given prefix ("mantra" for MANTRA)
 ///
 /// # Arguments
 /// * `mnemonic` - The BIP39 mnemonic to derive from
+/// * `hrp` - The bech32 human-readable part (e.g. "mantra")
 ///
 /// # Returns
-/// * `String` - The bech32-encoded MANTRA address
-fn derive_address(mnemonic: &Mnemonic) -> Result<String, Box<dyn std::error::Error>> {
-    // Generate seed from mnemonic (BIP39 standard with empty passphrase)
-    let seed = mnemonic.to_seed("");
+/// * `String` - The bech32-encoded address
+fn derive_address(mnemonic: &Mnemonic, hrp: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let derived = derive_account_key(mnemonic)?;
 
-    // Create master key using HMAC-SHA512 with "Bitcoin seed" (BIP32 standard)
-    let mut mac = Hmac::<Sha512>::new_from_slice(b"Bitcoin seed")
-        .map_err(|e| format!("Failed to create HMAC: {}", e))?;
-    mac.update(&seed);
-    let result = mac.finalize().into_bytes();
-
-    // Split into master private key (left 32 bytes) and chain code (right 32 bytes)
-    let mut current_private_key_bytes = [0u8; 32];
-    let mut current_chain_code = [0u8; 32];
-    current_private_key_bytes.copy_from_slice(&result[0..32]);
-    current_chain_code.copy_from_slice(&result[32..64]);
+    // Get compressed public key bytes (33 bytes, starts with 0x02 or 0x03)
+    let pubkey_bytes = derived.public_key()?;
 
-    // Convert to scalar for arithmetic operations
-    let mut current_private_scalar = Scalar::from_repr(current_private_key_bytes.into()).unwrap(); // Safe unwrap - master key is always valid
+    address::public_key_to_address(&pubkey_bytes, hrp)
+}
 
+/// Derives the extended private key at m/44'/118'/0'/0/0 for a BIP39 mnemonic
+///
+/// # Arguments
+/// * `mnemonic` - The BIP39 mnemonic to derive from
+///
+/// # Returns
+/// * `ExtendedPrivateKey` - The derived key for the first MANTRA account address
+fn derive_account_key(
+    mnemonic: &Mnemonic,
+) -> Result<ExtendedPrivateKey, Box<dyn std::error::Error>> {
     // Derive using path m/44'/118'/0'/0/0 (Cosmos standard for MANTRA)
-    let derivation_path: [u32; 5] = [
-        44 + 0x80000000,  // purpose (hardened) - BIP44
-        118 + 0x80000000, // coin type for Cosmos (hardened)
-        0x80000000,       // account 0 (hardened)
-        0,                // change 0 (non-hardened)
-        0,                // address_index 0 (non-hardened)
-    ];
-
-    // Derive through each path component using proper BIP32 secp256k1 derivation
-    for &index in &derivation_path {
-        let mut mac = Hmac::<Sha512>::new_from_slice(&current_chain_code)
-            .map_err(|e| format!("Failed to create HMAC for derivation: {}", e))?;
-
-        if index >= 0x80000000 {
-            // Hardened derivation: use 0x00 + private_key + index
-            mac.update(&[0x00]);
-            mac.update(&current_private_scalar.to_bytes());
-        } else {
-            // Non-hardened derivation: use compressed_public_key + index
-            let signing_key = SigningKey::from_bytes(&current_private_scalar.to_bytes().into())
-                .map_err(|e| format!("Failed to create signing key for derivation: {}", e))?;
-            let pubkey = signing_key.verifying_key().to_encoded_point(true);
-            mac.update(pubkey.as_bytes());
-        }
-
-        mac.update(&index.to_be_bytes());
-        let derived = mac.finalize().into_bytes();
-
-        // Parse left 32 bytes as the derived key scalar
-        let mut derived_key_bytes = [0u8; 32];
-        derived_key_bytes.copy_from_slice(&derived[0..32]);
-        let derived_scalar = Scalar::from_repr(derived_key_bytes.into()).unwrap(); // Safe unwrap - derived key is always valid
-
-        // BIP32 key derivation: new_key = (parent_key + derived_key) mod n
-        // This is the critical step that was missing in our previous implementation
-        current_private_scalar = current_private_scalar.add(&derived_scalar);
-
-        // Update chain code for next iteration
-        current_chain_code.copy_from_slice(&derived[32..64]);
-    }
+    derive_key_at_path(mnemonic, &COSMOS_ADDRESS_PATH)
+}
 
-    // Create final signing key from the computed private key
-    let final_private_key_bytes = current_private_scalar.to_bytes();
-    let signing_key = SigningKey::from_bytes(&final_private_key_bytes.into())
-        .map_err(|e| format!("Failed to create final signing key: {}", e))?;
+/// Derives the extended private key at an arbitrary BIP32 path for a BIP39 mnemonic
+///
+/// # Arguments
+/// * `mnemonic` - The BIP39 mnemonic to derive from
+/// * `path` - The child indexes to derive through (hardened indexes include `HARDENED_OFFSET`)
+///
+/// # Returns
+/// * `ExtendedPrivateKey` - The derived key at the given path
+fn derive_key_at_path(
+    mnemonic: &Mnemonic,
+    path: &[u32],
+) -> Result<ExtendedPrivateKey, Box<dyn std::error::Error>> {
+    // Generate seed from mnemonic (BIP39 standard with empty passphrase)
+    let seed = Zeroizing::new(mnemonic.to_seed(""));
 
-    // Get compressed public key bytes (33 bytes, starts with 0x02 or 0x03)
-    let pubkey = signing_key.verifying_key().to_encoded_point(true);
-    let pubkey_bytes = pubkey.as_bytes();
+    derive_key_from_seed(&*seed, path)
+}
 
-    // Standard Cosmos address derivation: SHA256 then RIPEMD160 of public key
-    let sha256_hash = Sha256::digest(pubkey_bytes);
-    let ripemd_hash = Ripemd160::digest(&sha256_hash);
+/// Derives the extended private key at a BIP32 path directly from a seed
+///
+/// # Arguments
+/// * `seed` - The BIP39 seed bytes
+/// * `path` - The child indexes to derive through
+///
+/// # Returns
+/// * `ExtendedPrivateKey` - The derived key at the given path
+fn derive_key_from_seed(
+    seed: &[u8],
+    path: &[u32],
+) -> Result<ExtendedPrivateKey, Box<dyn std::error::Error>> {
+    // Create master key and derive through each path component
+    let master = ExtendedPrivateKey::master(seed)?;
+    master.derive_path(path)
+}
 
-    // Encode with bech32 using MANTRA prefix
-    let address = bech32_encode("mantra", ripemd_hash.to_base32(), Variant::Bech32)
-        .map_err(|e| format!("Failed to encode bech32 address: {}", e))?;
+/// Encodes a compressed secp256k1 public key as a MANTRA address
+///
+/// # Arguments
+/// * `pubkey_bytes` - The 33-byte compressed public key
+///
+/// # Returns
+/// * `String` - The bech32-encoded MANTRA address
+fn public_key_to_address(pubkey_bytes: &[u8]) -> Result<String, Box<dyn std::error::Error>> {
+    address::public_key_to_address(pubkey_bytes, MANTRA_HRP)
+}
 
-    Ok(address)
+/// Resolves an optional caller-supplied HRP, defaulting to "mantra"
+///
+/// Addresses are always rendered in lowercase, so the HRP is lowercased too.
+fn hrp_or_default(hrp: Option<String>) -> String {
+    hrp.map_or_else(|| MANTRA_HRP.to_string(), |hrp| hrp.trim().to_lowercase())
 }
 
 /// Generates a random keypair for the MANTRA blockchain
@@ -170,30 +675,222 @@ fn derive_address(mnemonic: &Mnemonic) -> Result<String, Box<dyn std::error::Err
 /// - 24-word mnemonic for maximum security (256 bits entropy)
 /// - Deterministic address derivation following standards
 ///
+/// # Arguments
+/// * `hrp` - Optional bech32 prefix for the address (defaults to "mantra"; e.g. "cosmos", "osmo")
+///
 /// # Returns
-/// * `Keypair` - A new keypair with random mnemonic and derived address
+/// * `Keypair` - A new keypair with random mnemonic and derived address, or a
+///   `VanityError` (`InvalidHrp`, `RngFailure`, `CrossCheckFailed`)
+///
 ///
 /// # Example
 /// ```javascript
 /// const keypair = generate_random_keypair();
 /// console.log(`Address: ${keypair.address}`);
-/// console.log(`Mnemonic: ${keypair.mnemonic}`);
+/// console.log(`Mnemonic: ${keypair.reveal_mnemonic(false)}`);
 /// ```
 #[wasm_bindgen]
-pub fn generate_random_keypair() -> Keypair {
-    let mut rng = OsRng;
+pub fn generate_random_keypair(hrp: Option<String>) -> Result<Keypair, VanityError> {
+    generate_random_keypair_with_scheme(AccountScheme::Cosmos, hrp)
+}
+
+/// Generates a random keypair for the MANTRA blockchain using the given account scheme
+///
+/// This is the scheme-aware form of `generate_random_keypair()`. With
+/// `AccountScheme::EthSecp256k1` the key is derived at m/44'/60'/0'/0/0 and the
+/// address is the bech32 encoding of the keccak256-derived account ID, matching
+/// MANTRA's EVM-compatible accounts.
+///
+/// # Arguments
+/// * `scheme` - The account scheme (Cosmos or EthSecp256k1)
+/// * `hrp` - Optional bech32 prefix for the address (defaults to "mantra")
+///
+/// # Returns
+/// * `Keypair` - A new keypair with random mnemonic and derived address, or a
+///   `VanityError` (`InvalidHrp`, `RngFailure`, `CrossCheckFailed`)
+///
+#[wasm_bindgen]
+pub fn generate_random_keypair_with_scheme(
+    scheme: AccountScheme,
+    hrp: Option<String>,
+) -> Result<Keypair, VanityError> {
+    paranoid_checked(random_keypair(scheme, &hrp_or_default(hrp))?)
+}
+
+/// Generates a random 24-word keypair with its address encoded under `hrp`
+///
+/// Draws from `KeyRng`. Fails instead of panicking, so a bad prefix or a
+/// failed RNG health test throws in JavaScript rather than aborting the WASM
+/// instance.
+fn random_keypair(scheme: AccountScheme, hrp: &str) -> Result<Keypair, VanityError> {
+    generate_keypair_with_rng(&mut KeyRng, scheme, hrp)
+}
+
+/// Generates a random 24-word keypair from a caller-supplied RNG (Rust API)
+///
+/// Native and server users of the crate can pass their own CSPRNG or a
+/// hardware RNG wrapper here, and tests can pass a seeded one. Pass `KeyRng`
+/// for what the JavaScript generators use: `OsRng` behind the continuous
+/// health tests. Only the mnemonic entropy comes from `rng`.
+///
+/// # Arguments
+/// * `rng` - Any cryptographically secure RNG
+/// * `scheme` - The account scheme (Cosmos or EthSecp256k1)
+/// * `hrp` - The bech32 prefix for the address
+///
+/// # Returns
+/// * `Keypair` - A new keypair, or a `VanityError` for an invalid prefix or a failing RNG
+///
+/// # Example
+/// ```no_run
+/// use rand::rngs::OsRng;
+/// use vanity_wasm::{generate_keypair_with_rng, AccountScheme};
+///
+/// let keypair = generate_keypair_with_rng(&mut OsRng, AccountScheme::Cosmos, "mantra")?;
+/// # Ok::<(), vanity_wasm::VanityError>(())
+/// ```
+pub fn generate_keypair_with_rng<R: RngCore + CryptoRng>(
+    rng: &mut R,
+    scheme: AccountScheme,
+    hrp: &str,
+) -> Result<Keypair, VanityError> {
+    check_hrp(hrp)?;
 
     // Generate 32 bytes of entropy for 24-word mnemonic (256 bits entropy)
-    let mut entropy = [0u8; 32];
-    rng.fill_bytes(&mut entropy);
+    let mut entropy = Zeroizing::new([0u8; 32]);
+    rng.try_fill_bytes(&mut *entropy)
+        .map_err(|e| VanityError::RngFailure(e.to_string()))?;
 
     // Generate mnemonic from entropy
-    let mnemonic = Mnemonic::from_entropy(&entropy).expect("Failed to generate mnemonic");
+    let mnemonic = Mnemonic::from_entropy(&*entropy)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error building mnemonic: {}", e)))?;
+
+    // Derive the corresponding address
+    keypair_from_mnemonic(&mnemonic, scheme, hrp)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))
+}
+
+/// Builds a keypair for a mnemonic using the scheme's standard derivation path
+///
+/// # Arguments
+/// * `mnemonic` - The BIP39 mnemonic to derive from
+/// * `scheme` - The account scheme used for the path and address encoding
+/// * `hrp` - The bech32 human-readable part for the address
+///
+/// # Returns
+/// * `Keypair` - The keypair with address, mnemonic, public key and path populated
+fn keypair_from_mnemonic(
+    mnemonic: &Mnemonic,
+    scheme: AccountScheme,
+    hrp: &str,
+) -> Result<Keypair, Box<dyn std::error::Error>> {
+    keypair_from_mnemonic_at_path(mnemonic, scheme, scheme.derivation_path(), hrp)
+}
+
+/// Builds a keypair for a mnemonic at an explicit derivation path
+///
+/// # Arguments
+/// * `mnemonic` - The BIP39 mnemonic to derive from
+/// * `scheme` - The account scheme used for the address encoding
+/// * `path` - The child indexes to derive through
+/// * `hrp` - The bech32 human-readable part for the address
+///
+/// # Returns
+/// * `Keypair` - The keypair with address, mnemonic, public key and path
+///   populated; the recorded path holds the child numbers actually derived,
+///   which differ from `path` if an invalid child was skipped
+fn keypair_from_mnemonic_at_path(
+    mnemonic: &Mnemonic,
+    scheme: AccountScheme,
+    path: &[u32],
+    hrp: &str,
+) -> Result<Keypair, Box<dyn std::error::Error>> {
+    let seed = Zeroizing::new(mnemonic.to_seed(""));
+    let (derived, derived_path) = ExtendedPrivateKey::master(&*seed)?.derive_path_recorded(path)?;
+    let public_key = derived.public_key()?;
+    let address = scheme.address(&public_key, hrp)?;
+
+    Ok(Keypair::derived(
+        address,
+        mnemonic.to_string(),
+        public_key,
+        scheme,
+        &derived_path,
+    ))
+}
+
+/// Derives a keypair from a mnemonic at a custom derivation path
+///
+/// The path is validated first (see `validate_derivation_path()`); invalid
+/// paths are rejected with the offending segment in the error message. Valid
+/// but non-standard paths are allowed, so check `validate_derivation_path()`
+/// for warnings before presenting the result to users.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `path` - The BIP32 path string (e.g. `m/44'/118'/0'/0/1`)
+/// * `scheme` - The account scheme used for the address encoding
+/// * `hrp` - Optional bech32 prefix for the address (defaults to "mantra")
+///
+/// # Returns
+/// * `Keypair` - The keypair at the given path, or an error
+///
+/// # Example
+/// ```javascript
+/// const keypair = derive_keypair_at_path("word1 ... word24", "m/44'/118'/0'/0/1", AccountScheme.Cosmos, undefined);
+/// console.log(`${keypair.derivation_path}: ${keypair.address}`);
+/// ```
+#[wasm_bindgen]
+pub fn derive_keypair_at_path(
+    mnemonic_str: &str,
+    path: &str,
+    scheme: AccountScheme,
+    hrp: Option<String>,
+) -> Result<Keypair, VanityError> {
+    let hrp = hrp_or_default(hrp);
+    check_hrp(&hrp)?;
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    let indexes = hd::parse_derivation_path(path)
+        .map_err(|e| VanityError::InvalidPath(format!("Invalid derivation path: {}", e)))?;
+
+    keypair_from_mnemonic_at_path(&mnemonic, scheme, &indexes, &hrp)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))
+}
+
+/// Constructs a keypair from caller-provided entropy bytes
+///
+/// This function builds the BIP39 mnemonic from entropy the user generated
+/// themselves (dice, hardware TRNG) and derives the MANTRA address, so this
+/// tool is only used for the derivation step.
+///
+/// # Arguments
+/// * `entropy` - 16, 20, 24, 28 or 32 bytes of entropy (12 to 24 words)
+///
+/// # Returns
+/// * `Keypair` - The keypair for the resulting mnemonic, or an error if the length is invalid
+///
+/// # Example
+/// ```javascript
+/// const entropy = new Uint8Array(32); // filled from your own entropy source
+/// const keypair = keypair_from_entropy(entropy);
+/// console.log(`Mnemonic: ${keypair.reveal_mnemonic(false)}`);
+/// ```
+#[wasm_bindgen]
+pub fn keypair_from_entropy(entropy: &[u8]) -> Result<Keypair, VanityError> {
+    if !matches!(entropy.len(), 16 | 20 | 24 | 28 | 32) {
+        return Err(VanityError::InvalidInput(format!(
+            "Invalid entropy length: {} bytes (expected 16, 20, 24, 28 or 32)",
+            entropy.len()
+        )));
+    }
 
-    // Derive the corresponding MANTRA address
-    let address = derive_address(&mnemonic).expect("Failed to derive address from mnemonic");
+    let mnemonic = Mnemonic::from_entropy(entropy).map_err(|e| {
+        VanityError::DerivationFailed(format!("Failed to generate mnemonic: {}", e))
+    })?;
 
-    Keypair::new(address, mnemonic.to_string())
+    keypair_from_mnemonic(&mnemonic, AccountScheme::Cosmos, MANTRA_HRP)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))
 }
 
 /// Validates if a target string is compatible with bech32 encoding
@@ -203,34 +900,153 @@ pub fn generate_random_keypair() -> Keypair {
 /// search patterns that could never be found.
 ///
 /// # Arguments
-/// * `target` - The target string to validate
+/// * `target` - The target string to validate
+///
+/// # Returns
+/// * `bool` - true if the target is valid for bech32 addresses
+#[wasm_bindgen]
+pub fn validate_target_string(target: &str) -> bool {
+    target
+        .chars()
+        .all(|ch| ch == '1' || address::BECH32_CHARSET.contains(ch))
+}
+
+/// Validates if a target string can appear in a `0x...` hex address
+///
+/// Hex addresses use the full 0-9a-f alphabet; uppercase letters are allowed
+/// because EIP-55 checksummed addresses are mixed-case.
+///
+/// # Arguments
+/// * `target` - The target string to validate
+///
+/// # Returns
+/// * `bool` - true if the target only contains hex digits
+#[wasm_bindgen]
+pub fn validate_evm_target_string(target: &str) -> bool {
+    target.chars().all(|ch| ch.is_ascii_hexdigit())
+}
+
+/// Lowercases a bech32 vanity target, rejecting characters no address contains
+fn check_target(target: &str) -> Result<String, VanityError> {
+    let target_lower = target.to_lowercase();
+    if !validate_target_string(&target_lower) {
+        return Err(VanityError::InvalidTarget(format!(
+            "Target {:?} can never match: bech32 addresses only contain {}",
+            target,
+            address::BECH32_CHARSET
+        )));
+    }
+    Ok(target_lower)
+}
+
+/// Rejects a hex vanity target containing anything but 0-9 and a-f
+fn check_hex_target(target: &str) -> Result<(), VanityError> {
+    if !validate_evm_target_string(target) {
+        return Err(VanityError::InvalidTarget(format!(
+            "Target {:?} can never match: hex addresses only contain 0-9 and a-f",
+            target
+        )));
+    }
+    Ok(())
+}
+
+/// Position where the vanity string should appear in the address
+#[wasm_bindgen]
+#[derive(Copy, Clone, PartialEq, Default)]
+pub enum VanityPosition {
+    /// Match anywhere in the address (default behavior)
+    #[default]
+    Anywhere = 0,
+    /// Match immediately after the HRP and separator (e.g. "mantra1")
+    Prefix = 1,
+    /// Match at the end of the address (in checksum portion)
+    ///
+    /// The last 6 characters are the bech32 checksum, which is recomputed from
+    /// the HRP, so a suffix found here does not survive a prefix conversion
+    Suffix = 2,
+    /// Match at the end of the data part, immediately before the 6-character
+    /// checksum, so the pattern is part of the account ID itself
+    BeforeChecksum = 3,
+}
+
+/// Number of trailing bech32 checksum characters in every address
+const BECH32_CHECKSUM_LENGTH: usize = 6;
+
+/// Number of data characters in a bech32-encoded 20-byte account ID
+const ACCOUNT_DATA_LENGTH: usize = 32;
+
+/// Checks whether an address contains the (lowercased) target at the given position
+///
+/// # Arguments
+/// * `address` - The bech32-encoded address to check
+/// * `target_lower` - The lowercased substring pattern to search for
+/// * `position` - Where the pattern should appear (Anywhere, Prefix, Suffix, or BeforeChecksum)
+///
+/// # Returns
+/// * `bool` - true if the address matches the pattern at the given position
+fn address_matches(address: &str, target_lower: &str, position: VanityPosition) -> bool {
+    let address_lower = address.to_lowercase();
+
+    match position {
+        VanityPosition::Anywhere => address_lower.contains(target_lower),
+        VanityPosition::Prefix => {
+            // Check if pattern appears right after the separator; the bech32 data
+            // charset excludes '1', so the last '1' always ends the HRP
+            match address_lower.rfind('1') {
+                Some(separator) if address_lower.len() > separator + 1 + target_lower.len() => {
+                    address_lower[separator + 1..].starts_with(target_lower)
+                }
+                _ => false,
+            }
+        }
+        VanityPosition::Suffix => {
+            // Check if pattern appears at the end
+            address_lower.ends_with(target_lower)
+        }
+        VanityPosition::BeforeChecksum => {
+            // Strip the checksum and require the pattern to end the data part
+            match address_lower.rfind('1') {
+                Some(separator)
+                    if address_lower.len()
+                        >= separator + 1 + target_lower.len() + BECH32_CHECKSUM_LENGTH =>
+                {
+                    address_lower[..address_lower.len() - BECH32_CHECKSUM_LENGTH]
+                        .ends_with(target_lower)
+                }
+                _ => false,
+            }
+        }
+    }
+}
+
+/// Checks whether a `0x...` hex address contains the target at the given position
+///
+/// # Arguments
+/// * `hex_address` - The EIP-55 checksummed hex address, including the `0x` prefix
+/// * `target` - The hex pattern to search for (already lowercased unless case-sensitive)
+/// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
+/// * `case_sensitive` - true to match the EIP-55 mixed-case form exactly
 ///
 /// # Returns
-/// * `bool` - true if the target is valid for bech32 addresses
-#[wasm_bindgen]
-pub fn validate_target_string(target: &str) -> bool {
-    let bech32_alphabet = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
-
-    target
-        .chars()
-        .all(|ch| ch == '1' || bech32_alphabet.contains(ch))
-}
-
-/// Position where the vanity string should appear in the address
-#[wasm_bindgen]
-#[derive(Copy, Clone, PartialEq)]
-pub enum VanityPosition {
-    /// Match anywhere in the address (default behavior)
-    Anywhere = 0,
-    /// Match immediately after "mantra1" prefix
-    Prefix = 1,
-    /// Match at the end of the address (in checksum portion)
-    Suffix = 2,
-}
+/// * `bool` - true if the address matches the pattern at the given position
+fn hex_address_matches(
+    hex_address: &str,
+    target: &str,
+    position: VanityPosition,
+    case_sensitive: bool,
+) -> bool {
+    // Skip the "0x" prefix so Prefix matches start at the first hex digit
+    let digits = if case_sensitive {
+        hex_address[2..].to_string()
+    } else {
+        hex_address[2..].to_lowercase()
+    };
 
-impl Default for VanityPosition {
-    fn default() -> Self {
-        VanityPosition::Anywhere
+    match position {
+        VanityPosition::Anywhere => digits.contains(target),
+        VanityPosition::Prefix => digits.starts_with(target),
+        // Hex addresses carry no trailing checksum, so both suffix modes coincide
+        VanityPosition::Suffix | VanityPosition::BeforeChecksum => digits.ends_with(target),
     }
 }
 
@@ -244,9 +1060,11 @@ impl Default for VanityPosition {
 /// * `target` - The substring pattern to search for in addresses
 /// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
 /// * `max_attempts` - Maximum number of generation attempts (0 = unlimited)
+/// * `hrp` - Optional bech32 prefix to search under (defaults to "mantra"; e.g. "cosmos", "osmo")
 ///
 /// # Returns
-/// * `Option<Keypair>` - The first matching keypair, or None if max_attempts reached
+/// * `Option<Keypair>` - The first matching keypair, None if max_attempts reached, or a
+///   `VanityError` if generation fails
 ///
 /// # Examples
 /// - Prefix: "mantra1test..." (pattern "test" right after prefix)
@@ -255,42 +1073,28 @@ impl Default for VanityPosition {
 ///
 /// # Note
 /// This function can be computationally expensive for rare patterns.
-/// Prefix matching is generally faster than suffix matching.
+/// Anchored positions (Prefix, Suffix, BeforeChecksum) all need about 32^n
+/// attempts; see `estimate_vanity_attempts`.
 #[wasm_bindgen]
 pub fn generate_vanity_keypair_with_position(
     target: &str,
     position: VanityPosition,
     max_attempts: u32,
-) -> Option<Keypair> {
-    let target_lower = target.to_lowercase();
+    hrp: Option<String>,
+) -> Result<Option<Keypair>, VanityError> {
+    let target_lower = check_target(target)?;
+    let hrp = hrp_or_default(hrp);
     let mut attempts = 0;
 
     loop {
         if max_attempts > 0 && attempts >= max_attempts {
-            return None;
+            return Ok(None);
         }
 
-        let keypair = generate_random_keypair();
-        let address_lower = keypair.address.to_lowercase();
-
-        let matches = match position {
-            VanityPosition::Anywhere => address_lower.contains(&target_lower),
-            VanityPosition::Prefix => {
-                // Check if pattern appears right after "mantra1"
-                if address_lower.len() > 7 + target_lower.len() {
-                    address_lower[7..].starts_with(&target_lower)
-                } else {
-                    false
-                }
-            }
-            VanityPosition::Suffix => {
-                // Check if pattern appears at the end
-                address_lower.ends_with(&target_lower)
-            }
-        };
+        let keypair = random_keypair(AccountScheme::Cosmos, &hrp)?;
 
-        if matches {
-            return Some(keypair);
+        if address_matches(&keypair.address, &target_lower, position) {
+            return paranoid_checked(keypair).map(Some);
         }
 
         attempts += 1;
@@ -305,28 +1109,38 @@ pub fn generate_vanity_keypair_with_position(
 /// # Arguments
 /// * `target` - The substring pattern to search for in addresses
 /// * `max_attempts` - Maximum number of generation attempts (0 = unlimited)
+/// * `hrp` - Optional bech32 prefix to search under (defaults to "mantra")
 ///
 /// # Returns
-/// * `Option<Keypair>` - The first matching keypair, or None if max_attempts reached
+/// * `Option<Keypair>` - The first matching keypair, None if max_attempts reached, or a
+///   `VanityError` if generation fails
 ///
 /// # Note
 /// This function can be computationally expensive for rare patterns.
 /// Consider the probability: for a 3-character pattern, expect ~32,768 attempts.
 #[wasm_bindgen]
-pub fn generate_vanity_keypair(target: &str, max_attempts: u32) -> Option<Keypair> {
-    generate_vanity_keypair_with_position(target, VanityPosition::Anywhere, max_attempts)
+pub fn generate_vanity_keypair(
+    target: &str,
+    max_attempts: u32,
+    hrp: Option<String>,
+) -> Result<Option<Keypair>, VanityError> {
+    generate_vanity_keypair_with_position(target, VanityPosition::Anywhere, max_attempts, hrp)
 }
 
 /// Derives a MANTRA address from a given mnemonic string (for testing purposes)
 ///
 /// This function allows users to test address derivation with their own mnemonic
-/// to verify that our derivation matches standard wallets.
+/// to verify that our derivation matches standard wallets. The phrase is
+/// normalized first (NFKD, lowercase, single spaces); `normalize_mnemonic()`
+/// reports what was adjusted.
 ///
 /// # Arguments
 /// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `hrp` - Optional bech32 prefix for the address (defaults to "mantra")
 ///
 /// # Returns
-/// * `String` - The derived MANTRA address, or error message if invalid
+/// * `String` - The derived MANTRA address, or a `VanityError` (code
+///   `InvalidMnemonic`) naming the offending word if the phrase is invalid
 ///
 /// # Example
 /// ```javascript
@@ -334,14 +1148,284 @@ pub fn generate_vanity_keypair(target: &str, max_attempts: u32) -> Option<Keypai
 /// console.log(`Derived address: ${address}`);
 /// ```
 #[wasm_bindgen]
-pub fn derive_address_from_mnemonic(mnemonic_str: &str) -> String {
-    match Mnemonic::parse(mnemonic_str) {
-        Ok(mnemonic) => match derive_address(&mnemonic) {
-            Ok(address) => address,
-            Err(e) => format!("Error deriving address: {}", e),
-        },
-        Err(e) => format!("Invalid mnemonic: {}", e),
+pub fn derive_address_from_mnemonic(
+    mnemonic_str: &str,
+    hrp: Option<String>,
+) -> Result<String, VanityError> {
+    let hrp = hrp_or_default(hrp);
+    check_hrp(&hrp)?;
+    let mnemonic = parse_mnemonic(mnemonic_str).map_err(|e| mnemonic_error(mnemonic_str, e))?;
+
+    derive_address(&mnemonic, &hrp)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))
+}
+
+/// Describes why a phrase failed to parse, word by word where possible
+fn mnemonic_error(mnemonic_str: &str, error: bip39::Error) -> VanityError {
+    let reason = diagnose_mnemonic(mnemonic_str)
+        .message()
+        .unwrap_or_else(|| error.to_string());
+    VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", reason))
+}
+
+/// Derives a MANTRA address from a given mnemonic string using the given account scheme
+///
+/// This is the scheme-aware form of `derive_address_from_mnemonic()`, so users can
+/// check the EVM-style (ethsecp256k1) address their mnemonic produces.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `scheme` - The account scheme (Cosmos or EthSecp256k1)
+///
+/// # Returns
+/// * `String` - The derived MANTRA address, or a `VanityError` (code
+///   `InvalidMnemonic`) naming the offending word if the phrase is invalid
+#[wasm_bindgen]
+pub fn derive_address_from_mnemonic_with_scheme(
+    mnemonic_str: &str,
+    scheme: AccountScheme,
+) -> Result<String, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str).map_err(|e| mnemonic_error(mnemonic_str, e))?;
+
+    keypair_from_mnemonic(&mnemonic, scheme, MANTRA_HRP)
+        .map(|keypair| keypair.address.clone())
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))
+}
+
+/// Imports a raw secp256k1 private key
+///
+/// This function validates the scalar (it must be non-zero and below the curve
+/// order), derives the compressed public key and the MANTRA address, and returns
+/// a keypair without a mnemonic or derivation path. Useful for verifying keys
+/// exported from other tools.
+///
+/// # Arguments
+/// * `private_key_hex` - The 32-byte private key as hex (an optional `0x` prefix is accepted)
+///
+/// # Returns
+/// * `Keypair` - The keypair holding the imported key, or an error if the key is invalid
+///
+/// # Example
+/// ```javascript
+/// const keypair = keypair_from_private_key_hex("0x1f2e...");
+/// console.log(`Address: ${keypair.address}`);
+/// ```
+#[wasm_bindgen]
+pub fn keypair_from_private_key_hex(private_key_hex: &str) -> Result<Keypair, VanityError> {
+    import_private_key(private_key_hex)
+        .map_err(|e| VanityError::InvalidKey(format!("Invalid private key: {}", e)))
+}
+
+/// Trims whitespace and an optional `0x`/`0X` prefix from a hex string
+fn strip_hex_prefix(input: &str) -> &str {
+    let trimmed = input.trim();
+    trimmed
+        .strip_prefix("0x")
+        .or_else(|| trimmed.strip_prefix("0X"))
+        .unwrap_or(trimmed)
+}
+
+/// Parses and validates a hex private key and builds the corresponding keypair
+fn import_private_key(private_key_hex: &str) -> Result<Keypair, Box<dyn std::error::Error>> {
+    let bytes = hex::decode(strip_hex_prefix(private_key_hex))
+        .map_err(|e| format!("not valid hex: {}", e))?;
+    if bytes.len() != 32 {
+        return Err(format!("expected 32 bytes, got {}", bytes.len()).into());
+    }
+
+    // Rejects zero and values at or above the curve order
+    let signing_key =
+        SigningKey::from_slice(&bytes).map_err(|_| "scalar is zero or out of range")?;
+
+    let mut public_key = [0u8; 33];
+    public_key.copy_from_slice(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes(),
+    );
+    let address = public_key_to_address(&public_key)?;
+
+    Ok(Keypair::from_raw_key(
+        address,
+        public_key,
+        hex::encode(bytes),
+    ))
+}
+
+/// Derives a MANTRA address directly from a 64-byte BIP39 seed
+///
+/// This bypasses the mnemonic step for users who only have the seed (e.g.
+/// exported from another tool) and want to verify the resulting address at
+/// the standard path m/44'/118'/0'/0/0.
+///
+/// # Arguments
+/// * `seed_hex` - The 64-byte seed as hex (an optional `0x` prefix is accepted)
+///
+/// # Returns
+/// * `String` - The derived MANTRA address, or an error if the seed is invalid
+///
+/// # Example
+/// ```javascript
+/// const address = derive_address_from_seed("5eb00bbddcf069084889a8ab9155568165f5c453...");
+/// console.log(`Derived address: ${address}`);
+/// ```
+#[wasm_bindgen]
+pub fn derive_address_from_seed(seed_hex: &str) -> Result<String, VanityError> {
+    let seed = hex::decode(strip_hex_prefix(seed_hex))
+        .map_err(|e| VanityError::InvalidKey(format!("Invalid seed: not valid hex: {}", e)))?;
+    if seed.len() != 64 {
+        return Err(VanityError::InvalidKey(format!(
+            "Invalid seed: expected 64 bytes, got {}",
+            seed.len()
+        )));
+    }
+
+    derive_key_from_seed(&seed, &COSMOS_ADDRESS_PATH)
+        .and_then(|derived| public_key_to_address(&derived.public_key()?))
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))
+}
+
+/// Exports the 64-byte BIP39 seed of a mnemonic as hex
+///
+/// This lets users cross-check the seed against other BIP39 tooling (such as
+/// Ian Coleman's BIP39 page, run offline). The seed is as sensitive as the
+/// mnemonic itself and should never be logged or transmitted.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `passphrase` - Optional BIP39 passphrase (the "25th word"); empty when omitted.
+///   It is NFKD-normalized only; case and whitespace are significant (see `normalize_passphrase()`)
+///
+/// # Returns
+/// * `String` - The hex-encoded 64-byte seed, or an error if the mnemonic is
+///   invalid or hardened mode is on
+///
+/// # Example
+/// ```javascript
+/// const seed = mnemonic_to_seed_hex("word1 word2 ... word24", undefined);
+/// console.log(`Seed: ${seed}`);
+/// ```
+#[wasm_bindgen]
+pub fn mnemonic_to_seed_hex(
+    mnemonic_str: &str,
+    passphrase: Option<String>,
+) -> Result<String, VanityError> {
+    check_plaintext_secrets("the BIP39 seed")?;
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    let seed = Zeroizing::new(mnemonic.to_seed(passphrase.as_deref().unwrap_or("")));
+    audit_export("bip39_seed", None)?;
+
+    Ok(hex::encode(seed.as_slice()))
+}
+
+/// Extended private keys exported from a mnemonic
+///
+/// **SENSITIVE OUTPUT**: every field except `account_path` grants full control over
+/// all funds in the wallet. Only create this when migrating into a wallet that
+/// imports extended keys, and never log or transmit the values.
+#[wasm_bindgen]
+pub struct ExtendedKeyExport {
+    root_xprv: String,
+    account_xprv: String,
+    account_path: String,
+}
+
+#[wasm_bindgen]
+impl ExtendedKeyExport {
+    /// Gets the BIP32 root (master) extended private key
+    #[wasm_bindgen(getter)]
+    pub fn root_xprv(&self) -> String {
+        self.root_xprv.clone()
+    }
+
+    /// Gets the account-level extended private key
+    #[wasm_bindgen(getter)]
+    pub fn account_xprv(&self) -> String {
+        self.account_xprv.clone()
+    }
+
+    /// Gets the derivation path of the account-level key
+    #[wasm_bindgen(getter)]
+    pub fn account_path(&self) -> String {
+        self.account_path.clone()
+    }
+}
+
+/// Wipes both xprvs when the export is freed from JavaScript
+impl Drop for ExtendedKeyExport {
+    fn drop(&mut self) {
+        self.root_xprv.zeroize();
+        self.account_xprv.zeroize();
+    }
+}
+
+/// Shows the account path only; both xprvs print as `[REDACTED]`
+impl fmt::Debug for ExtendedKeyExport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedKeyExport")
+            .field("root_xprv", &Redacted)
+            .field("account_xprv", &Redacted)
+            .field("account_path", &self.account_path)
+            .finish()
+    }
+}
+
+/// Exports the BIP32 root key and account-level xprv for a mnemonic
+///
+/// This is an opt-in export for users migrating into wallets that import
+/// extended keys rather than mnemonics. Because the output is as sensitive as
+/// the mnemonic itself, the caller must explicitly acknowledge this by passing
+/// `acknowledge_sensitive_output = true`; otherwise an error is returned.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `acknowledge_sensitive_output` - Must be true to confirm secret material may be returned
+///
+/// # Returns
+/// * `ExtendedKeyExport` - Root xprv and account xprv (m/44'/118'/0'), or an
+///   error if not acknowledged or hardened mode is on
+///
+/// # Example
+/// ```javascript
+/// const keys = export_extended_private_keys("word1 word2 ... word24", true);
+/// console.log(`Account xprv: ${keys.account_xprv}`);
+/// ```
+#[wasm_bindgen]
+pub fn export_extended_private_keys(
+    mnemonic_str: &str,
+    acknowledge_sensitive_output: bool,
+) -> Result<ExtendedKeyExport, VanityError> {
+    if !acknowledge_sensitive_output {
+        return Err(VanityError::NotAcknowledged(
+            "Extended private key export requires acknowledge_sensitive_output = true".to_string(),
+        ));
     }
+    check_plaintext_secrets("extended private key export")?;
+
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+
+    let export = extended_key_export(&mnemonic)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error exporting keys: {}", e)))?;
+    audit_export("extended_private_keys", None)?;
+    Ok(export)
+}
+
+/// Builds the extended key export for a parsed mnemonic
+fn extended_key_export(
+    mnemonic: &Mnemonic,
+) -> Result<ExtendedKeyExport, Box<dyn std::error::Error>> {
+    let seed = Zeroizing::new(mnemonic.to_seed(""));
+    let master = ExtendedPrivateKey::master(&*seed)?;
+    let account = master.derive_path(&COSMOS_ACCOUNT_PATH)?;
+
+    Ok(ExtendedKeyExport {
+        root_xprv: master.to_xprv()?,
+        account_xprv: account.to_xprv()?,
+        account_path: format_derivation_path(&COSMOS_ACCOUNT_PATH),
+    })
 }
 
 /// Generate multiple random keypairs in a single WASM call for better performance
@@ -352,18 +1436,23 @@ pub fn derive_address_from_mnemonic(mnemonic_str: &str) -> String {
 ///
 /// # Arguments
 /// * `count` - Number of keypairs to generate
+/// * `hrp` - Optional bech32 prefix for the addresses (defaults to "mantra")
 ///
 /// # Returns
-/// * `Vec<Keypair>` - Vector of generated keypairs
+/// * `Vec<Keypair>` - Vector of generated keypairs, or a `VanityError` if generation fails
 #[wasm_bindgen]
-pub fn generate_random_keypairs_batch(count: u32) -> Vec<Keypair> {
+pub fn generate_random_keypairs_batch(
+    count: u32,
+    hrp: Option<String>,
+) -> Result<Vec<Keypair>, VanityError> {
+    let hrp = hrp_or_default(hrp);
     let mut keypairs = Vec::with_capacity(count as usize);
-    
+
     for _ in 0..count {
-        keypairs.push(generate_random_keypair());
+        keypairs.push(random_keypair(AccountScheme::Cosmos, &hrp)?);
     }
-    
-    keypairs
+
+    Ok(keypairs)
 }
 
 /// Generate vanity keypairs in batches for better performance
@@ -376,43 +1465,173 @@ pub fn generate_random_keypairs_batch(count: u32) -> Vec<Keypair> {
 /// * `target` - The substring pattern to search for in addresses
 /// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
 /// * `batch_size` - Number of keypairs to generate and check in this batch
+/// * `hrp` - Optional bech32 prefix to search under (defaults to "mantra"; e.g. "cosmos", "osmo")
 ///
 /// # Returns
-/// * `Option<Keypair>` - The first matching keypair, or None if no match found
+/// * `Option<Keypair>` - The first matching keypair, None if no match found, or a
+///   `VanityError` if generation fails
 #[wasm_bindgen]
 pub fn generate_vanity_keypair_batch(
     target: &str,
     position: VanityPosition,
     batch_size: u32,
-) -> Option<Keypair> {
-    let target_lower = target.to_lowercase();
-    
+    hrp: Option<String>,
+) -> Result<Option<Keypair>, VanityError> {
+    generate_vanity_keypair_batch_with_scheme(
+        target,
+        position,
+        batch_size,
+        AccountScheme::Cosmos,
+        hrp,
+    )
+}
+
+/// Generate vanity keypairs in batches using the given account scheme
+///
+/// This is the scheme-aware form of `generate_vanity_keypair_batch()`, so the
+/// same search can be run over EVM-style (ethsecp256k1) MANTRA addresses.
+///
+/// # Arguments
+/// * `target` - The substring pattern to search for in addresses
+/// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
+/// * `batch_size` - Number of keypairs to generate and check in this batch
+/// * `scheme` - The account scheme (Cosmos or EthSecp256k1)
+/// * `hrp` - Optional bech32 prefix to search under (defaults to "mantra")
+///
+/// # Returns
+/// * `Option<Keypair>` - The first matching keypair, None if no match found, or a
+///   `VanityError` if generation fails
+#[wasm_bindgen]
+pub fn generate_vanity_keypair_batch_with_scheme(
+    target: &str,
+    position: VanityPosition,
+    batch_size: u32,
+    scheme: AccountScheme,
+    hrp: Option<String>,
+) -> Result<Option<Keypair>, VanityError> {
+    let target_lower = check_target(target)?;
+    let hrp = hrp_or_default(hrp);
+
     for _ in 0..batch_size {
-        let keypair = generate_random_keypair();
-        let address_lower = keypair.address.to_lowercase();
-
-        let matches = match position {
-            VanityPosition::Anywhere => address_lower.contains(&target_lower),
-            VanityPosition::Prefix => {
-                // Check if pattern appears right after "mantra1"
-                if address_lower.len() > 7 + target_lower.len() {
-                    address_lower[7..].starts_with(&target_lower)
-                } else {
-                    false
-                }
-            }
-            VanityPosition::Suffix => {
-                // Check if pattern appears at the end
-                address_lower.ends_with(&target_lower)
-            }
+        let keypair = random_keypair(scheme, &hrp)?;
+
+        if address_matches(&keypair.address, &target_lower, position) {
+            return paranoid_checked(keypair).map(Some);
+        }
+    }
+
+    Ok(None)
+}
+
+/// Generate vanity keypairs matching under any of several bech32 prefixes
+///
+/// One secp256k1 key has an address on every Cosmos chain, so each candidate
+/// is encoded under all of `hrps` and checked against the pattern, giving a
+/// multi-chain search for the cost of a single derivation. The returned
+/// keypair's `address` is the encoding that matched; the same mnemonic yields
+/// the other chains' addresses too.
+///
+/// # Arguments
+/// * `target` - The substring pattern to search for in addresses
+/// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
+/// * `batch_size` - Number of keypairs to generate and check in this batch
+/// * `hrps` - The bech32 prefixes to try (e.g. `["mantra", "cosmos", "osmo"]`); empty means "mantra"
+///
+/// # Returns
+/// * `Option<Keypair>` - The first matching keypair, None if no match found, or a
+///   `VanityError` if generation fails
+///
+/// # Note
+/// The address data (and so any Prefix match) is identical under every HRP;
+/// only the 6-character bech32 checksum differs, so extra prefixes mostly
+/// improve the odds of Suffix and Anywhere matches near the end.
+#[wasm_bindgen]
+pub fn generate_multi_hrp_vanity_keypair_batch(
+    target: &str,
+    position: VanityPosition,
+    batch_size: u32,
+    hrps: Vec<String>,
+) -> Result<Option<Keypair>, VanityError> {
+    let target_lower = check_target(target)?;
+    let hrps: Vec<String> = if hrps.is_empty() {
+        vec![MANTRA_HRP.to_string()]
+    } else {
+        hrps.into_iter()
+            .map(|hrp| hrp_or_default(Some(hrp)))
+            .collect()
+    };
+
+    for hrp in &hrps {
+        check_hrp(hrp)?;
+    }
+
+    for _ in 0..batch_size {
+        let mut keypair = random_keypair(AccountScheme::Cosmos, &hrps[0])?;
+        let Some(public_key) = keypair.public_key else {
+            return Ok(None);
         };
 
+        for hrp in &hrps {
+            let address = address::public_key_to_address(&public_key, hrp).map_err(|e| {
+                VanityError::DerivationFailed(format!("Error encoding address: {}", e))
+            })?;
+
+            if address_matches(&address, &target_lower, position) {
+                keypair.address = address;
+                return paranoid_checked(keypair).map(Some);
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Generate EVM-style vanity keypairs matching the `0x...` hex address
+///
+/// Keys are derived with the ethsecp256k1 scheme (m/44'/60'/0'/0/0) and the
+/// pattern is matched against the hex address shown by MetaMask rather than
+/// the bech32 form. Returns the first match in the batch, or None.
+///
+/// # Arguments
+/// * `target` - The hex pattern to search for (0-9, a-f)
+/// * `position` - Where the pattern should appear (Prefix means right after "0x")
+/// * `batch_size` - Number of keypairs to generate and check in this batch
+/// * `case_sensitive` - true to require the exact EIP-55 letter case of `target`
+///
+/// # Returns
+/// * `Option<Keypair>` - The first matching keypair, None if no match found, or a
+///   `VanityError` if generation fails
+///
+/// # Note
+/// Case-sensitive matching roughly halves the odds per letter in the pattern,
+/// since each letter's case is fixed by the EIP-55 checksum.
+#[wasm_bindgen]
+pub fn generate_evm_vanity_keypair_batch(
+    target: &str,
+    position: VanityPosition,
+    batch_size: u32,
+    case_sensitive: bool,
+) -> Result<Option<Keypair>, VanityError> {
+    check_hex_target(target)?;
+    let target = if case_sensitive {
+        target.to_string()
+    } else {
+        target.to_lowercase()
+    };
+
+    for _ in 0..batch_size {
+        let keypair = random_keypair(AccountScheme::EthSecp256k1, MANTRA_HRP)?;
+
+        let matches = keypair.evm_address().is_some_and(|hex_address| {
+            hex_address_matches(&hex_address, &target, position, case_sensitive)
+        });
+
         if matches {
-            return Some(keypair);
+            return paranoid_checked(keypair).map(Some);
         }
     }
-    
-    None
+
+    Ok(None)
 }
 
 /// Get optimal batch size for performance
@@ -436,3 +1655,132 @@ pub fn get_optimal_batch_size(target_length: u32) -> u32 {
         _ => 10000,
     }
 }
+
+/// Estimates the expected number of keypairs to generate before a match
+///
+/// Every character of a 20-byte account address is uniformly distributed over
+/// the 32-symbol bech32 alphabet, including the checksum characters, so each
+/// anchored position (Prefix, Suffix, BeforeChecksum) needs 32^n attempts on
+/// average. Suffix patterns longer than the checksum spill into the data part,
+/// which does not change the odds. Anywhere matches get one chance per
+/// placement within the data part and checksum.
+///
+/// # Arguments
+/// * `target_length` - Length of the target pattern
+/// * `position` - Where the pattern should appear
+///
+/// # Returns
+/// * `f64` - Expected number of attempts, or infinity if the pattern cannot fit
+#[wasm_bindgen]
+pub fn estimate_vanity_attempts(target_length: u32, position: VanityPosition) -> f64 {
+    let length = target_length as usize;
+    let total = ACCOUNT_DATA_LENGTH + BECH32_CHECKSUM_LENGTH;
+    let room = match position {
+        VanityPosition::Anywhere | VanityPosition::Prefix | VanityPosition::Suffix => total,
+        VanityPosition::BeforeChecksum => ACCOUNT_DATA_LENGTH,
+    };
+    if length == 0 {
+        return 1.0;
+    }
+    if length > room {
+        return f64::INFINITY;
+    }
+
+    let odds = 32f64.powi(target_length as i32);
+    match position {
+        VanityPosition::Anywhere => odds / (total - length + 1) as f64,
+        _ => odds,
+    }
+}
+
+/// A watch-only address found by scanning an extended public key
+///
+/// Contains no private material: only the address and where it sits below
+/// the scanned xpub, so the owning hardware wallet can be pointed at it.
+#[wasm_bindgen]
+pub struct WatchOnlyMatch {
+    address: String,
+    index: u32,
+}
+
+#[wasm_bindgen]
+impl WatchOnlyMatch {
+    /// Gets the matching bech32-encoded MANTRA address
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// Gets the address index on the receive chain (`<xpub>/0/index`)
+    #[wasm_bindgen(getter)]
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Gets the path of the address relative to the scanned xpub
+    #[wasm_bindgen(getter)]
+    pub fn relative_path(&self) -> String {
+        format!("0/{}", self.index)
+    }
+}
+
+/// Watch-only vanity scan over an account xpub
+///
+/// Scans the non-hardened receive chain below an account-level extended
+/// public key (`<xpub>/0/index`) for an address matching the target pattern.
+/// No private material is involved, so this lets users hunt a vanity receive
+/// address inside an existing hardware-wallet account instead of creating a new
+/// seed.
+///
+/// # Arguments
+/// * `xpub` - The account-level extended public key (e.g. for m/44'/118'/0')
+/// * `target` - The substring pattern to search for in addresses
+/// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
+/// * `start_index` - First address index to check
+/// * `count` - Number of consecutive indexes to check
+///
+/// # Returns
+/// * `Option<WatchOnlyMatch>` - The first matching address, or None if none matched
+///
+/// # Note
+/// Indexes at or above 2^31 are hardened and cannot be derived from an xpub,
+/// so the scan stops at the end of the non-hardened range.
+#[wasm_bindgen]
+pub fn scan_xpub_for_vanity(
+    xpub: &str,
+    target: &str,
+    position: VanityPosition,
+    start_index: u32,
+    count: u32,
+) -> Result<Option<WatchOnlyMatch>, VanityError> {
+    let target_lower = check_target(target)?;
+    scan_xpub(xpub, &target_lower, position, start_index, count)
+        .map_err(|e| VanityError::InvalidKey(format!("Error scanning xpub: {}", e)))
+}
+
+/// Scans the receive chain below an xpub for a matching (lowercased) address
+fn scan_xpub(
+    xpub: &str,
+    target_lower: &str,
+    position: VanityPosition,
+    start_index: u32,
+    count: u32,
+) -> Result<Option<WatchOnlyMatch>, Box<dyn std::error::Error>> {
+    let receive_chain = ExtendedPublicKey::from_xpub(xpub)?.derive_child(0)?;
+    let end_index = start_index.saturating_add(count).min(HARDENED_OFFSET);
+
+    for index in start_index..end_index {
+        let child = receive_chain.derive_child(index)?;
+        if child.child_number() != index {
+            // BIP32 skips invalid children; the next iteration covers the index used
+            continue;
+        }
+        let address = public_key_to_address(&child.public_key_bytes())?;
+
+        if address_matches(&address, target_lower, position) {
+            return Ok(Some(WatchOnlyMatch { address, index }));
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,113 @@
+//! MetaMask account export
+//!
+//! MANTRA's EVM side is used through MetaMask, which imports accounts from a
+//! raw private key rather than a Cosmos mnemonic and path. For keypairs
+//! derived with the ethsecp256k1 scheme, the key MetaMask needs is the same
+//! one behind the bech32 address, so the account shows up under the matching
+//! 0x address.
+
+use crate::audit::audit_export;
+use crate::error::VanityError;
+use crate::redact::Redacted;
+use crate::schema::SCHEMA_VERSION;
+use crate::{AccountScheme, Keypair};
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+/// A private key and address ready for MetaMask's "Import account" screen
+#[wasm_bindgen]
+pub struct MetaMaskExport {
+    address: String,
+    private_key: String,
+}
+
+#[wasm_bindgen]
+impl MetaMaskExport {
+    /// Gets the EIP-55 checksummed `0x...` address MetaMask will show
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// Gets the private key as `0x`-prefixed hex, as pasted into MetaMask
+    #[wasm_bindgen(getter)]
+    pub fn private_key(&self) -> String {
+        self.private_key.clone()
+    }
+
+    /// Gets the export as `{"schema_version": ..., "address": ..., "privateKey": ...}` JSON
+    pub fn to_json(&self) -> String {
+        serde_json::json!({
+            "schema_version": SCHEMA_VERSION,
+            "address": self.address,
+            "privateKey": self.private_key,
+        })
+        .to_string()
+    }
+}
+
+/// Wipes the private key when the export is freed from JavaScript
+impl Drop for MetaMaskExport {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+/// Shows the address only; the private key prints as `[REDACTED]`
+impl fmt::Debug for MetaMaskExport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MetaMaskExport")
+            .field("address", &self.address)
+            .field("private_key", &Redacted)
+            .finish()
+    }
+}
+
+/// Exports an ethsecp256k1 keypair's private key for import into MetaMask
+///
+/// Only keypairs generated with `AccountScheme.EthSecp256k1` are accepted:
+/// a Cosmos-scheme key would import fine but open an unrelated 0x account.
+/// The raw key grants full control of the account on every chain it is used
+/// on, so the export is refused unless `acknowledge_risk` is true, which a
+/// frontend should only pass after the user explicitly confirmed.
+///
+/// # Arguments
+/// * `keypair` - An ethsecp256k1 keypair with a mnemonic or raw private key
+/// * `acknowledge_risk` - Must be true to confirm the user accepted the risk
+///
+/// # Returns
+/// * `MetaMaskExport` - The checksummed address and `0x` private key, or an
+///   error if the risk was not acknowledged, hardened mode is on or the
+///   keypair cannot be exported
+///
+/// # Example
+/// ```javascript
+/// const keypair = generate_random_keypair_with_scheme(AccountScheme.EthSecp256k1, undefined);
+/// const exported = export_metamask_private_key(keypair, confirmCheckbox.checked);
+/// console.log(`Import ${exported.private_key} to get ${exported.address}`);
+/// ```
+#[wasm_bindgen]
+pub fn export_metamask_private_key(
+    keypair: &Keypair,
+    acknowledge_risk: bool,
+) -> Result<MetaMaskExport, VanityError> {
+    if !acknowledge_risk {
+        return Err(VanityError::NotAcknowledged(
+            "Refusing to export a raw private key without acknowledge_risk = true".to_string(),
+        ));
+    }
+    if keypair.scheme() != AccountScheme::EthSecp256k1 {
+        return Err(VanityError::InvalidInput("Only ethsecp256k1 keypairs can be exported to MetaMask; Cosmos-scheme keys map to a different 0x account".to_string()));
+    }
+    let address = keypair.evm_address().ok_or_else(|| {
+        VanityError::InvalidInput("Keypair has no public key to compute its 0x address".to_string())
+    })?;
+
+    let private_key = Zeroizing::new(keypair.raw_private_key_hex()?);
+    audit_export("metamask_private_key", Some(&keypair.address))?;
+    Ok(MetaMaskExport {
+        address,
+        private_key: format!("0x{}", *private_key),
+    })
+}
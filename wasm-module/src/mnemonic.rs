@@ -0,0 +1,495 @@
+//! BIP39 mnemonic internals
+//!
+//! A mnemonic is entropy plus a SHA256-derived checksum, written out 11 bits
+//! per word. This module exposes that structure for educational frontends
+//! and recovery tooling, converts between phrases and raw entropy, and
+//! re-renders the same entropy in another wordlist language. Every phrase
+//! accepted by the module passes through the input normalization here.
+
+use crate::address::MANTRA_HRP;
+use crate::audit::audit_export;
+use crate::derive_address;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::wordlist::{language_or_default, parse_language, word_indexes};
+use bip39::{Language, Mnemonic};
+use sha2::{Digest, Sha256};
+use unicode_normalization::UnicodeNormalization;
+use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Number of bits encoded by each mnemonic word
+const BITS_PER_WORD: usize = 11;
+
+/// Bit-level breakdown of a mnemonic's checksum
+#[wasm_bindgen]
+pub struct ChecksumAnalysis {
+    word_count: u32,
+    entropy_bits: u32,
+    checksum_bits: u32,
+    bits: String,
+    stored_checksum: String,
+    expected_checksum: String,
+}
+
+#[wasm_bindgen]
+impl ChecksumAnalysis {
+    /// Gets the number of words in the phrase
+    #[wasm_bindgen(getter)]
+    pub fn word_count(&self) -> u32 {
+        self.word_count
+    }
+
+    /// Gets the number of entropy bits (128 to 256)
+    #[wasm_bindgen(getter)]
+    pub fn entropy_bits(&self) -> u32 {
+        self.entropy_bits
+    }
+
+    /// Gets the number of checksum bits (entropy bits / 32)
+    #[wasm_bindgen(getter)]
+    pub fn checksum_bits(&self) -> u32 {
+        self.checksum_bits
+    }
+
+    /// Gets the zero-based position of the only word that carries checksum bits (the last word)
+    #[wasm_bindgen(getter)]
+    pub fn checksum_word_position(&self) -> u32 {
+        self.word_count - 1
+    }
+
+    /// Gets how many leading bits of the last word are entropy rather than checksum
+    #[wasm_bindgen(getter)]
+    pub fn entropy_bits_in_last_word(&self) -> u32 {
+        BITS_PER_WORD as u32 - self.checksum_bits
+    }
+
+    /// Gets every bit of the phrase as a string of 0s and 1s, 11 per word
+    #[wasm_bindgen(getter)]
+    pub fn bits(&self) -> String {
+        self.bits.clone()
+    }
+
+    /// Gets the checksum bits as written in the phrase
+    #[wasm_bindgen(getter)]
+    pub fn stored_checksum(&self) -> String {
+        self.stored_checksum.clone()
+    }
+
+    /// Gets the checksum bits recomputed from the entropy
+    #[wasm_bindgen(getter)]
+    pub fn expected_checksum(&self) -> String {
+        self.expected_checksum.clone()
+    }
+
+    /// Gets whether the stored checksum matches the recomputed one
+    #[wasm_bindgen(getter)]
+    pub fn consistent(&self) -> bool {
+        // Both checksums are exposed through getters, so a constant-time compare buys nothing
+        self.stored_checksum == self.expected_checksum
+    }
+}
+
+/// Breaks a mnemonic down into its entropy and checksum bits
+///
+/// Every word encodes 11 bits. The first `entropy_bits` of the phrase are the
+/// entropy and the remaining `checksum_bits` — all in the last word — must
+/// equal the leading bits of SHA256(entropy). Unlike parsing, an inconsistent
+/// checksum is reported rather than rejected.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase (12, 15, 18, 21 or 24 words)
+/// * `language` - Optional wordlist name (defaults to "english")
+///
+/// # Returns
+/// * `ChecksumAnalysis` - The bit-level breakdown, or an error for unknown words or a bad word count
+///
+/// # Example
+/// ```javascript
+/// const analysis = analyze_mnemonic_checksum("word1 ... word24", undefined);
+/// console.log(`stored ${analysis.stored_checksum}, expected ${analysis.expected_checksum}`);
+/// ```
+#[wasm_bindgen]
+pub fn analyze_mnemonic_checksum(
+    mnemonic_str: &str,
+    language: Option<String>,
+) -> Result<ChecksumAnalysis, VanityError> {
+    let language = language_or_default(language).map_err(VanityError::InvalidInput)?;
+    let phrase = normalize_mnemonic(mnemonic_str).normalized;
+    let words: Vec<String> = phrase.split_whitespace().map(str::to_string).collect();
+    if !matches!(words.len(), 12 | 15 | 18 | 21 | 24) {
+        return Err(VanityError::InvalidInput(format!(
+            "Invalid word count: {} (expected 12, 15, 18, 21 or 24)",
+            words.len()
+        )));
+    }
+    let indexes = word_indexes(&words, language).map_err(VanityError::InvalidMnemonic)?;
+
+    let bits = indexes_to_bits(&indexes);
+    let checksum_bits = bits.len() / 33;
+    let entropy_bits = bits.len() - checksum_bits;
+    let entropy = bits_to_bytes(&bits[..entropy_bits]);
+    let hash = Sha256::digest(&entropy);
+    // At most 8 checksum bits (for 24 words), so the first hash byte covers them
+    let expected = format!("{:08b}", hash[0]);
+
+    Ok(ChecksumAnalysis {
+        word_count: words.len() as u32,
+        entropy_bits: entropy_bits as u32,
+        checksum_bits: checksum_bits as u32,
+        stored_checksum: bits[entropy_bits..].to_string(),
+        expected_checksum: expected[..checksum_bits].to_string(),
+        bits,
+    })
+}
+
+/// Writes wordlist indexes out as a bit string, 11 bits per word
+fn indexes_to_bits(indexes: &[u16]) -> String {
+    indexes
+        .iter()
+        .map(|index| format!("{:011b}", index))
+        .collect()
+}
+
+/// Packs a bit string (a multiple of 8 bits long) into bytes
+fn bits_to_bytes(bits: &str) -> Vec<u8> {
+    bits.as_bytes()
+        .chunks(8)
+        .map(|byte| {
+            byte.iter()
+                .fold(0u8, |acc, &bit| (acc << 1) | u8::from(bit == b'1'))
+        })
+        .collect()
+}
+
+/// The raw entropy behind a mnemonic
+#[wasm_bindgen]
+pub struct MnemonicEntropy {
+    entropy: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl MnemonicEntropy {
+    /// Gets the entropy as lowercase hex (32 to 64 characters)
+    #[wasm_bindgen(getter)]
+    pub fn hex(&self) -> String {
+        hex::encode(&self.entropy)
+    }
+
+    /// Gets the entropy as a string of 0s and 1s (128 to 256 characters)
+    #[wasm_bindgen(getter)]
+    pub fn bits(&self) -> String {
+        self.entropy
+            .iter()
+            .map(|byte| format!("{:08b}", byte))
+            .collect()
+    }
+
+    /// Gets the entropy bytes
+    #[wasm_bindgen(getter)]
+    pub fn bytes(&self) -> Vec<u8> {
+        self.entropy.clone()
+    }
+}
+
+/// Wipes the entropy when the result is freed from JavaScript
+impl Drop for MnemonicEntropy {
+    fn drop(&mut self) {
+        self.entropy.zeroize();
+    }
+}
+
+/// Extracts the entropy encoded by a mnemonic
+///
+/// The phrase must have a valid checksum. The result matches the "entropy"
+/// field of other BIP39 tools (such as the Ian Coleman converter), so a
+/// backup can be cross-checked without trusting this module alone. The
+/// entropy is as secret as the phrase, so this is refused in hardened mode and
+/// reported to the hook registered with `set_export_audit_hook()`.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase (12, 15, 18, 21 or 24 words)
+/// * `language` - Optional wordlist name (defaults to "english")
+///
+/// # Returns
+/// * `MnemonicEntropy` - The entropy in hex, bit and byte form, or an error for
+///   an invalid phrase, hardened mode or a failed audit hook
+///
+/// # Example
+/// ```javascript
+/// const entropy = mnemonic_to_entropy("word1 ... word24", undefined);
+/// console.log(`Entropy: ${entropy.hex}`);
+/// ```
+#[wasm_bindgen]
+pub fn mnemonic_to_entropy(
+    mnemonic_str: &str,
+    language: Option<String>,
+) -> Result<MnemonicEntropy, VanityError> {
+    check_plaintext_secrets("mnemonic entropy")?;
+    let language = language_or_default(language).map_err(VanityError::InvalidInput)?;
+    let phrase = normalize_mnemonic(mnemonic_str).normalized;
+    let mnemonic = Mnemonic::parse_in_normalized(language, &phrase)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    audit_export("mnemonic_entropy", None)?;
+
+    Ok(MnemonicEntropy {
+        entropy: mnemonic.to_entropy(),
+    })
+}
+
+/// Builds the mnemonic for given entropy
+///
+/// Accepts either hex (32, 40, 48, 56 or 64 characters) or a bit string of
+/// 0s and 1s (128, 160, 192, 224 or 256 characters), so entropy drawn by hand
+/// from dice or coin flips can be entered directly. Spaces are ignored.
+///
+/// # Arguments
+/// * `entropy` - The entropy as hex or as a bit string
+/// * `language` - Optional wordlist name (defaults to "english")
+///
+/// # Returns
+/// * `String` - The mnemonic phrase, or an error for malformed entropy
+///
+/// # Example
+/// ```javascript
+/// const phrase = entropy_to_mnemonic("00000000000000000000000000000000", undefined);
+/// // "abandon abandon ... about"
+/// ```
+#[wasm_bindgen]
+pub fn entropy_to_mnemonic(entropy: &str, language: Option<String>) -> Result<String, VanityError> {
+    let language = language_or_default(language).map_err(VanityError::InvalidInput)?;
+    let compact: String = entropy.split_whitespace().collect();
+
+    let bytes = if compact.len() >= 128 && compact.bytes().all(|ch| ch == b'0' || ch == b'1') {
+        if !compact.len().is_multiple_of(8) {
+            return Err(VanityError::InvalidInput(
+                "Bit string length must be a multiple of 8".to_string(),
+            ));
+        }
+        bits_to_bytes(&compact)
+    } else {
+        hex::decode(compact.trim_start_matches("0x"))
+            .map_err(|e| VanityError::InvalidInput(format!("Invalid entropy hex: {}", e)))?
+    };
+
+    Mnemonic::from_entropy_in(language, &bytes)
+        .map(|mnemonic| mnemonic.to_string())
+        .map_err(|e| {
+            VanityError::InvalidInput(format!(
+                "Invalid entropy: {} (expected 16, 20, 24, 28 or 32 bytes)",
+                e
+            ))
+        })
+}
+
+/// Warning attached to every translated mnemonic
+const TRANSLATION_WARNING: &str = "The translated phrase encodes the same entropy but derives a \
+different wallet: BIP39 seeds are computed from the words themselves, not the entropy. \
+Most Cosmos wallets, including Keplr and Leap, only import English phrases. Keep the \
+original phrase as the backup of record.";
+
+/// A mnemonic re-rendered in another wordlist language
+#[wasm_bindgen]
+pub struct MnemonicTranslation {
+    mnemonic: String,
+    original_address: String,
+    translated_address: String,
+}
+
+#[wasm_bindgen]
+impl MnemonicTranslation {
+    /// Gets the phrase in the target language
+    #[wasm_bindgen(getter)]
+    pub fn mnemonic(&self) -> String {
+        self.mnemonic.clone()
+    }
+
+    /// Gets the MANTRA address (m/44'/118'/0'/0/0) of the original phrase
+    #[wasm_bindgen(getter)]
+    pub fn original_address(&self) -> String {
+        self.original_address.clone()
+    }
+
+    /// Gets the MANTRA address (m/44'/118'/0'/0/0) of the translated phrase
+    #[wasm_bindgen(getter)]
+    pub fn translated_address(&self) -> String {
+        self.translated_address.clone()
+    }
+
+    /// Gets the wallet compatibility warning to show alongside the translation
+    #[wasm_bindgen(getter)]
+    pub fn warning(&self) -> String {
+        TRANSLATION_WARNING.to_string()
+    }
+}
+
+/// Re-renders a mnemonic's entropy in another language's wordlist
+///
+/// The word at each position maps to the word with the same index in the
+/// target list, so the entropy and checksum are unchanged. The wallet is
+/// not: the seed is PBKDF2 over the phrase text, so the two phrases derive
+/// different keys. Both addresses are returned so the difference is visible,
+/// together with a warning to show the user.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase to translate
+/// * `from_language` - Optional source wordlist name (detected from the words if omitted)
+/// * `to_language` - Target wordlist name (e.g. "spanish")
+///
+/// # Returns
+/// * `MnemonicTranslation` - The translated phrase, both addresses and the warning, or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// const translation = translate_mnemonic("word1 ... word24", undefined, "spanish");
+/// console.warn(translation.warning);
+/// console.log(translation.mnemonic);
+/// ```
+#[wasm_bindgen]
+pub fn translate_mnemonic(
+    mnemonic_str: &str,
+    from_language: Option<String>,
+    to_language: &str,
+) -> Result<MnemonicTranslation, VanityError> {
+    let to_language = parse_language(to_language).map_err(VanityError::InvalidInput)?;
+    let phrase = normalize_mnemonic(mnemonic_str).normalized;
+    let original = match from_language {
+        Some(name) => {
+            let from_language = parse_language(&name).map_err(VanityError::InvalidInput)?;
+            Mnemonic::parse_in_normalized(from_language, &phrase)
+        }
+        None => Mnemonic::parse_normalized(&phrase),
+    }
+    .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+
+    let translated = Mnemonic::from_entropy_in(to_language, &original.to_entropy())
+        .map_err(|e| VanityError::DerivationFailed(format!("Error translating mnemonic: {}", e)))?;
+    let address_of = |mnemonic: &Mnemonic| {
+        derive_address(mnemonic, MANTRA_HRP)
+            .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))
+    };
+
+    Ok(MnemonicTranslation {
+        mnemonic: translated.to_string(),
+        original_address: address_of(&original)?,
+        translated_address: address_of(&translated)?,
+    })
+}
+
+/// How a mnemonic or passphrase was normalized before use
+#[wasm_bindgen]
+pub struct InputNormalization {
+    normalized: Zeroizing<String>,
+    changed: bool,
+    notes: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl InputNormalization {
+    /// Gets the input exactly as it is used for derivation
+    #[wasm_bindgen(getter)]
+    pub fn normalized(&self) -> String {
+        self.normalized.to_string()
+    }
+
+    /// Gets whether normalization changed the input
+    #[wasm_bindgen(getter)]
+    pub fn changed(&self) -> bool {
+        self.changed
+    }
+
+    /// Gets one human-readable note per adjustment made (or deliberately not made)
+    #[wasm_bindgen(getter)]
+    pub fn notes(&self) -> Vec<String> {
+        self.notes.clone()
+    }
+}
+
+/// Reports how a mnemonic phrase is normalized before it is parsed
+///
+/// BIP39 specifies NFKD normalization of the phrase. On top of that, words
+/// are lowercased (the wordlists are lowercase, and phones often capitalise
+/// the first word) and runs of whitespace, including tabs, newlines and
+/// non-breaking spaces, are collapsed to single spaces. Every API that takes
+/// a mnemonic applies exactly this normalization.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as typed or pasted
+///
+/// # Returns
+/// * `InputNormalization` - The normalized phrase and a note per adjustment
+///
+/// # Example
+/// ```javascript
+/// const report = normalize_mnemonic(input);
+/// if (report.changed) report.notes.forEach((note) => console.info(note));
+/// ```
+#[wasm_bindgen]
+pub fn normalize_mnemonic(mnemonic_str: &str) -> InputNormalization {
+    let mut notes = Vec::new();
+
+    // Every intermediate holds the phrase, so each one is wiped when dropped
+    let decomposed = Zeroizing::new(mnemonic_str.nfkd().collect::<String>());
+    if *decomposed != mnemonic_str {
+        notes.push("Applied Unicode NFKD normalization (e.g. to accented characters)".to_string());
+    }
+    let lowered = Zeroizing::new(decomposed.to_lowercase());
+    if lowered != decomposed {
+        notes.push("Converted uppercase letters to lowercase".to_string());
+    }
+    let collapsed = Zeroizing::new(lowered.split_whitespace().collect::<Vec<_>>().join(" "));
+    if collapsed != lowered {
+        notes.push("Collapsed extra, leading or trailing whitespace to single spaces".to_string());
+    }
+
+    InputNormalization {
+        changed: *collapsed != mnemonic_str,
+        normalized: collapsed,
+        notes,
+    }
+}
+
+/// Reports how a BIP39 passphrase is normalized before seed derivation
+///
+/// Unlike the phrase, a passphrase is only NFKD-normalized: its case and
+/// whitespace are significant, so "Secret" and "secret " give different
+/// wallets. Surrounding whitespace is kept but called out, since it is a
+/// common cause of "wrong wallet" reports.
+///
+/// # Arguments
+/// * `passphrase` - The passphrase as typed
+///
+/// # Returns
+/// * `InputNormalization` - The normalized passphrase and a note per adjustment
+#[wasm_bindgen]
+pub fn normalize_passphrase(passphrase: &str) -> InputNormalization {
+    let mut notes = Vec::new();
+
+    let normalized = Zeroizing::new(passphrase.nfkd().collect::<String>());
+    if *normalized != passphrase {
+        notes.push("Applied Unicode NFKD normalization (e.g. to accented characters)".to_string());
+    }
+    if normalized.trim() != normalized.as_str() {
+        notes.push("Kept leading or trailing whitespace; it is part of the passphrase".to_string());
+    }
+
+    InputNormalization {
+        changed: *normalized != passphrase,
+        normalized,
+        notes,
+    }
+}
+
+/// Parses a mnemonic after applying `normalize_mnemonic()`
+///
+/// English is tried first, since several words are shared with the French
+/// list and Cosmos wallets only issue English phrases; other languages are
+/// then detected from the words.
+pub fn parse_mnemonic(mnemonic_str: &str) -> Result<Mnemonic, bip39::Error> {
+    let normalized = normalize_mnemonic(mnemonic_str).normalized;
+
+    match Mnemonic::parse_in_normalized(Language::English, &normalized) {
+        Err(bip39::Error::UnknownWord(_)) => Mnemonic::parse_normalized(&normalized),
+        result => result,
+    }
+}
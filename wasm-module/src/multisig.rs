@@ -0,0 +1,249 @@
+//! Legacy amino multisig addresses
+//!
+//! A Cosmos SDK multisig account (`LegacyAminoPubKey`) has no key of its own:
+//! its address is the truncated SHA256 of the amino encoding of the threshold
+//! and the participant public keys. This module reproduces that encoding so a
+//! treasury multisig address can be verified offline before it is funded, or
+//! ground to a vanity pattern by varying one participant's key.
+
+use crate::address::{encode_bech32, SECP256K1_AMINO_PREFIX};
+use crate::crosscheck::paranoid_checked;
+use crate::error::VanityError;
+use crate::hd::hash160;
+use crate::{
+    address_matches, check_target, hrp_or_default, random_keypair, AccountScheme, Keypair,
+    VanityPosition,
+};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use k256::PublicKey;
+use sha2::{Digest, Sha256};
+use wasm_bindgen::prelude::*;
+
+/// Amino prefix of `tendermint/PubKeyMultisigThreshold`
+const MULTISIG_AMINO_PREFIX: [u8; 4] = [0x22, 0xC1, 0xF7, 0xE2];
+
+/// Parses a compressed secp256k1 public key given as hex or base64
+pub fn parse_compressed_pubkey(input: &str) -> Result<[u8; 33], Box<dyn std::error::Error>> {
+    let input = input.trim();
+    let bytes = if input.len() == 66 && input.chars().all(|ch| ch.is_ascii_hexdigit()) {
+        hex::decode(input)?
+    } else {
+        BASE64_STANDARD.decode(input).map_err(|_| {
+            format!(
+                "Invalid public key: neither 33-byte hex nor base64: {}",
+                input
+            )
+        })?
+    };
+
+    let pubkey: [u8; 33] = bytes.as_slice().try_into().map_err(|_| {
+        format!(
+            "Invalid public key: expected 33 compressed bytes, got {}",
+            bytes.len()
+        )
+    })?;
+    PublicKey::from_sec1_bytes(&pubkey)
+        .map_err(|_| "Invalid public key: not a valid secp256k1 point")?;
+    Ok(pubkey)
+}
+
+/// Appends an unsigned varint (protobuf/amino encoding)
+fn push_uvarint(buffer: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buffer.push((value as u8) | 0x80);
+        value >>= 7;
+    }
+    buffer.push(value as u8);
+}
+
+/// Computes the 20-byte address of a legacy amino multisig
+///
+/// # Arguments
+/// * `pubkeys` - The participant compressed public keys
+/// * `threshold` - Number of signatures required
+/// * `sort` - true to sort keys by address first, as `keys add --multisig` does by default
+pub fn multisig_address_bytes(
+    pubkeys: &[[u8; 33]],
+    threshold: u32,
+    sort: bool,
+) -> Result<[u8; 20], Box<dyn std::error::Error>> {
+    if pubkeys.is_empty() {
+        return Err("A multisig needs at least one public key".into());
+    }
+    if threshold == 0 || threshold as usize > pubkeys.len() {
+        return Err(format!(
+            "Invalid threshold: {} (expected 1 to {})",
+            threshold,
+            pubkeys.len()
+        )
+        .into());
+    }
+
+    let mut ordered = pubkeys.to_vec();
+    if sort {
+        ordered.sort_by_key(|pubkey| hash160(pubkey));
+    }
+
+    // Field 1: threshold (varint); field 2: each pubkey as a length-delimited amino value
+    let mut encoded = MULTISIG_AMINO_PREFIX.to_vec();
+    encoded.push(0x08);
+    push_uvarint(&mut encoded, u64::from(threshold));
+    for pubkey in &ordered {
+        encoded.push(0x12);
+        push_uvarint(
+            &mut encoded,
+            (SECP256K1_AMINO_PREFIX.len() + 1 + pubkey.len()) as u64,
+        );
+        encoded.extend_from_slice(&SECP256K1_AMINO_PREFIX);
+        encoded.push(pubkey.len() as u8);
+        encoded.extend_from_slice(pubkey);
+    }
+
+    let hash = Sha256::digest(&encoded);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[..20]);
+    Ok(address)
+}
+
+/// Computes the address of a legacy amino (`LegacyAminoPubKey`) multisig
+///
+/// This gives the same address as `keys add <name> --multisig=... --multisig-threshold=K`.
+/// The CLI sorts keys by address unless `--nosort` is passed; set `sort`
+/// accordingly, since key order changes the address.
+///
+/// # Arguments
+/// * `pubkeys` - The participant compressed secp256k1 public keys, as hex or base64
+/// * `threshold` - Number of signatures required (1 to the number of keys)
+/// * `sort` - true to sort keys by address (CLI default), false to keep the given order
+/// * `hrp` - Optional bech32 prefix for the address (defaults to "mantra")
+///
+/// # Returns
+/// * `String` - The bech32-encoded multisig address, or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// const address = multisig_address([alice.public_key_base64(true), bob.public_key_base64(true)], 2, true, undefined);
+/// console.log(`Treasury multisig: ${address}`);
+/// ```
+#[wasm_bindgen]
+pub fn multisig_address(
+    pubkeys: Vec<String>,
+    threshold: u32,
+    sort: bool,
+    hrp: Option<String>,
+) -> Result<String, VanityError> {
+    let pubkeys = pubkeys
+        .iter()
+        .map(|pubkey| parse_compressed_pubkey(pubkey))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| VanityError::InvalidKey(e.to_string()))?;
+    let hrp = hrp_or_default(hrp);
+
+    multisig_address_bytes(&pubkeys, threshold, sort)
+        .and_then(|address| encode_bech32(&hrp, &address))
+        .map_err(|e| {
+            VanityError::DerivationFailed(format!("Error computing multisig address: {}", e))
+        })
+}
+
+/// A multisig whose address matches the vanity pattern, with the ground participant key
+#[wasm_bindgen]
+pub struct MultisigVanityMatch {
+    participant: Keypair,
+    multisig_address: String,
+}
+
+#[wasm_bindgen]
+impl MultisigVanityMatch {
+    /// Gets the address of the generated participant
+    #[wasm_bindgen(getter)]
+    pub fn participant_address(&self) -> String {
+        self.participant.address.clone()
+    }
+
+    /// Takes the generated participant keypair that completes the multisig
+    ///
+    /// This consumes the match, so the keypair's secrets are never copied; read
+    /// `multisig_address` first, as the JavaScript handle is unusable afterwards.
+    pub fn into_participant(self) -> Keypair {
+        self.participant
+    }
+
+    /// Gets the matching multisig address
+    #[wasm_bindgen(getter)]
+    pub fn multisig_address(&self) -> String {
+        self.multisig_address.clone()
+    }
+}
+
+/// Grind the last participant of a multisig until its address matches a pattern
+///
+/// Holds the N-1 given public keys fixed and generates a fresh random keypair
+/// for the final participant on each attempt, so the matching multisig comes
+/// with a full mnemonic for that participant. Each attempt costs one mnemonic
+/// derivation, the same as the wallet search.
+///
+/// # Arguments
+/// * `fixed_pubkeys` - The other participants' compressed secp256k1 public keys, as hex or base64
+/// * `threshold` - Number of signatures required (1 to the total number of keys)
+/// * `sort` - true to sort keys by address (CLI default); false appends the ground key last
+/// * `target` - The substring pattern to search for in the multisig address
+/// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
+/// * `batch_size` - Number of participant keys to try in this batch
+/// * `hrp` - Optional bech32 prefix for the addresses (defaults to "mantra")
+///
+/// # Returns
+/// * `Option<MultisigVanityMatch>` - The first match, None if no match found, or a
+///   `VanityError` for invalid input or failed generation
+#[wasm_bindgen]
+pub fn generate_multisig_vanity_batch(
+    fixed_pubkeys: Vec<String>,
+    threshold: u32,
+    sort: bool,
+    target: &str,
+    position: VanityPosition,
+    batch_size: u32,
+    hrp: Option<String>,
+) -> Result<Option<MultisigVanityMatch>, VanityError> {
+    let mut pubkeys = fixed_pubkeys
+        .iter()
+        .map(|pubkey| parse_compressed_pubkey(pubkey))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| VanityError::InvalidInput(e.to_string()))?;
+    let hrp = hrp_or_default(hrp);
+    let target_lower = check_target(target)?;
+
+    // Reserve the slot for the ground participant and validate the threshold up front
+    pubkeys.push([0x02; 33]);
+    let last = pubkeys.len() - 1;
+    if threshold == 0 || threshold as usize > pubkeys.len() {
+        return Err(VanityError::InvalidInput(format!(
+            "Invalid threshold: {} (expected 1 to {})",
+            threshold,
+            pubkeys.len()
+        )));
+    }
+
+    for _ in 0..batch_size {
+        let participant = random_keypair(AccountScheme::Cosmos, &hrp)?;
+        let Some(public_key) = participant.public_key else {
+            continue;
+        };
+        pubkeys[last] = public_key;
+
+        let multisig_address = multisig_address_bytes(&pubkeys, threshold, sort)
+            .and_then(|address| encode_bech32(&hrp, &address))
+            .map_err(|e| {
+                VanityError::DerivationFailed(format!("Error computing multisig address: {}", e))
+            })?;
+
+        if address_matches(&multisig_address, &target_lower, position) {
+            return Ok(Some(MultisigVanityMatch {
+                participant: paranoid_checked(participant)?,
+                multisig_address,
+            }));
+        }
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,128 @@
+//! Network-aware address checks
+//!
+//! Dapps embedding the module usually target one network at a time. A
+//! `Network` pins the chain ID and bech32 prefix it expects, so addresses meant
+//! for another chain can be rejected before a transaction is signed.
+
+use crate::address::{check_hrp, decode_bech32, MANTRA_HRP};
+use crate::error::VanityError;
+use wasm_bindgen::prelude::*;
+
+/// Chain ID of MANTRA Chain mainnet
+const MAINNET_CHAIN_ID: &str = "mantra-1";
+
+/// Chain ID of the MANTRA Chain (DuKong) testnet
+const TESTNET_CHAIN_ID: &str = "mantra-dukong-1";
+
+/// Which kind of network a `Network` describes
+#[wasm_bindgen]
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum NetworkKind {
+    /// MANTRA Chain mainnet
+    Mainnet = 0,
+    /// MANTRA Chain public testnet
+    Testnet = 1,
+    /// Any other chain, identified by its chain ID and prefix
+    Custom = 2,
+}
+
+/// A network that addresses can be checked against
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct Network {
+    kind: NetworkKind,
+    chain_id: String,
+    hrp: String,
+}
+
+#[wasm_bindgen]
+impl Network {
+    /// MANTRA Chain mainnet (`mantra-1`, prefix "mantra")
+    pub fn mainnet() -> Network {
+        Network {
+            kind: NetworkKind::Mainnet,
+            chain_id: MAINNET_CHAIN_ID.to_string(),
+            hrp: MANTRA_HRP.to_string(),
+        }
+    }
+
+    /// MANTRA Chain testnet (`mantra-dukong-1`, prefix "mantra")
+    pub fn testnet() -> Network {
+        Network {
+            kind: NetworkKind::Testnet,
+            chain_id: TESTNET_CHAIN_ID.to_string(),
+            hrp: MANTRA_HRP.to_string(),
+        }
+    }
+
+    /// A custom network with its own chain ID and bech32 prefix
+    ///
+    /// # Arguments
+    /// * `chain_id` - The chain ID (e.g. "osmosis-1")
+    /// * `hrp` - The account address prefix (e.g. "osmo")
+    ///
+    /// # Returns
+    /// * `Network` - The network, or an error if the chain ID is empty or the HRP is invalid
+    pub fn custom(chain_id: &str, hrp: &str) -> Result<Network, VanityError> {
+        let chain_id = chain_id.trim();
+        if chain_id.is_empty() {
+            return Err(VanityError::InvalidInput(
+                "Chain ID must not be empty".to_string(),
+            ));
+        }
+        let hrp = hrp.trim();
+        check_hrp(hrp)?;
+
+        Ok(Network {
+            kind: NetworkKind::Custom,
+            chain_id: chain_id.to_string(),
+            hrp: hrp.to_lowercase(),
+        })
+    }
+
+    /// Gets whether this is mainnet, testnet or a custom network
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> NetworkKind {
+        self.kind
+    }
+
+    /// Gets the chain ID of the network
+    #[wasm_bindgen(getter)]
+    pub fn chain_id(&self) -> String {
+        self.chain_id.clone()
+    }
+
+    /// Gets the account address prefix of the network
+    #[wasm_bindgen(getter)]
+    pub fn hrp(&self) -> String {
+        self.hrp.clone()
+    }
+}
+
+/// Checks whether an address is a valid account address on the given network
+///
+/// The address must decode with a valid checksum, carry the network's prefix
+/// and hold a 20- or 32-byte account ID. MANTRA mainnet and testnet share the
+/// "mantra" prefix, so an address cannot tell them apart; this check only
+/// guards against addresses meant for a different chain.
+///
+/// # Arguments
+/// * `address` - The bech32 address to check
+/// * `network` - The network the address is about to be used on
+///
+/// # Returns
+/// * `bool` - true if the address belongs to the network
+///
+/// # Example
+/// ```javascript
+/// if (!is_address_for_network(recipient, Network.mainnet())) {
+///   throw new Error("Recipient is not a MANTRA address");
+/// }
+/// ```
+#[wasm_bindgen]
+pub fn is_address_for_network(address: &str, network: &Network) -> bool {
+    match decode_bech32(address.trim()) {
+        Ok((hrp, bytes)) => hrp == network.hrp && matches!(bytes.len(), 20 | 32),
+        Err(_) => false,
+    }
+}
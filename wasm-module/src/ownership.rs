@@ -0,0 +1,224 @@
+//! Checking which addresses a mnemonic controls
+//!
+//! Before funds are sent to a freshly generated or restored address, users
+//! want to confirm that the phrase they hold really derives it. The scan
+//! covers the paths wallets actually use for both account schemes: address
+//! indexes of the first account (`m/44'/<coin>'/0'/0/i`, as Ledger Live and
+//! the Cosmos SDK CLI number them) and account indexes (`m/44'/<coin>'/a'/0/0`,
+//! as Keplr numbers them).
+
+use crate::address::{decode_bech32, evm_account_id};
+use crate::error::VanityError;
+use crate::hd::{format_derivation_path, hash160, ExtendedPrivateKey, HARDENED_OFFSET};
+use crate::mnemonic::parse_mnemonic;
+use crate::AccountScheme;
+use bip39::Mnemonic;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+/// Highest address or account index a scan may reach
+const MAX_SCAN_INDEX: u32 = 1000;
+
+/// Most addresses one `mnemonic_owns_addresses()` call may check
+const MAX_BATCH_ADDRESSES: usize = 10_000;
+
+/// Scheme and path a scanned address was found at, if it was
+type PathMatch = Option<(AccountScheme, Vec<u32>)>;
+
+/// Whether a mnemonic derives an address, and where
+#[wasm_bindgen]
+pub struct AddressOwnership {
+    address: String,
+    scheme: Option<AccountScheme>,
+    derivation_path: Option<Vec<u32>>,
+}
+
+#[wasm_bindgen]
+impl AddressOwnership {
+    /// Gets the address that was checked
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// Gets whether the mnemonic derives the address
+    #[wasm_bindgen(getter)]
+    pub fn owned(&self) -> bool {
+        self.derivation_path.is_some()
+    }
+
+    /// Gets the account scheme the address was found under, if found
+    #[wasm_bindgen(getter)]
+    pub fn scheme(&self) -> Option<AccountScheme> {
+        self.scheme
+    }
+
+    /// Gets the derivation path the address was found at (e.g. "m/44'/118'/0'/0/3"), if found
+    #[wasm_bindgen(getter)]
+    pub fn derivation_path(&self) -> Option<String> {
+        self.derivation_path.as_deref().map(format_derivation_path)
+    }
+}
+
+/// Computes the account ID a public key has under a scheme
+fn account_id(
+    scheme: AccountScheme,
+    public_key: &[u8; 33],
+) -> Result<[u8; 20], Box<dyn std::error::Error>> {
+    match scheme {
+        AccountScheme::Cosmos => Ok(hash160(public_key)),
+        AccountScheme::EthSecp256k1 => evm_account_id(public_key),
+    }
+}
+
+/// Scans the standard paths up to `max_index` for the given account IDs
+///
+/// Returns, for each target, the first scheme and path that derives it.
+/// Stops early once every target has been found.
+pub fn find_account_ids(
+    mnemonic: &Mnemonic,
+    targets: &[Vec<u8>],
+    max_index: u32,
+) -> Result<Vec<PathMatch>, Box<dyn std::error::Error>> {
+    let master = ExtendedPrivateKey::master(&*Zeroizing::new(mnemonic.to_seed("")))?;
+    let mut found: Vec<PathMatch> = vec![None; targets.len()];
+
+    for scheme in [AccountScheme::Cosmos, AccountScheme::EthSecp256k1] {
+        let purpose_and_coin = &scheme.derivation_path()[..2];
+        let mut paths = Vec::new();
+        // Address indexes of account 0, then accounts 1.. at address index 0
+        for index in 0..=max_index {
+            paths.push([purpose_and_coin, &[HARDENED_OFFSET, 0, index]].concat());
+        }
+        for account in 1..=max_index {
+            paths.push([purpose_and_coin, &[HARDENED_OFFSET + account, 0, 0]].concat());
+        }
+
+        let first_account_chain = master.derive_path(&paths[0][..4])?;
+        for path in paths {
+            if found.iter().all(Option::is_some) {
+                return Ok(found);
+            }
+            let key = if path[2] == HARDENED_OFFSET {
+                first_account_chain.derive_child(path[4])?
+            } else {
+                master.derive_path(&path)?
+            };
+            let id = account_id(scheme, &key.public_key()?)?;
+            for (target, slot) in targets.iter().zip(found.iter_mut()) {
+                if slot.is_none() && target.as_slice() == id {
+                    *slot = Some((scheme, path.clone()));
+                }
+            }
+        }
+    }
+    Ok(found)
+}
+
+/// Checks whether a mnemonic derives an address on any standard path
+///
+/// Both account schemes are scanned: Cosmos (coin type 118) and ethsecp256k1
+/// (coin type 60), each over address indexes 0 to `max_index` of the first
+/// account and account indexes 1 to `max_index`. The address is compared by
+/// its account ID, so an address under any bech32 prefix is recognized.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `address` - The bech32 address to look for
+/// * `max_index` - Highest address and account index to try (at most 1000)
+///
+/// # Returns
+/// * `AddressOwnership` - Whether the address was found, with scheme and path,
+///   or an error for an invalid mnemonic, address or index
+///
+/// # Example
+/// ```javascript
+/// const result = mnemonic_owns_address(mnemonic, "mantra1moon...", 20);
+/// console.log(result.owned ? `Found at ${result.derivation_path}` : "Not derivable from this phrase");
+/// ```
+#[wasm_bindgen]
+pub fn mnemonic_owns_address(
+    mnemonic_str: &str,
+    address: &str,
+    max_index: u32,
+) -> Result<AddressOwnership, VanityError> {
+    let mut results = check_ownership(mnemonic_str, &[address.to_string()], max_index)?;
+    Ok(results.remove(0))
+}
+
+/// Checks which of several addresses a mnemonic derives, in one scan
+///
+/// The same paths as `mnemonic_owns_address()` are scanned, but each key is
+/// derived once and compared against every address, so auditing a list of
+/// deposit addresses costs little more than checking one. The scan stops as
+/// soon as every address has been found.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `addresses` - The bech32 addresses to look for (at most 10000)
+/// * `max_index` - Highest address and account index to try (at most 1000)
+///
+/// # Returns
+/// * `Vec<AddressOwnership>` - One result per address, in input order, or an
+///   error for an invalid mnemonic, address or index
+///
+/// # Example
+/// ```javascript
+/// const results = mnemonic_owns_addresses(mnemonic, depositAddresses, 200);
+/// const foreign = results.filter((result) => !result.owned).map((result) => result.address);
+/// ```
+#[wasm_bindgen]
+pub fn mnemonic_owns_addresses(
+    mnemonic_str: &str,
+    addresses: Vec<String>,
+    max_index: u32,
+) -> Result<Vec<AddressOwnership>, VanityError> {
+    if addresses.len() > MAX_BATCH_ADDRESSES {
+        return Err(VanityError::InvalidInput(format!(
+            "At most {} addresses can be checked at once",
+            MAX_BATCH_ADDRESSES
+        )));
+    }
+    check_ownership(mnemonic_str, &addresses, max_index)
+}
+
+/// Validates the input and scans for every address
+fn check_ownership(
+    mnemonic_str: &str,
+    addresses: &[String],
+    max_index: u32,
+) -> Result<Vec<AddressOwnership>, VanityError> {
+    if max_index > MAX_SCAN_INDEX {
+        return Err(VanityError::InvalidInput(format!(
+            "max_index must be at most {}",
+            MAX_SCAN_INDEX
+        )));
+    }
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    let targets = addresses
+        .iter()
+        .map(|address| {
+            decode_bech32(address.trim())
+                .map(|(_, account_id)| account_id)
+                .map_err(|e| {
+                    VanityError::InvalidAddress(format!("Invalid address {}: {}", address, e))
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let found = find_account_ids(&mnemonic, &targets, max_index)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving keys: {}", e)))?;
+    Ok(addresses
+        .iter()
+        .zip(found)
+        .map(|(address, found)| {
+            let (scheme, derivation_path) = found.unzip();
+            AddressOwnership {
+                address: address.trim().to_string(),
+                scheme,
+                derivation_path,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,192 @@
+//! Printable paper wallets
+//!
+//! A paper wallet is laid out as a single A4 SVG page entirely inside the
+//! module: the address with its QR code on top, the numbered recovery phrase
+//! below. Nothing is fetched or uploaded, so the page can be produced and
+//! printed from a machine that stays offline.
+
+use crate::audit::audit_export;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::qr::qr_code;
+use crate::Keypair;
+use qrcode::{Color, QrCode};
+use std::fmt::Write;
+use wasm_bindgen::prelude::*;
+
+/// Page width in millimetres (A4 portrait)
+const PAGE_WIDTH: f64 = 210.0;
+
+/// Page height in millimetres (A4 portrait)
+const PAGE_HEIGHT: f64 = 297.0;
+
+/// Outer page margin in millimetres
+const MARGIN: f64 = 15.0;
+
+/// Edge length of the address QR code in millimetres
+const QR_SIZE: f64 = 50.0;
+
+/// Number of columns in the recovery phrase grid
+const GRID_COLUMNS: usize = 4;
+
+/// Height of one recovery phrase cell in millimetres
+const GRID_ROW_HEIGHT: f64 = 10.0;
+
+/// Approximate advance of a monospace glyph relative to its font size
+const MONOSPACE_ADVANCE: f64 = 0.6;
+
+/// Escapes text for use inside SVG elements and attributes
+fn escape_xml(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '"' => "&quot;".to_string(),
+            '\'' => "&apos;".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// Draws the dark modules of a QR code as one path scaled into a square
+fn qr_path(code: &QrCode, x: f64, y: f64, size: f64) -> String {
+    let width = code.width();
+    let mut path = String::new();
+    for (index, color) in code.to_colors().into_iter().enumerate() {
+        if color == Color::Dark {
+            let _ = write!(path, "M{} {}h1v1h-1z", index % width, index / width);
+        }
+    }
+
+    format!(
+        r##"<path transform="translate({x} {y}) scale({scale})" fill="#000" d="{path}"/>"##,
+        scale = size / width as f64
+    )
+}
+
+/// Lays out the recovery phrase as numbered cells, numbered down each column
+fn mnemonic_grid(words: &[&str], top: f64) -> String {
+    let rows = words.len().div_ceil(GRID_COLUMNS);
+    let column_width = (PAGE_WIDTH - 2.0 * MARGIN) / GRID_COLUMNS as f64;
+    let mut grid = String::new();
+
+    for (index, word) in words.iter().enumerate() {
+        let x = MARGIN + (index / rows) as f64 * column_width;
+        let y = top + (index % rows) as f64 * GRID_ROW_HEIGHT;
+        let _ = write!(
+            grid,
+            r##"<rect x="{x}" y="{y}" width="{w}" height="{h}" fill="none" stroke="#999" stroke-width="0.3"/><text x="{nx}" y="{ty}" font-size="3" fill="#666">{number}</text><text x="{wx}" y="{ty}" font-size="4.5" font-family="monospace">{word}</text>"##,
+            w = column_width - 2.0,
+            h = GRID_ROW_HEIGHT - 2.0,
+            nx = x + 2.0,
+            wx = x + 8.0,
+            ty = y + 5.5,
+            number = index + 1,
+            word = escape_xml(word),
+        );
+    }
+    grid
+}
+
+/// Renders a printable paper wallet for a keypair as an A4 SVG page
+///
+/// The page shows the address with its QR code (safe to share) and the
+/// numbered recovery phrase (secret), plus the derivation path and a creation
+/// date when requested. Print it from an offline machine and avoid printers
+/// that store jobs.
+///
+/// # Arguments
+/// * `keypair` - The keypair to print (must have a mnemonic)
+/// * `include_derivation_path` - true to print the derivation path, when known
+/// * `created` - Optional creation date text (e.g. "2025-06-01")
+///
+/// # Returns
+/// * `String` - The SVG document, or an error if the keypair has no mnemonic
+///   or hardened mode is on
+///
+/// # Example
+/// ```javascript
+/// const svg = paper_wallet_svg(keypair, true, new Date().toISOString().slice(0, 10));
+/// printFrame.contentDocument.body.innerHTML = svg;
+/// ```
+#[wasm_bindgen]
+pub fn paper_wallet_svg(
+    keypair: &Keypair,
+    include_derivation_path: bool,
+    created: Option<String>,
+) -> Result<String, VanityError> {
+    check_plaintext_secrets("a paper wallet")?;
+    let mnemonic = keypair.mnemonic.as_deref().ok_or_else(|| {
+        VanityError::InvalidInput("Paper wallets need a keypair with a mnemonic".to_string())
+    })?;
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    let address = keypair.address();
+    let code = qr_code(address.as_bytes()).map_err(VanityError::InvalidInput)?;
+
+    let mut svg = format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{PAGE_WIDTH}mm" height="{PAGE_HEIGHT}mm" viewBox="0 0 {PAGE_WIDTH} {PAGE_HEIGHT}" font-family="sans-serif"><rect width="{PAGE_WIDTH}" height="{PAGE_HEIGHT}" fill="#fff"/>"##
+    );
+    let _ = write!(
+        svg,
+        r#"<text x="{MARGIN}" y="25" font-size="8" font-weight="bold">Paper Wallet</text>"#
+    );
+    if let Some(created) = created.filter(|created| !created.trim().is_empty()) {
+        let _ = write!(
+            svg,
+            r##"<text x="{MARGIN}" y="32" font-size="3.5" fill="#666">Created {}</text>"##,
+            escape_xml(created.trim())
+        );
+    }
+
+    // Address block: QR on the left, text on the right
+    let qr_top = 42.0;
+    let text_x = MARGIN + QR_SIZE + 7.0;
+    // Shrink long (32-byte) addresses so they stay on one line
+    let address_font =
+        ((PAGE_WIDTH - MARGIN - text_x) / (address.len() as f64 * MONOSPACE_ADVANCE)).min(3.4);
+    svg.push_str(&qr_path(&code, MARGIN, qr_top, QR_SIZE));
+    let _ = write!(
+        svg,
+        r##"<text x="{text_x}" y="{}" font-size="5" font-weight="bold">Address</text><text x="{text_x}" y="{}" font-size="3.5" fill="#666">Public: share it to receive funds</text><text x="{text_x}" y="{}" font-size="{address_font:.2}" font-family="monospace">{}</text>"##,
+        qr_top + 6.0,
+        qr_top + 12.0,
+        qr_top + 22.0,
+        escape_xml(&address)
+    );
+    if let Some(path) = keypair
+        .derivation_path()
+        .filter(|_| include_derivation_path)
+    {
+        let _ = write!(
+            svg,
+            r##"<text x="{text_x}" y="{}" font-size="3.5" fill="#666">Derivation path</text><text x="{text_x}" y="{}" font-size="3.5" font-family="monospace">{}</text>"##,
+            qr_top + 32.0,
+            qr_top + 38.0,
+            escape_xml(&path)
+        );
+    }
+
+    // Recovery phrase block
+    let phrase_top = qr_top + QR_SIZE + 15.0;
+    let _ = write!(
+        svg,
+        r##"<line x1="{MARGIN}" y1="{}" x2="{}" y2="{}" stroke="#000" stroke-width="0.3" stroke-dasharray="2 1.5"/><text x="{MARGIN}" y="{}" font-size="5" font-weight="bold">Recovery phrase ({} words)</text><text x="{MARGIN}" y="{}" font-size="3.5" fill="#b00">Secret: anyone who reads these words controls the funds</text>"##,
+        phrase_top - 7.0,
+        PAGE_WIDTH - MARGIN,
+        phrase_top - 7.0,
+        phrase_top,
+        words.len(),
+        phrase_top + 6.0
+    );
+    svg.push_str(&mnemonic_grid(&words, phrase_top + 10.0));
+
+    let _ = write!(
+        svg,
+        r##"<text x="{MARGIN}" y="{}" font-size="3" fill="#666">Store this page offline and out of sight. Never photograph it or type the phrase into a website.</text></svg>"##,
+        PAGE_HEIGHT - MARGIN
+    );
+
+    audit_export("paper_wallet", Some(&address))?;
+    Ok(svg)
+}
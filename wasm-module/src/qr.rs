@@ -0,0 +1,228 @@
+//! QR code rendering
+//!
+//! QR codes are rendered inside the module so the offline page needs no
+//! separate JavaScript QR library, and every build encodes an address the
+//! same way. Output is either a standalone SVG or the raw module matrix for
+//! frontends that draw onto a canvas themselves. Secrets (mnemonics and
+//! encrypted mnemonic blobs) are only rendered after an explicit opt-in.
+
+use crate::address::decode_bech32;
+use crate::audit::audit_export;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::keystore::compact_mnemonic_blob;
+use crate::mnemonic::parse_mnemonic;
+use qrcode::render::svg;
+use qrcode::{Color, EcLevel, QrCode};
+use wasm_bindgen::prelude::*;
+
+/// Default minimum edge length of a rendered SVG, in pixels
+const DEFAULT_SVG_SIZE: u32 = 256;
+
+/// Builds a QR code at error correction level M (about 15% recoverable)
+pub fn qr_code(data: &[u8]) -> Result<QrCode, String> {
+    QrCode::with_error_correction_level(data, EcLevel::M)
+        .map_err(|e| format!("Error building QR code: {}", e))
+}
+
+/// Renders a QR code as an SVG document with a 4-module quiet zone
+pub fn qr_svg(code: &QrCode, min_size: Option<u32>) -> String {
+    let size = min_size.unwrap_or(DEFAULT_SVG_SIZE);
+    code.render::<svg::Color>()
+        .min_dimensions(size, size)
+        .quiet_zone(true)
+        .build()
+}
+
+/// Refuses to render secrets unless the caller acknowledged the risk
+fn check_risk_acknowledged(acknowledge_risk: bool) -> Result<(), VanityError> {
+    if acknowledge_risk {
+        Ok(())
+    } else {
+        Err(VanityError::NotAcknowledged(
+            "Refusing to render a secret as a QR code without acknowledge_risk = true".to_string(),
+        ))
+    }
+}
+
+/// Checks that the input is a bech32 address and returns it in the requested case
+fn checked_address(address: &str, uppercase: bool) -> Result<String, VanityError> {
+    let address = address.trim();
+    decode_bech32(address)
+        .map_err(|e| VanityError::InvalidAddress(format!("Invalid address: {}", e)))?;
+    Ok(if uppercase {
+        address.to_uppercase()
+    } else {
+        address.to_lowercase()
+    })
+}
+
+/// The dark and light modules of a QR code, without a quiet zone
+#[wasm_bindgen]
+pub struct QrMatrix {
+    width: u32,
+    modules: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl QrMatrix {
+    /// Gets the number of modules along each edge
+    #[wasm_bindgen(getter)]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Gets the modules row by row, 1 for dark and 0 for light
+    #[wasm_bindgen(getter)]
+    pub fn modules(&self) -> Vec<u8> {
+        self.modules.clone()
+    }
+}
+
+impl QrMatrix {
+    /// Reads the module matrix out of a QR code
+    pub fn from_code(code: &QrCode) -> QrMatrix {
+        QrMatrix {
+            width: code.width() as u32,
+            modules: code
+                .to_colors()
+                .into_iter()
+                .map(|color| u8::from(color == Color::Dark))
+                .collect(),
+        }
+    }
+}
+
+/// Renders a bech32 address as a QR code SVG
+///
+/// The address is checked before encoding, so a mistyped address never ends
+/// up in a QR code. The SVG includes the standard 4-module quiet zone and
+/// scales cleanly, so it can be inlined or printed as is.
+///
+/// With `uppercase` the address is encoded as `MANTRA1...`, which QR
+/// alphanumeric mode stores more compactly. Bech32 allows this form, but check
+/// that the scanning wallet accepts it.
+///
+/// # Arguments
+/// * `address` - The bech32 address to encode
+/// * `uppercase` - true to encode the all-uppercase form for a denser code
+/// * `min_size` - Optional minimum edge length in pixels (defaults to 256)
+///
+/// # Returns
+/// * `String` - The SVG document, or an error if the address is invalid
+///
+/// # Example
+/// ```javascript
+/// document.getElementById("qr").innerHTML = address_qr_svg(keypair.address, false, undefined);
+/// ```
+#[wasm_bindgen]
+pub fn address_qr_svg(
+    address: &str,
+    uppercase: bool,
+    min_size: Option<u32>,
+) -> Result<String, VanityError> {
+    let address = checked_address(address, uppercase)?;
+    let code = qr_code(address.as_bytes()).map_err(VanityError::InvalidInput)?;
+
+    Ok(qr_svg(&code, min_size))
+}
+
+/// Encodes a bech32 address as a QR module matrix
+///
+/// Useful for drawing the code onto a canvas. The matrix has no quiet zone;
+/// leave a light margin of at least 4 modules around it when drawing.
+///
+/// # Arguments
+/// * `address` - The bech32 address to encode
+/// * `uppercase` - true to encode the all-uppercase form for a denser code
+///
+/// # Returns
+/// * `QrMatrix` - The module matrix, or an error if the address is invalid
+///
+/// # Example
+/// ```javascript
+/// const qr = address_qr_matrix(keypair.address, true);
+/// qr.modules.forEach((dark, i) => {
+///   if (dark) ctx.fillRect((i % qr.width) * 4, Math.floor(i / qr.width) * 4, 4, 4);
+/// });
+/// ```
+#[wasm_bindgen]
+pub fn address_qr_matrix(address: &str, uppercase: bool) -> Result<QrMatrix, VanityError> {
+    let address = checked_address(address, uppercase)?;
+    let code = qr_code(address.as_bytes()).map_err(VanityError::InvalidInput)?;
+
+    Ok(QrMatrix::from_code(&code))
+}
+
+/// Renders a mnemonic as a QR code SVG, for air-gapped backup workflows
+///
+/// Anyone who can see or photograph this QR code controls the wallet, and
+/// screens, printers and camera apps may keep copies. Rendering is therefore
+/// refused unless `acknowledge_risk` is true, which a frontend should only
+/// pass after the user explicitly confirmed they understand this. Prefer
+/// `encrypted_mnemonic_qr_svg()` where the scanning side can decrypt.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase to encode (normalized before encoding)
+/// * `acknowledge_risk` - Must be true to confirm the user accepted the risk
+/// * `min_size` - Optional minimum edge length in pixels (defaults to 256)
+///
+/// # Returns
+/// * `String` - The SVG document, or an error if the risk was not
+///   acknowledged, the mnemonic is invalid or hardened mode is on
+///
+/// # Example
+/// ```javascript
+/// if (riskCheckbox.checked) {
+///   qrContainer.innerHTML = mnemonic_qr_svg(keypair.reveal_mnemonic(false), true, undefined);
+/// }
+/// ```
+#[wasm_bindgen]
+pub fn mnemonic_qr_svg(
+    mnemonic_str: &str,
+    acknowledge_risk: bool,
+    min_size: Option<u32>,
+) -> Result<String, VanityError> {
+    check_risk_acknowledged(acknowledge_risk)?;
+    check_plaintext_secrets("a mnemonic QR code")?;
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    let code = qr_code(mnemonic.to_string().as_bytes()).map_err(VanityError::InvalidInput)?;
+    audit_export("mnemonic_qr", None)?;
+
+    Ok(qr_svg(&code, min_size))
+}
+
+/// Renders an `encrypt_mnemonic()` blob as a QR code SVG
+///
+/// The blob is only as strong as its password, and a QR code is easy to copy
+/// unnoticed for offline guessing, so this also requires `acknowledge_risk`.
+/// The blob is re-serialized as compact JSON to keep the code small.
+///
+/// # Arguments
+/// * `blob` - The JSON blob returned by `encrypt_mnemonic()`
+/// * `acknowledge_risk` - Must be true to confirm the user accepted the risk
+/// * `min_size` - Optional minimum edge length in pixels (defaults to 256)
+///
+/// # Returns
+/// * `String` - The SVG document, or an error if the risk was not
+///   acknowledged or the blob is malformed
+///
+/// # Example
+/// ```javascript
+/// const blob = encrypt_mnemonic(keypair.reveal_mnemonic(false), password, undefined);
+/// qrContainer.innerHTML = encrypted_mnemonic_qr_svg(blob, true, undefined);
+/// ```
+#[wasm_bindgen]
+pub fn encrypted_mnemonic_qr_svg(
+    blob: &str,
+    acknowledge_risk: bool,
+    min_size: Option<u32>,
+) -> Result<String, VanityError> {
+    check_risk_acknowledged(acknowledge_risk)?;
+    let blob = compact_mnemonic_blob(blob)
+        .map_err(|e| VanityError::InvalidInput(format!("Invalid mnemonic blob: {}", e)))?;
+    let code = qr_code(blob.as_bytes()).map_err(VanityError::InvalidInput)?;
+
+    Ok(qr_svg(&code, min_size))
+}
@@ -0,0 +1,744 @@
+//! Mnemonic recovery from transcription errors
+//!
+//! A backup with a single wrong word fails the BIP39 checksum (or, if the
+//! checksum happens to pass, derives an unexpected address). This module
+//! brute-forces the English wordlist to find the corrections that produce a
+//! valid phrase, optionally constrained to one that derives a known address.
+//! It also completes phrases with missing words when the address is known,
+//! and detects words that were written down in the wrong order, and explains
+//! word by word why a phrase is rejected. Missing final (checksum) words can
+//! be computed outright.
+
+use crate::error::VanityError;
+use crate::{
+    address::MANTRA_HRP,
+    ct::secret_eq,
+    derive_address,
+    mnemonic::normalize_mnemonic,
+    wordlist::{language_or_default, word_indexes},
+};
+use bip39::{Language, Mnemonic};
+use sha2::{Digest, Sha256};
+use wasm_bindgen::prelude::*;
+
+/// Marker words accepted in place of a missing mnemonic word
+const BLANK_MARKERS: [&str; 2] = ["?", "_"];
+
+/// Maximum number of missing words a search may cover (2048^2 combinations)
+const MAX_BLANKS: usize = 2;
+
+/// Maximum number of wordlist suggestions offered for an unknown word
+const MAX_WORD_SUGGESTIONS: usize = 3;
+
+/// Maximum edit distance for a wordlist word to be suggested
+const MAX_SUGGESTION_DISTANCE: usize = 2;
+
+/// A single-word substitution that turns the phrase into a valid mnemonic
+#[wasm_bindgen]
+pub struct MnemonicCorrection {
+    position: u32,
+    original_word: String,
+    replacement_word: String,
+    mnemonic: String,
+}
+
+#[wasm_bindgen]
+impl MnemonicCorrection {
+    /// Gets the zero-based position of the replaced word
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// Gets the word as it appeared in the input phrase
+    #[wasm_bindgen(getter)]
+    pub fn original_word(&self) -> String {
+        self.original_word.clone()
+    }
+
+    /// Gets the wordlist word substituted at `position`
+    #[wasm_bindgen(getter)]
+    pub fn replacement_word(&self) -> String {
+        self.replacement_word.clone()
+    }
+
+    /// Gets the full corrected mnemonic phrase
+    #[wasm_bindgen(getter)]
+    pub fn mnemonic(&self) -> String {
+        self.mnemonic.clone()
+    }
+}
+
+/// Suggests single-word corrections for a mnemonic with one wrong word
+///
+/// Every position is tried with every word of the BIP39 English wordlist, and
+/// substitutions that yield a valid checksum are reported. If one word is not
+/// in the wordlist at all, only that position is tried. Without an expected
+/// address a 24-word phrase typically has around 190 checksum-valid
+/// corrections, so supply the address whenever it is known.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string (12 to 24 words)
+/// * `expected_address` - Optional MANTRA address the corrected phrase must derive at m/44'/118'/0'/0/0
+///
+/// # Returns
+/// * `Vec<MnemonicCorrection>` - Candidate corrections (empty if none found), or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// const candidates = suggest_mnemonic_corrections("word1 ... word24", "mantra1...");
+/// candidates.forEach((c) => console.log(`#${c.position + 1}: ${c.original_word} -> ${c.replacement_word}`));
+/// ```
+#[wasm_bindgen]
+pub fn suggest_mnemonic_corrections(
+    mnemonic_str: &str,
+    expected_address: Option<String>,
+) -> Result<Vec<MnemonicCorrection>, VanityError> {
+    let words = split_phrase(mnemonic_str).map_err(VanityError::InvalidMnemonic)?;
+    let expected_address = expected_address.map(|address| address.trim().to_lowercase());
+
+    single_word_corrections(&words, expected_address.as_deref())
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Error recovering mnemonic: {}", e)))
+}
+
+/// Progress of a chunked missing-word search
+///
+/// Searches run in chunks so the caller can report progress between calls and
+/// cancel by simply not requesting the next chunk.
+#[wasm_bindgen]
+pub struct MissingWordSearch {
+    mnemonic: Option<String>,
+    next_index: u32,
+    total: u32,
+}
+
+#[wasm_bindgen]
+impl MissingWordSearch {
+    /// Gets the completed mnemonic, if this chunk found it
+    #[wasm_bindgen(getter)]
+    pub fn mnemonic(&self) -> Option<String> {
+        self.mnemonic.clone()
+    }
+
+    /// Gets the combination index to pass as `start_index` for the next chunk
+    #[wasm_bindgen(getter)]
+    pub fn next_index(&self) -> u32 {
+        self.next_index
+    }
+
+    /// Gets the total number of combinations in the search space
+    #[wasm_bindgen(getter)]
+    pub fn total(&self) -> u32 {
+        self.total
+    }
+
+    /// Gets whether every combination has been checked
+    #[wasm_bindgen(getter)]
+    pub fn done(&self) -> bool {
+        self.next_index >= self.total
+    }
+}
+
+/// Searches completions of a mnemonic with one or two missing words
+///
+/// Mark each missing word with `?` or `_`. The search walks the wordlist
+/// combinations for the blanks in order, skipping those that fail the BIP39
+/// checksum, and stops at the first completion that derives `expected_address`
+/// at m/44'/118'/0'/0/0. Call repeatedly with the returned `next_index` until a
+/// mnemonic is found or `done` is true; stop calling to cancel.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase with blanks (12 to 24 words in total)
+/// * `expected_address` - The MANTRA address the completed phrase must derive
+/// * `start_index` - First combination to check (0 for a new search)
+/// * `count` - Number of combinations to check in this chunk
+///
+/// # Returns
+/// * `MissingWordSearch` - The chunk result and progress, or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// let search = recover_missing_words("word1 ? word3 ... word24", address, 0, 50000);
+/// while (!search.mnemonic && !search.done) {
+///   console.log(`${search.next_index} / ${search.total}`);
+///   search = recover_missing_words("word1 ? word3 ... word24", address, search.next_index, 50000);
+/// }
+/// ```
+#[wasm_bindgen]
+pub fn recover_missing_words(
+    mnemonic_str: &str,
+    expected_address: &str,
+    start_index: u32,
+    count: u32,
+) -> Result<MissingWordSearch, VanityError> {
+    let words = split_phrase(mnemonic_str).map_err(VanityError::InvalidMnemonic)?;
+    let expected_address = expected_address.trim().to_lowercase();
+
+    search_missing_words(&words, &expected_address, start_index, count)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Error recovering mnemonic: {}", e)))
+}
+
+/// Checks one chunk of blank-word combinations against the expected address
+fn search_missing_words(
+    words: &[String],
+    expected_address: &str,
+    start_index: u32,
+    count: u32,
+) -> Result<MissingWordSearch, Box<dyn std::error::Error>> {
+    let mut indexes = Vec::with_capacity(words.len());
+    let mut blanks = Vec::new();
+    for (position, word) in words.iter().enumerate() {
+        if BLANK_MARKERS.contains(&word.as_str()) {
+            blanks.push(position);
+            indexes.push(0);
+            continue;
+        }
+        let index = Language::English.find_word(word).ok_or_else(|| {
+            format!(
+                "Word {} ({}) is not in the BIP39 wordlist",
+                position + 1,
+                word
+            )
+        })?;
+        indexes.push(index);
+    }
+
+    if blanks.is_empty() || blanks.len() > MAX_BLANKS {
+        return Err(format!(
+            "Expected 1 to {} missing words, got {}",
+            MAX_BLANKS,
+            blanks.len()
+        )
+        .into());
+    }
+
+    let wordlist_len = Language::English.word_list().len() as u32;
+    let total = wordlist_len.pow(blanks.len() as u32);
+    let end_index = start_index.saturating_add(count).min(total);
+
+    for combination in start_index..end_index {
+        // Each blank takes one base-2048 digit of the combination index
+        let mut remaining = combination;
+        for &position in blanks.iter().rev() {
+            indexes[position] = (remaining % wordlist_len) as u16;
+            remaining /= wordlist_len;
+        }
+        if !checksum_valid(&indexes) {
+            continue;
+        }
+
+        let mnemonic = mnemonic_from_indexes(&indexes)?;
+        if derives_expected_address(&mnemonic, Some(expected_address))? {
+            return Ok(MissingWordSearch {
+                mnemonic: Some(mnemonic.to_string()),
+                next_index: combination + 1,
+                total,
+            });
+        }
+    }
+
+    Ok(MissingWordSearch {
+        mnemonic: None,
+        next_index: end_index.max(start_index),
+        total,
+    })
+}
+
+/// Tries every single-word substitution and keeps the valid (and matching) ones
+fn single_word_corrections(
+    words: &[String],
+    expected_address: Option<&str>,
+) -> Result<Vec<MnemonicCorrection>, Box<dyn std::error::Error>> {
+    let wordlist = Language::English.word_list();
+    let lookup: Vec<Option<u16>> = words
+        .iter()
+        .map(|word| Language::English.find_word(word))
+        .collect();
+
+    let unknown: Vec<usize> = (0..words.len()).filter(|&i| lookup[i].is_none()).collect();
+    let positions: Vec<usize> = match unknown.len() {
+        0 => (0..words.len()).collect(),
+        1 => unknown,
+        n => return Err(format!("{} words are not in the BIP39 wordlist", n).into()),
+    };
+
+    // Unknown words are only ever substituted, so the placeholder never reaches the checksum
+    let mut indexes: Vec<u16> = lookup.iter().map(|index| index.unwrap_or(0)).collect();
+    let mut corrections = Vec::new();
+
+    for position in positions {
+        let original = indexes[position];
+        for candidate in 0..wordlist.len() as u16 {
+            if lookup[position] == Some(candidate) {
+                continue;
+            }
+            indexes[position] = candidate;
+            if !checksum_valid(&indexes) {
+                continue;
+            }
+
+            let mnemonic = mnemonic_from_indexes(&indexes)?;
+            if !derives_expected_address(&mnemonic, expected_address)? {
+                continue;
+            }
+
+            corrections.push(MnemonicCorrection {
+                position: position as u32,
+                original_word: words[position].clone(),
+                replacement_word: wordlist[candidate as usize].to_string(),
+                mnemonic: mnemonic.to_string(),
+            });
+        }
+        indexes[position] = original;
+    }
+
+    Ok(corrections)
+}
+
+/// A reordering of the input words that yields a valid mnemonic
+#[wasm_bindgen]
+pub struct WordOrderFix {
+    description: String,
+    mnemonic: String,
+}
+
+#[wasm_bindgen]
+impl WordOrderFix {
+    /// Gets a human-readable description of the reordering (e.g. "Swap words 3 and 4")
+    #[wasm_bindgen(getter)]
+    pub fn description(&self) -> String {
+        self.description.clone()
+    }
+
+    /// Gets the reordered mnemonic phrase
+    #[wasm_bindgen(getter)]
+    pub fn mnemonic(&self) -> String {
+        self.mnemonic.clone()
+    }
+}
+
+/// Detects swapped or misordered words in a mnemonic
+///
+/// Tries the transcription mistakes that keep every word but change their
+/// order: swapping two adjacent words, reading a backup grid column by column
+/// instead of row by row (or the reverse), and reversing the whole phrase.
+/// Reorderings that yield a valid checksum are reported, and with an expected
+/// address only those deriving it at m/44'/118'/0'/0/0 are kept.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string (12 to 24 words)
+/// * `expected_address` - Optional MANTRA address the reordered phrase must derive
+///
+/// # Returns
+/// * `Vec<WordOrderFix>` - Candidate reorderings (empty if none found), or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// const fixes = detect_swapped_words("word1 ... word24", undefined);
+/// fixes.forEach((fix) => console.log(`${fix.description}: ${fix.mnemonic}`));
+/// ```
+#[wasm_bindgen]
+pub fn detect_swapped_words(
+    mnemonic_str: &str,
+    expected_address: Option<String>,
+) -> Result<Vec<WordOrderFix>, VanityError> {
+    let words = split_phrase(mnemonic_str).map_err(VanityError::InvalidMnemonic)?;
+    let expected_address = expected_address.map(|address| address.trim().to_lowercase());
+
+    word_order_fixes(&words, expected_address.as_deref())
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Error recovering mnemonic: {}", e)))
+}
+
+/// Tries common word reorderings and keeps the valid (and matching) ones
+fn word_order_fixes(
+    words: &[String],
+    expected_address: Option<&str>,
+) -> Result<Vec<WordOrderFix>, Box<dyn std::error::Error>> {
+    let mut indexes = Vec::with_capacity(words.len());
+    for (position, word) in words.iter().enumerate() {
+        let index = Language::English.find_word(word).ok_or_else(|| {
+            format!(
+                "Word {} ({}) is not in the BIP39 wordlist",
+                position + 1,
+                word
+            )
+        })?;
+        indexes.push(index);
+    }
+
+    let mut fixes = Vec::new();
+    let mut seen = vec![indexes.clone()];
+
+    for (description, reordered) in reorderings(&indexes) {
+        if seen.iter().any(|other| same_indexes(other, &reordered)) || !checksum_valid(&reordered) {
+            continue;
+        }
+        seen.push(reordered.clone());
+
+        let mnemonic = mnemonic_from_indexes(&reordered)?;
+        if derives_expected_address(&mnemonic, expected_address)? {
+            fixes.push(WordOrderFix {
+                description,
+                mnemonic: mnemonic.to_string(),
+            });
+        }
+    }
+
+    Ok(fixes)
+}
+
+/// Lists the candidate reorderings of a phrase with their descriptions
+fn reorderings(indexes: &[u16]) -> Vec<(String, Vec<u16>)> {
+    let len = indexes.len();
+    let mut candidates = Vec::new();
+
+    for i in 0..len - 1 {
+        let mut swapped = indexes.to_vec();
+        swapped.swap(i, i + 1);
+        candidates.push((format!("Swap words {} and {}", i + 1, i + 2), swapped));
+    }
+
+    // A grid written row by row but read column by column; every divisor
+    // pair is tried, which also covers the inverse mistake
+    for columns in 2..len / 2 + 1 {
+        if !len.is_multiple_of(columns) {
+            continue;
+        }
+        let rows = len / columns;
+        let reordered = (0..columns)
+            .flat_map(|column| (0..rows).map(move |row| indexes[row * columns + column]))
+            .collect();
+        candidates.push((
+            format!("Read as {} columns of {} words", columns, rows),
+            reordered,
+        ));
+    }
+
+    let reversed = indexes.iter().rev().copied().collect();
+    candidates.push(("Reverse the word order".to_string(), reversed));
+
+    candidates
+}
+
+/// A word of the phrase that is not in the BIP39 English wordlist
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct UnknownWord {
+    position: u32,
+    word: String,
+    suggestions: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl UnknownWord {
+    /// Gets the zero-based position of the word in the phrase
+    #[wasm_bindgen(getter)]
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// Gets the word as it appeared in the input phrase
+    #[wasm_bindgen(getter)]
+    pub fn word(&self) -> String {
+        self.word.clone()
+    }
+
+    /// Gets the closest wordlist words, best first
+    #[wasm_bindgen(getter)]
+    pub fn suggestions(&self) -> Vec<String> {
+        self.suggestions.clone()
+    }
+}
+
+/// Word-level diagnosis of a mnemonic phrase
+#[wasm_bindgen]
+pub struct MnemonicDiagnostics {
+    word_count: u32,
+    word_count_valid: bool,
+    unknown_words: Vec<UnknownWord>,
+    checksum_valid: Option<bool>,
+}
+
+#[wasm_bindgen]
+impl MnemonicDiagnostics {
+    /// Gets whether the phrase is a valid BIP39 mnemonic
+    #[wasm_bindgen(getter)]
+    pub fn valid(&self) -> bool {
+        self.checksum_valid == Some(true)
+    }
+
+    /// Gets the number of words in the phrase
+    #[wasm_bindgen(getter)]
+    pub fn word_count(&self) -> u32 {
+        self.word_count
+    }
+
+    /// Gets whether the word count is 12, 15, 18, 21 or 24
+    #[wasm_bindgen(getter)]
+    pub fn word_count_valid(&self) -> bool {
+        self.word_count_valid
+    }
+
+    /// Gets the words that are not in the wordlist, with suggestions
+    #[wasm_bindgen(getter)]
+    pub fn unknown_words(&self) -> Vec<UnknownWord> {
+        self.unknown_words.clone()
+    }
+
+    /// Gets whether the checksum matches, once every word is known and the count is valid
+    #[wasm_bindgen(getter)]
+    pub fn checksum_valid(&self) -> Option<bool> {
+        self.checksum_valid
+    }
+
+    /// Gets a one-line description of the first problem, or None for a valid phrase
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> Option<String> {
+        if !self.word_count_valid {
+            return Some(format!(
+                "Invalid word count: {} (expected 12, 15, 18, 21 or 24)",
+                self.word_count
+            ));
+        }
+        if let Some(unknown) = self.unknown_words.first() {
+            let hint = match unknown.suggestions.as_slice() {
+                [] => String::new(),
+                suggestions => format!(" (did you mean {}?)", suggestions.join(", ")),
+            };
+            return Some(format!(
+                "Word {} {:?} is not in the BIP39 wordlist{}",
+                unknown.position + 1,
+                unknown.word,
+                hint
+            ));
+        }
+        match self.checksum_valid {
+            Some(false) => Some(
+                "Every word is in the wordlist but the checksum does not match; a word is wrong or out of order"
+                    .to_string(),
+            ),
+            _ => None,
+        }
+    }
+}
+
+/// Diagnoses a mnemonic word by word
+///
+/// Reports the word count, every word missing from the BIP39 English wordlist
+/// together with its closest wordlist words, and whether the checksum
+/// matches once all words are known. Use it to explain a rejected phrase
+/// before reaching for `suggest_mnemonic_corrections()`.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+///
+/// # Returns
+/// * `MnemonicDiagnostics` - The structured report
+///
+/// # Example
+/// ```javascript
+/// const report = diagnose_mnemonic(input);
+/// if (!report.valid) console.log(report.message);
+/// ```
+#[wasm_bindgen]
+pub fn diagnose_mnemonic(mnemonic_str: &str) -> MnemonicDiagnostics {
+    let phrase = normalize_mnemonic(mnemonic_str).normalized();
+    let words: Vec<String> = phrase.split_whitespace().map(str::to_string).collect();
+    let word_count_valid = matches!(words.len(), 12 | 15 | 18 | 21 | 24);
+
+    let lookup: Vec<Option<u16>> = words
+        .iter()
+        .map(|word| Language::English.find_word(word))
+        .collect();
+    let unknown_words: Vec<UnknownWord> = words
+        .iter()
+        .zip(&lookup)
+        .enumerate()
+        .filter(|(_, (_, index))| index.is_none())
+        .map(|(position, (word, _))| UnknownWord {
+            position: position as u32,
+            word: word.clone(),
+            suggestions: closest_words(word),
+        })
+        .collect();
+
+    let checksum_valid = (word_count_valid && unknown_words.is_empty()).then(|| {
+        let indexes: Vec<u16> = lookup.iter().flatten().copied().collect();
+        checksum_valid(&indexes)
+    });
+
+    MnemonicDiagnostics {
+        word_count: words.len() as u32,
+        word_count_valid,
+        unknown_words,
+        checksum_valid,
+    }
+}
+
+/// Finds the wordlist words closest to a mistyped word, best first
+///
+/// BIP39 English words are unique in their first four letters, so a word
+/// sharing them is ranked first; the rest are ranked by edit distance.
+fn closest_words(word: &str) -> Vec<String> {
+    let stem: String = word.chars().take(4).collect();
+    let mut ranked: Vec<(usize, &str)> = Language::English
+        .word_list()
+        .iter()
+        .filter_map(|candidate| {
+            if stem.len() == 4 && candidate.starts_with(&stem) {
+                return Some((0, *candidate));
+            }
+            let distance = edit_distance(word, candidate);
+            (distance <= MAX_SUGGESTION_DISTANCE).then_some((distance, *candidate))
+        })
+        .collect();
+
+    ranked.sort_by_key(|(distance, _)| *distance);
+    ranked
+        .into_iter()
+        .take(MAX_WORD_SUGGESTIONS)
+        .map(|(_, candidate)| candidate.to_string())
+        .collect()
+}
+
+/// Computes the Levenshtein distance between two words
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (above + 1)
+                .min(row[j] + 1)
+                .min(diagonal + usize::from(ca != *cb));
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+/// Lists every final word that completes a phrase with a valid checksum
+///
+/// The last word of a BIP39 phrase mixes the remaining entropy bits with the
+/// checksum, so a phrase one word short has 2^(11 - checksum bits) valid
+/// endings: 128 for 12 words down to 8 for 24 words. Users who draw the
+/// entropy themselves (dice, cards) pick one of these; users who lost only the
+/// last word can try each against their address.
+///
+/// # Arguments
+/// * `partial` - The first 11, 14, 17, 20 or 23 words
+/// * `language` - Optional wordlist name (defaults to "english")
+///
+/// # Returns
+/// * `Vec<String>` - The valid final words in wordlist order, or an error for invalid input
+///
+/// # Example
+/// ```javascript
+/// const endings = valid_last_words("word1 ... word23", undefined);
+/// console.log(`${endings.length} possible last words: ${endings.join(", ")}`);
+/// ```
+#[wasm_bindgen]
+pub fn valid_last_words(
+    partial: &str,
+    language: Option<String>,
+) -> Result<Vec<String>, VanityError> {
+    let language = language_or_default(language).map_err(VanityError::InvalidInput)?;
+    let phrase = normalize_mnemonic(partial).normalized();
+    let words: Vec<String> = phrase.split_whitespace().map(str::to_string).collect();
+    if !matches!(words.len(), 11 | 14 | 17 | 20 | 23) {
+        return Err(VanityError::InvalidInput(format!(
+            "Invalid word count: {} (expected 11, 14, 17, 20 or 23)",
+            words.len()
+        )));
+    }
+
+    let mut indexes = word_indexes(&words, language).map_err(VanityError::InvalidMnemonic)?;
+    let wordlist = language.word_list();
+    indexes.push(0);
+    let last = indexes.len() - 1;
+    Ok((0..wordlist.len() as u16)
+        .filter(|&candidate| {
+            indexes[last] = candidate;
+            checksum_valid(&indexes)
+        })
+        .map(|candidate| wordlist[candidate as usize].to_string())
+        .collect())
+}
+
+/// Splits a phrase into normalized words and checks the BIP39 word count
+///
+/// The phrase goes through `normalize_mnemonic()` first, like every other API
+/// that takes a mnemonic.
+fn split_phrase(mnemonic_str: &str) -> Result<Vec<String>, String> {
+    let phrase = normalize_mnemonic(mnemonic_str).normalized();
+    let words: Vec<String> = phrase.split_whitespace().map(str::to_string).collect();
+
+    if !matches!(words.len(), 12 | 15 | 18 | 21 | 24) {
+        return Err(format!(
+            "Invalid word count: {} (expected 12, 15, 18, 21 or 24)",
+            words.len()
+        ));
+    }
+    Ok(words)
+}
+
+/// Compares two phrases given as wordlist indexes with `secret_eq()`
+fn same_indexes(a: &[u16], b: &[u16]) -> bool {
+    let bytes = |indexes: &[u16]| -> Vec<u8> {
+        indexes
+            .iter()
+            .flat_map(|index| index.to_be_bytes())
+            .collect()
+    };
+    secret_eq(&bytes(a), &bytes(b))
+}
+
+/// Checks the BIP39 checksum of a phrase given as wordlist indexes
+///
+/// This avoids building and parsing a phrase string for every candidate, which
+/// dominates the cost of a brute-force search.
+fn checksum_valid(indexes: &[u16]) -> bool {
+    let total_bits = indexes.len() * 11;
+    let checksum_bits = total_bits / 33;
+    let entropy_bytes = (total_bits - checksum_bits) / 8;
+
+    let mut bits = [0u8; 33];
+    for (i, &index) in indexes.iter().enumerate() {
+        for j in 0..11 {
+            if (index >> (10 - j)) & 1 == 1 {
+                let bit = i * 11 + j;
+                bits[bit / 8] |= 1 << (7 - bit % 8);
+            }
+        }
+    }
+
+    let hash = Sha256::digest(&bits[..entropy_bytes]);
+    let mask = 0xffu8 << (8 - checksum_bits);
+    secret_eq(&[bits[entropy_bytes] & mask], &[hash[0] & mask])
+}
+
+/// Builds a mnemonic from English wordlist indexes
+fn mnemonic_from_indexes(indexes: &[u16]) -> Result<Mnemonic, Box<dyn std::error::Error>> {
+    let wordlist = Language::English.word_list();
+    let phrase = indexes
+        .iter()
+        .map(|&index| wordlist[index as usize])
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Ok(Mnemonic::parse_in_normalized(Language::English, &phrase)?)
+}
+
+/// Checks the mnemonic against the expected address, if one was given
+fn derives_expected_address(
+    mnemonic: &Mnemonic,
+    expected_address: Option<&str>,
+) -> Result<bool, Box<dyn std::error::Error>> {
+    match expected_address {
+        Some(expected) => Ok(derive_address(mnemonic, MANTRA_HRP)? == expected),
+        None => Ok(true),
+    }
+}
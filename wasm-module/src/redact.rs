@@ -0,0 +1,24 @@
+//! Redacted formatting for secret-bearing types
+//!
+//! Types that hold a mnemonic, private key or chain code implement `Debug`
+//! (and `Keypair` also `Display` and JavaScript `toString()`) by hand, showing
+//! their public fields and replacing each secret with `[REDACTED]`. A stray
+//! `{:?}` in a log line or `console.log(String(keypair))` in glue code then
+//! cannot leak key material. New key types should do the same rather than
+//! derive `Debug`.
+
+use std::fmt;
+
+/// Stands in for a secret value in `Debug` output
+pub struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+/// Redacts an optional secret, keeping whether it is present
+pub fn redact_option<T>(secret: &Option<T>) -> Option<Redacted> {
+    secret.as_ref().map(|_| Redacted)
+}
@@ -0,0 +1,166 @@
+//! Source of key-generation randomness
+//!
+//! Every mnemonic generated for JavaScript (random keypairs, batches and all
+//! vanity searches), every Tendermint key and the random parts of SLIP-39
+//! shares draw their entropy from `KeyRng`, which reads `OsRng` and
+//! passes the output through the continuous health tests in `health.rs`.
+//! Native Rust callers can hand `generate_keypair_with_rng()` any other
+//! `CryptoRng` instead. Integration tests, demos and bug reports sometimes need the
+//! same keys on every run, so builds with the non-default `deterministic-tests`
+//! feature can replace it with a seeded SHA-256 counter stream via
+//! `set_deterministic_seed()`.
+//!
+//! **Keys from such a build are predictable.** The feature refuses to compile
+//! in release builds, and `is_deterministic_build()` lets a frontend show a
+//! banner whenever it is present.
+
+use crate::health::check_rng_output;
+use rand::{rngs::OsRng, CryptoRng, RngCore};
+use wasm_bindgen::prelude::*;
+
+#[cfg(all(feature = "deterministic-tests", not(debug_assertions)))]
+compile_error!(
+    "The `deterministic-tests` feature makes every generated key predictable; \
+     it must never be enabled in a release build"
+);
+
+/// Fills `bytes` with entropy for a new mnemonic
+///
+/// Uses `OsRng`, unless this is a `deterministic-tests` build and a seed has
+/// been set with `set_deterministic_seed()`. Fails if the `OsRng` output does
+/// not pass the continuous health tests.
+fn fill_key_entropy(bytes: &mut [u8]) -> Result<(), String> {
+    #[cfg(feature = "deterministic-tests")]
+    if deterministic::fill(bytes) {
+        return Ok(());
+    }
+    fill_os_entropy(bytes)
+}
+
+/// The default key-generation RNG
+///
+/// `OsRng` behind the continuous health tests, or the seeded stream once
+/// `set_deterministic_seed()` has been called in a `deterministic-tests`
+/// build. `try_fill_bytes()` reports a failed health test as an error;
+/// `fill_bytes()` panics on one, so prefer the former.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct KeyRng;
+
+impl RngCore for KeyRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if let Err(e) = fill_key_entropy(dest) {
+            panic!("{}", e);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        fill_key_entropy(dest).map_err(rand::Error::new)
+    }
+}
+
+impl CryptoRng for KeyRng {}
+
+/// Fills `bytes` from `OsRng` and runs the continuous health tests on them
+pub fn fill_os_entropy(bytes: &mut [u8]) -> Result<(), String> {
+    OsRng.fill_bytes(bytes);
+    check_rng_output(bytes)
+}
+
+/// Reports whether this build can generate predictable keys
+///
+/// True for builds with the `deterministic-tests` feature, whether or not a
+/// seed is currently set. Such builds are for tests and demos only.
+///
+/// # Returns
+/// * `bool` - true if `set_deterministic_seed()` is available
+///
+/// # Example
+/// ```javascript
+/// if (is_deterministic_build()) showBanner("TEST BUILD: generated keys are not secret");
+/// ```
+#[wasm_bindgen]
+pub fn is_deterministic_build() -> bool {
+    cfg!(feature = "deterministic-tests")
+}
+
+#[cfg(feature = "deterministic-tests")]
+mod deterministic {
+    use sha2::{Digest, Sha256};
+    use std::cell::RefCell;
+    use wasm_bindgen::prelude::*;
+    use zeroize::Zeroize;
+
+    /// A seeded SHA-256 counter stream
+    struct SeededStream {
+        seed: [u8; 32],
+        counter: u64,
+    }
+
+    impl Drop for SeededStream {
+        fn drop(&mut self) {
+            self.seed.zeroize();
+        }
+    }
+
+    thread_local! {
+        static SEEDED_STREAM: RefCell<Option<SeededStream>> = const { RefCell::new(None) };
+    }
+
+    /// Fills `bytes` from the seeded stream, returning false if no seed is set
+    pub fn fill(bytes: &mut [u8]) -> bool {
+        SEEDED_STREAM.with(|cell| {
+            let mut stream = cell.borrow_mut();
+            let Some(stream) = stream.as_mut() else {
+                return false;
+            };
+            for chunk in bytes.chunks_mut(32) {
+                let block = Sha256::new()
+                    .chain_update(stream.seed)
+                    .chain_update(stream.counter.to_be_bytes())
+                    .finalize();
+                chunk.copy_from_slice(&block[..chunk.len()]);
+                stream.counter += 1;
+            }
+            true
+        })
+    }
+
+    /// Makes key generation reproducible from a seed (test builds only)
+    ///
+    /// Only exists in builds with the `deterministic-tests` feature. After
+    /// this call, every generated mnemonic comes from a SHA-256 counter stream
+    /// keyed by the seed, so the same seed and the same sequence of calls
+    /// yield the same keys. Encryption salts and nonces still use `OsRng`.
+    ///
+    /// # Arguments
+    /// * `seed` - Any string, or undefined to return to `OsRng`
+    ///
+    /// # Example
+    /// ```javascript
+    /// set_deterministic_seed("issue-412");
+    /// const batch = generate_vanity_keypair_batch("moon", VanityPosition.Prefix, 5000, undefined); // same result every run
+    /// ```
+    #[wasm_bindgen]
+    pub fn set_deterministic_seed(seed: Option<String>) {
+        let stream = seed.map(|seed| SeededStream {
+            seed: Sha256::digest(seed.as_bytes()).into(),
+            counter: 0,
+        });
+        SEEDED_STREAM.with(|cell| *cell.borrow_mut() = stream);
+    }
+}
+
+#[cfg(feature = "deterministic-tests")]
+pub use deterministic::set_deterministic_seed;
@@ -0,0 +1,87 @@
+//! Schema versioning for emitted JSON
+//!
+//! Keypair records, watch-only bundles, encrypted blobs and the other JSON
+//! documents this crate writes all carry a `schema_version` field. Documents
+//! saved by older builds are upgraded step by step to the current schema when
+//! they are loaded, so results and backups keep working as the API grows.
+//!
+//! Formats defined by other software (Keplr keystores, Tendermint key files,
+//! amino public keys) keep their own layout and are not versioned here.
+
+use crate::error::VanityError;
+use serde_json::{json, Map, Value};
+use wasm_bindgen::prelude::*;
+
+/// Schema version of every JSON document the crate writes
+pub const SCHEMA_VERSION: u64 = 2;
+
+/// Reads a document's schema version
+///
+/// Schema 1 predates `schema_version` and marked documents with `"version": 1`.
+fn document_version(fields: &Map<String, Value>) -> Result<u64, String> {
+    let version = match (fields.get("schema_version"), fields.get("version")) {
+        (Some(version), _) => version
+            .as_u64()
+            .filter(|version| *version > 1)
+            .ok_or_else(|| format!("Invalid schema_version: {}", version))?,
+        (None, Some(version)) if version.as_u64() == Some(1) => 1,
+        (None, Some(version)) => return Err(format!("Unsupported version: {}", version)),
+        (None, None) => return Err("Missing field: schema_version".to_string()),
+    };
+
+    if version > SCHEMA_VERSION {
+        return Err(format!(
+            "Document uses schema {} but this build only reads up to schema {}; update to load it",
+            version, SCHEMA_VERSION
+        ));
+    }
+    Ok(version)
+}
+
+/// Schema 1 to 2: `version` is renamed to `schema_version`
+fn upgrade_from_v1(fields: &mut Map<String, Value>) {
+    fields.shift_remove("version");
+    fields.shift_insert(0, "schema_version".to_string(), json!(2));
+}
+
+/// Upgrades a document written by this or an older build to the current schema
+pub fn upgrade_document(mut doc: Value) -> Result<Value, String> {
+    let fields = doc.as_object_mut().ok_or("Expected a JSON object")?;
+    let mut version = document_version(fields)?;
+    while version < SCHEMA_VERSION {
+        match version {
+            1 => upgrade_from_v1(fields),
+            _ => unreachable!("every older schema has an upgrade step"),
+        }
+        version += 1;
+    }
+    Ok(doc)
+}
+
+/// Upgrades a saved JSON document to the current schema
+///
+/// Accepts anything this crate writes (keypair records, watch-only bundles,
+/// encrypted blobs, archives and address books) from this or an older build. Loading
+/// functions such as `Keypair.from_json()` already upgrade on the fly; call
+/// this to rewrite stored files so they no longer depend on the migration.
+/// Documents from a newer build are rejected rather than silently truncated.
+///
+/// # Arguments
+/// * `json` - The saved JSON document
+///
+/// # Returns
+/// * `String` - The document in the current schema as compact JSON, or an
+///   error if it is not a versioned document this build understands
+///
+/// # Example
+/// ```javascript
+/// localStorage.setItem("result", migrate_json(localStorage.getItem("result")));
+/// ```
+#[wasm_bindgen]
+pub fn migrate_json(json: &str) -> Result<String, VanityError> {
+    let doc: Value = serde_json::from_str(json)
+        .map_err(|e| VanityError::InvalidInput(format!("Invalid JSON: {}", e)))?;
+    upgrade_document(doc)
+        .map(|doc| doc.to_string())
+        .map_err(VanityError::InvalidInput)
+}
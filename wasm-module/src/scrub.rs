@@ -0,0 +1,73 @@
+//! On-demand wiping of sensitive residue
+//!
+//! Keys, seeds and mnemonics are zeroized when the values holding them are
+//! dropped, but copies made along the way (input strings from JavaScript,
+//! formatting buffers, library temporaries) are freed without being wiped and
+//! linger in WASM linear memory until reused. `scrub_memory()` overwrites as
+//! much of that residue as it can reach: it clears the RNG state, zeroes a
+//! stretch of the stack below the caller, and hands out and zeroes the heap's
+//! free blocks. It is best effort by nature, since Rust cannot address memory
+//! the allocator keeps to itself.
+
+use std::hint::black_box;
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroize;
+
+/// Bytes of stack zeroed below the caller
+const STACK_SWEEP_BYTES: usize = 32 * 1024;
+
+/// Bytes of heap handed out and zeroed per block size
+const HEAP_SWEEP_BYTES_PER_SIZE: usize = 256 * 1024;
+
+/// Block sizes swept, so free chunks of every size class are reached
+const HEAP_SWEEP_BLOCK_SIZES: [usize; 8] = [16, 64, 256, 1024, 4096, 16384, 65536, 262144];
+
+/// Zeroes stack memory that earlier, deeper calls may have left secrets in
+#[inline(never)]
+fn sweep_stack() {
+    let mut scratch = [0xFFu8; STACK_SWEEP_BYTES];
+    scratch.zeroize();
+    black_box(&scratch);
+}
+
+/// Allocates blocks of each size class, zeroes them and frees them again
+///
+/// The allocator hands out recently freed chunks first, so this overwrites
+/// the freed copies of secrets before fresh memory is touched.
+fn sweep_heap() {
+    for size in HEAP_SWEEP_BLOCK_SIZES {
+        let mut blocks: Vec<Vec<u8>> = (0..HEAP_SWEEP_BYTES_PER_SIZE / size)
+            .map(|_| Vec::with_capacity(size))
+            .collect();
+        for block in &mut blocks {
+            // Zeroes the whole capacity, not just the (empty) contents
+            block.zeroize();
+        }
+        black_box(&blocks);
+    }
+}
+
+/// Wipes sensitive residue from WASM memory
+///
+/// Call this after a search completes, after the user has saved their
+/// mnemonic, or when the page is being left. It resets the deterministic RNG
+/// seed in `deterministic-tests` builds, zeroes stack memory, and overwrites
+/// the free blocks of the heap, so secrets from dropped values do not linger.
+/// Live keypairs are not touched; call `free()` on them first.
+///
+/// This takes a few milliseconds and is best effort: it cannot reach memory
+/// the allocator has not returned, or copies JavaScript made of revealed
+/// strings.
+///
+/// # Example
+/// ```javascript
+/// keypair.free();
+/// scrub_memory();
+/// ```
+#[wasm_bindgen]
+pub fn scrub_memory() {
+    #[cfg(feature = "deterministic-tests")]
+    crate::rng::set_deterministic_seed(None);
+    sweep_heap();
+    sweep_stack();
+}
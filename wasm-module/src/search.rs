@@ -0,0 +1,250 @@
+//! Typed vanity search API
+//!
+//! The batch functions take long positional argument lists, and the results
+//! and statistics a frontend wants come from several calls. `search_vanity()`
+//! takes one spec object and returns one outcome object. Both are serde types
+//! exported with tsify, so TypeScript consumers get exact interfaces for them
+//! rather than loosely typed values.
+
+use crate::crosscheck::paranoid_checked;
+use crate::error::VanityError;
+use crate::hardened::{check_plaintext_secrets, is_hardened_mode};
+use crate::keypair_json::KeypairRecord;
+use crate::keystore::check_password;
+use crate::{
+    address_matches, check_target, estimate_vanity_attempts, hrp_or_default, random_keypair,
+    AccountScheme, VanityPosition,
+};
+use serde::{Deserialize, Deserializer, Serialize};
+use tsify_next::Tsify;
+use wasm_bindgen::prelude::*;
+
+/// Most candidates one `search_vanity()` call may generate
+const MAX_SEARCH_ATTEMPTS: u32 = 1_000_000;
+
+/// What to search for in one `search_vanity()` call
+#[derive(Tsify, Deserialize)]
+#[tsify(from_wasm_abi)]
+pub struct VanitySearchSpec {
+    /// The pattern to look for (matched case-insensitively)
+    target: String,
+    /// Where the pattern must appear (defaults to `VanityPosition.Anywhere`)
+    #[serde(default, deserialize_with = "deserialize_position")]
+    #[tsify(optional, type = "VanityPosition")]
+    position: VanityPosition,
+    /// Account scheme of the generated keys (defaults to `AccountScheme.Cosmos`)
+    #[serde(default, deserialize_with = "deserialize_scheme")]
+    #[tsify(optional, type = "AccountScheme")]
+    scheme: AccountScheme,
+    /// Bech32 prefixes to match under (defaults to `["mantra"]`)
+    #[serde(default, deserialize_with = "deserialize_hrps")]
+    #[tsify(optional)]
+    hrps: Vec<String>,
+    /// Number of candidates to generate before giving up
+    max_attempts: u32,
+    /// Password to encrypt the match's mnemonic under (at least 8 characters);
+    /// required in hardened mode, where the record carries no mnemonic
+    #[serde(default)]
+    #[tsify(optional)]
+    password: Option<String>,
+    /// true to put the mnemonic and private key in the returned record
+    /// (defaults to false; refused in hardened mode)
+    #[serde(default)]
+    #[tsify(optional)]
+    include_secrets: bool,
+}
+
+/// Statistics about one `search_vanity()` call
+#[derive(Tsify, Serialize)]
+pub struct VanitySearchStats {
+    /// Candidates generated, including the match if there was one
+    attempts: u32,
+    /// Average number of candidates a match takes for this pattern and position
+    /// under a single prefix
+    expected_attempts: f64,
+    /// Chance that a search of `attempts` candidates finds a match
+    probability: f64,
+}
+
+/// Result of one `search_vanity()` call
+#[derive(Tsify, Serialize)]
+#[tsify(into_wasm_abi)]
+pub struct VanitySearchOutcome {
+    /// The matching keypair as a `Keypair.to_json()` record, if one was found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    keypair: Option<KeypairRecord>,
+    /// The prefix the match was found under, if one was found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    hrp: Option<String>,
+    /// The match's mnemonic as an `encrypt_mnemonic()` blob, if a password was given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[tsify(optional)]
+    encrypted_mnemonic: Option<String>,
+    /// Search statistics
+    stats: VanitySearchStats,
+}
+
+/// Reads a `VanityPosition` from its numeric JavaScript enum value
+fn deserialize_position<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<VanityPosition, D::Error> {
+    match Option::<u32>::deserialize(deserializer)? {
+        None => Ok(VanityPosition::default()),
+        Some(0) => Ok(VanityPosition::Anywhere),
+        Some(1) => Ok(VanityPosition::Prefix),
+        Some(2) => Ok(VanityPosition::Suffix),
+        Some(3) => Ok(VanityPosition::BeforeChecksum),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "Unknown vanity position: {}",
+            other
+        ))),
+    }
+}
+
+/// Reads an `AccountScheme` from its numeric JavaScript enum value
+fn deserialize_scheme<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<AccountScheme, D::Error> {
+    match Option::<u32>::deserialize(deserializer)? {
+        None => Ok(AccountScheme::default()),
+        Some(0) => Ok(AccountScheme::Cosmos),
+        Some(1) => Ok(AccountScheme::EthSecp256k1),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "Unknown account scheme: {}",
+            other
+        ))),
+    }
+}
+
+/// Reads the prefix list, treating `undefined` or `null` as empty
+fn deserialize_hrps<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<String>, D::Error> {
+    Ok(Option::<Vec<String>>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Runs a vanity search described by a spec object
+///
+/// Generates up to `max_attempts` keypairs and checks each one's address
+/// under every prefix in `hrps`, stopping at the first match. The outcome
+/// always carries statistics; the keypair is present only on a match and can
+/// be turned into a `Keypair` with `Keypair.from_json(JSON.stringify(...))`.
+///
+/// The record leaves the mnemonic and private key out unless the spec sets
+/// `include_secrets`, which is refused in hardened mode and reported to the
+/// hook registered with `set_export_audit_hook()`. With a `password` in the
+/// spec, the match's mnemonic is also returned as an `encrypt_mnemonic()`
+/// blob. A match must be recoverable, so one of the two is required.
+///
+/// # Arguments
+/// * `spec` - The search spec (`target`, `max_attempts` and the optional
+///   `position`, `scheme`, `hrps`, `password` and `include_secrets`)
+///
+/// # Returns
+/// * `VanitySearchOutcome` - The match (if any) and search statistics, or a
+///   `VanityError` for an invalid spec, a weak or missing password, secrets
+///   requested in hardened mode, a failed audit hook or failed generation
+///
+/// # Example
+/// ```javascript
+/// const outcome = search_vanity({ target: "moon", position: VanityPosition.Prefix, max_attempts: 5000, include_secrets: true });
+/// if (outcome.keypair) save(Keypair.from_json(JSON.stringify(outcome.keypair)));
+/// console.log(`${outcome.stats.attempts} tried, ${outcome.stats.expected_attempts} expected`);
+///
+/// // Hardened mode: keep the match as an encrypted blob
+/// const sealed = search_vanity({ target: "moon", max_attempts: 5000, password });
+/// if (sealed.encrypted_mnemonic) localStorage.setItem("vanity-result", sealed.encrypted_mnemonic);
+/// ```
+#[wasm_bindgen]
+pub fn search_vanity(spec: VanitySearchSpec) -> Result<VanitySearchOutcome, VanityError> {
+    if spec.target.is_empty() {
+        return Err(VanityError::InvalidInput(
+            "Target must not be empty".to_string(),
+        ));
+    }
+    if spec.max_attempts == 0 || spec.max_attempts > MAX_SEARCH_ATTEMPTS {
+        return Err(VanityError::InvalidInput(format!(
+            "max_attempts must be between 1 and {}",
+            MAX_SEARCH_ATTEMPTS
+        )));
+    }
+
+    if spec.include_secrets {
+        check_plaintext_secrets("a search result with secrets")?;
+    }
+    match spec.password.as_deref() {
+        Some(password) => check_password(password)?,
+        // Without a password a hardened match could never be recovered
+        None if is_hardened_mode() => {
+            return Err(VanityError::SecretsDisabled(
+                "Hardened mode is on; pass a password so a match can be returned encrypted"
+                    .to_string(),
+            ));
+        }
+        // Without either the match could never be recovered
+        None if !spec.include_secrets => {
+            return Err(VanityError::InvalidInput(
+                "Pass a password or include_secrets so a match can be recovered".to_string(),
+            ));
+        }
+        _ => {}
+    }
+
+    let target = check_target(&spec.target)?;
+    let hrps: Vec<String> = if spec.hrps.is_empty() {
+        vec![hrp_or_default(None)]
+    } else {
+        spec.hrps
+            .into_iter()
+            .map(|hrp| hrp_or_default(Some(hrp)))
+            .collect()
+    };
+    let expected_attempts = estimate_vanity_attempts(target.len() as u32, spec.position);
+
+    let mut found = None;
+    let mut attempts = 0;
+    'search: while attempts < spec.max_attempts {
+        attempts += 1;
+        let mut keypair = random_keypair(spec.scheme, &hrps[0])?;
+        let Some(public_key) = keypair.public_key else {
+            continue;
+        };
+
+        for hrp in &hrps {
+            let address = spec.scheme.address(&public_key, hrp).map_err(|e| {
+                VanityError::DerivationFailed(format!("Error encoding address: {}", e))
+            })?;
+            if address_matches(&address, &target, spec.position) {
+                keypair.address = address;
+                let keypair = paranoid_checked(keypair)?;
+                let encrypted_mnemonic = spec
+                    .password
+                    .as_deref()
+                    .map(|password| keypair.encrypt_mnemonic(password, None))
+                    .transpose()?;
+                let record = if spec.include_secrets {
+                    keypair.to_secret_record("search_vanity")?
+                } else {
+                    keypair.to_public_record()
+                };
+                found = Some((record, hrp.clone(), encrypted_mnemonic));
+                break 'search;
+            }
+        }
+    }
+
+    let (keypair, hrp, encrypted_mnemonic) = match found {
+        Some((keypair, hrp, encrypted_mnemonic)) => (Some(keypair), Some(hrp), encrypted_mnemonic),
+        None => (None, None, None),
+    };
+    Ok(VanitySearchOutcome {
+        keypair,
+        hrp,
+        encrypted_mnemonic,
+        stats: VanitySearchStats {
+            attempts,
+            expected_attempts,
+            probability: 1.0 - (1.0 - 1.0 / expected_attempts).powf(attempts as f64),
+        },
+    })
+}
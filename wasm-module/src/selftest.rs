@@ -0,0 +1,166 @@
+//! Known-answer self-test of the cryptographic primitives
+//!
+//! A WASM binary corrupted in transit or miscompiled can still load and
+//! produce well-formed addresses that no wallet derives. Before anything is
+//! generated, the primitives every address depends on are checked against
+//! published test vectors: SHA-256, SHA-512 and HMAC-SHA512 (FIPS 180 and
+//! RFC 4231), RIPEMD-160, bech32 (BIP173) and one complete mnemonic-to-address
+//! derivation. The test runs once at module start and can be re-run with
+//! `self_test()`.
+
+use crate::address::encode_bech32;
+use crate::vectors::check_first_vector;
+use hmac::{Hmac, Mac};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256, Sha512};
+use std::sync::OnceLock;
+use wasm_bindgen::prelude::*;
+
+/// Outcome of the known-answer self-test
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct SelfTestReport {
+    tests_run: u32,
+    failures: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl SelfTestReport {
+    /// Gets whether every known-answer test passed
+    #[wasm_bindgen(getter)]
+    pub fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Gets the number of known-answer tests run
+    #[wasm_bindgen(getter)]
+    pub fn tests_run(&self) -> u32 {
+        self.tests_run
+    }
+
+    /// Gets a description of each failed test
+    #[wasm_bindgen(getter)]
+    pub fn failures(&self) -> Vec<String> {
+        self.failures.clone()
+    }
+}
+
+/// A single known-answer test, returning a description on failure
+type KnownAnswerTest = fn() -> Result<(), String>;
+
+/// Result of the self-test run at module start
+static STARTUP_REPORT: OnceLock<SelfTestReport> = OnceLock::new();
+
+/// Compares computed bytes against an expected hex string
+fn expect_hex(actual: &[u8], expected: &str) -> Result<(), String> {
+    let actual = hex::encode(actual);
+    if actual != expected {
+        return Err(format!("got {}, expected {}", actual, expected));
+    }
+    Ok(())
+}
+
+/// SHA-256("abc") from FIPS 180-2
+fn sha256_test() -> Result<(), String> {
+    expect_hex(
+        &Sha256::digest(b"abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+    )
+}
+
+/// SHA-512("abc") from FIPS 180-2
+fn sha512_test() -> Result<(), String> {
+    expect_hex(
+        &Sha512::digest(b"abc"),
+        "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+         2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f",
+    )
+}
+
+/// HMAC-SHA512 test case 2 from RFC 4231
+fn hmac_sha512_test() -> Result<(), String> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(b"Jefe").map_err(|e| e.to_string())?;
+    mac.update(b"what do ya want for nothing?");
+    expect_hex(
+        &mac.finalize().into_bytes(),
+        "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554\
+         9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737",
+    )
+}
+
+/// RIPEMD-160("abc") from the RIPEMD-160 reference
+fn ripemd160_test() -> Result<(), String> {
+    expect_hex(
+        &Ripemd160::digest(b"abc"),
+        "8eb208f7e05d987a9b044a8e98c6b087f15a0bfc",
+    )
+}
+
+/// A BIP173 checksum vector and a Cosmos account ID encoding
+fn bech32_test() -> Result<(), String> {
+    bech32::decode("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw")
+        .map_err(|e| format!("BIP173 vector rejected: {}", e))?;
+
+    let account_id =
+        hex::decode("28ff5c6d57d8cfd492b6fb42614536ed648e01fd").map_err(|e| e.to_string())?;
+    let address = encode_bech32("cosmos", &account_id).map_err(|e| e.to_string())?;
+    if address != "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4" {
+        return Err(format!("encoded {}", address));
+    }
+    Ok(())
+}
+
+/// Runs every known-answer test
+fn run_self_test() -> SelfTestReport {
+    let tests: [(&str, KnownAnswerTest); 6] = [
+        ("SHA-256", sha256_test),
+        ("SHA-512", sha512_test),
+        ("HMAC-SHA512", hmac_sha512_test),
+        ("RIPEMD-160", ripemd160_test),
+        ("bech32", bech32_test),
+        ("mnemonic derivation", check_first_vector),
+    ];
+    SelfTestReport {
+        tests_run: tests.len() as u32,
+        failures: tests
+            .iter()
+            .filter_map(|(name, test)| test().err().map(|e| format!("{}: {}", name, e)))
+            .collect(),
+    }
+}
+
+/// Runs the self-test once, at module start
+pub fn startup_self_test() -> &'static SelfTestReport {
+    STARTUP_REPORT.get_or_init(run_self_test)
+}
+
+/// Runs the known-answer self-test again and reports the result
+///
+/// The same tests already ran when the module started (see
+/// `startup_self_test_passed()`); re-running them catches memory corruption
+/// that happened since. A build that fails any of them must not be trusted to
+/// generate keys.
+///
+/// # Returns
+/// * `SelfTestReport` - Whether all tests passed, and which failed
+///
+/// # Example
+/// ```javascript
+/// const report = self_test();
+/// if (!report.passed) throw new Error(`WASM self-test failed: ${report.failures.join("; ")}`);
+/// ```
+#[wasm_bindgen]
+pub fn self_test() -> SelfTestReport {
+    run_self_test()
+}
+
+/// Gets whether the self-test run at module start passed
+///
+/// Runs the test first if the module was loaded without its start function.
+///
+/// # Returns
+/// * `bool` - true if every known-answer test passed at start-up
+#[wasm_bindgen]
+pub fn startup_self_test_passed() -> bool {
+    startup_self_test().passed()
+}
@@ -0,0 +1,502 @@
+//! Offline message signing
+//!
+//! Proving control of a vanity address (for an OTC deal or a support ticket)
+//! should not require importing its key into a wallet. ADR-36 defines how
+//! Cosmos wallets sign arbitrary data: the message is wrapped in an amino
+//! `sign/MsgSignData` sign doc with an empty chain ID, fee and memo, and that
+//! doc is signed like a transaction. Keplr's `signArbitrary()` produces the
+//! same signatures, and `verify_signature()` checks them as well as plain
+//! secp256k1 signatures over the raw message.
+
+use crate::address::{decode_bech32, evm_account_id, public_key_to_address, MANTRA_HRP};
+use crate::error::VanityError;
+use crate::hd::{hash160, parse_derivation_path};
+use crate::mnemonic::parse_mnemonic;
+use crate::multisig::parse_compressed_pubkey;
+use crate::schema::SCHEMA_VERSION;
+use crate::{
+    derive_account_key, derive_key_at_path, hrp_or_default, strip_hex_prefix, AccountScheme,
+};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use k256::ecdsa::signature::hazmat::PrehashVerifier;
+use k256::ecdsa::signature::{Signer, Verifier};
+use k256::ecdsa::{Signature, SigningKey, VerifyingKey};
+use serde_json::{json, Value};
+use sha3::{Digest, Keccak256};
+use wasm_bindgen::prelude::*;
+use zeroize::Zeroizing;
+
+/// Amino type of a secp256k1 public key in a `StdSignature`
+const SECP256K1_PUBKEY_TYPE: &str = "tendermint/PubKeySecp256k1";
+
+/// An ADR-36 signature over an arbitrary message
+#[wasm_bindgen]
+pub struct Adr36Signature {
+    signer: String,
+    data: Vec<u8>,
+    public_key: [u8; 33],
+    signature: [u8; 64],
+}
+
+#[wasm_bindgen]
+impl Adr36Signature {
+    /// Gets the bech32 address of the signer
+    #[wasm_bindgen(getter)]
+    pub fn signer(&self) -> String {
+        self.signer.clone()
+    }
+
+    /// Gets the signer's compressed public key as base64
+    #[wasm_bindgen(getter)]
+    pub fn public_key_base64(&self) -> String {
+        BASE64_STANDARD.encode(self.public_key)
+    }
+
+    /// Gets the 64-byte `r || s` signature as base64
+    #[wasm_bindgen(getter)]
+    pub fn signature_base64(&self) -> String {
+        BASE64_STANDARD.encode(self.signature)
+    }
+
+    /// Gets the amino sign doc that was signed, as canonical JSON
+    #[wasm_bindgen(getter)]
+    pub fn sign_doc(&self) -> String {
+        adr36_sign_doc(&self.signer, &self.data)
+    }
+
+    /// Gets the signature as a JSON envelope
+    ///
+    /// The envelope holds the signer, the message as base64 (`data`) and the
+    /// `pub_key`/`signature` pair of a Cosmos `StdSignature`, so it can be
+    /// passed to Keplr's `verifyArbitrary()` as well as `verify_signature()`.
+    ///
+    /// # Returns
+    /// * `String` - The envelope JSON, or an error if serialization fails
+    ///
+    /// # Example
+    /// ```javascript
+    /// download("proof.json", sign_adr36(mnemonic, "I own this address", undefined).to_json());
+    /// ```
+    pub fn to_json(&self) -> Result<String, VanityError> {
+        serde_json::to_string_pretty(&json!({
+            "schema_version": SCHEMA_VERSION,
+            "signer": self.signer,
+            "data": BASE64_STANDARD.encode(&self.data),
+            "pub_key": {
+                "type": SECP256K1_PUBKEY_TYPE,
+                "value": self.public_key_base64(),
+            },
+            "signature": self.signature_base64(),
+        }))
+        .map_err(|e| VanityError::DerivationFailed(format!("Error serializing signature: {}", e)))
+    }
+}
+
+/// Builds the canonical ADR-36 amino sign doc for a signer and message
+///
+/// Keys are in sorted order, as amino JSON signing requires.
+pub fn adr36_sign_doc(signer: &str, data: &[u8]) -> String {
+    json!({
+        "account_number": "0",
+        "chain_id": "",
+        "fee": { "amount": [], "gas": "0" },
+        "memo": "",
+        "msgs": [{
+            "type": "sign/MsgSignData",
+            "value": { "data": BASE64_STANDARD.encode(data), "signer": signer },
+        }],
+        "sequence": "0",
+    })
+    .to_string()
+}
+
+/// Reads a signing key from a mnemonic (standard Cosmos path) or a raw hex key
+pub fn signing_key_from_input(mnemonic_or_key: &str) -> Result<SigningKey, VanityError> {
+    let key_hex = strip_hex_prefix(mnemonic_or_key);
+    if key_hex.len() == 64 && key_hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        let bytes = Zeroizing::new(
+            hex::decode(key_hex)
+                .map_err(|e| VanityError::InvalidKey(format!("Invalid private key: {}", e)))?,
+        );
+        return SigningKey::from_slice(&bytes).map_err(|_| {
+            VanityError::InvalidKey(
+                "Invalid private key: scalar is zero or out of range".to_string(),
+            )
+        });
+    }
+
+    let mnemonic = parse_mnemonic(mnemonic_or_key)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    let derived = derive_account_key(&mnemonic)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving key: {}", e)))?;
+    SigningKey::from_slice(&*Zeroizing::new(derived.private_key_bytes()))
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving key: {}", e)))
+}
+
+/// Gets the compressed public key of a signing key
+pub fn compressed_public_key(signing_key: &SigningKey) -> [u8; 33] {
+    let mut public_key = [0u8; 33];
+    public_key.copy_from_slice(
+        signing_key
+            .verifying_key()
+            .to_encoded_point(true)
+            .as_bytes(),
+    );
+    public_key
+}
+
+/// Signs a message with ADR-36, entirely offline
+///
+/// The key is the account at m/44'/118'/0'/0/0 when a mnemonic is given, or
+/// the raw key itself when 32 bytes of hex are given. The signer address is
+/// derived from that key with the Cosmos scheme. The signature is a low-S
+/// secp256k1 ECDSA signature over SHA-256 of the sign doc, as Keplr makes.
+///
+/// # Arguments
+/// * `mnemonic_or_key` - A BIP39 mnemonic or a hex private key (optional `0x` prefix)
+/// * `message` - The text to sign
+/// * `hrp` - Optional bech32 prefix of the signer address (defaults to "mantra")
+///
+/// # Returns
+/// * `Adr36Signature` - The signature with signer and public key, or an error
+///   for an invalid mnemonic or key
+///
+/// # Example
+/// ```javascript
+/// const proof = sign_adr36(mnemonic, "Selling mantra1moon... to @alice, 2025-06-01", undefined);
+/// console.log(proof.signer, proof.signature_base64);
+/// ```
+#[wasm_bindgen]
+pub fn sign_adr36(
+    mnemonic_or_key: &str,
+    message: &str,
+    hrp: Option<String>,
+) -> Result<Adr36Signature, VanityError> {
+    let signing_key = signing_key_from_input(mnemonic_or_key)?;
+    let public_key = compressed_public_key(&signing_key);
+    let signer = public_key_to_address(&public_key, &hrp_or_default(hrp))
+        .map_err(|e| VanityError::DerivationFailed(format!("Error encoding address: {}", e)))?;
+
+    let data = message.as_bytes().to_vec();
+    let signature: Signature = signing_key.sign(adr36_sign_doc(&signer, &data).as_bytes());
+    Ok(Adr36Signature {
+        signer,
+        data,
+        public_key,
+        signature: signature.to_bytes().into(),
+    })
+}
+
+/// Result of checking a signature with `verify_signature()`
+#[wasm_bindgen]
+pub struct SignatureVerification {
+    valid: bool,
+    error: Option<String>,
+    format: Option<String>,
+    signer: Option<String>,
+}
+
+#[wasm_bindgen]
+impl SignatureVerification {
+    /// Gets whether the signature is valid for the message and key
+    #[wasm_bindgen(getter)]
+    pub fn valid(&self) -> bool {
+        self.valid
+    }
+
+    /// Gets why the signature was rejected
+    #[wasm_bindgen(getter)]
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    /// Gets what was signed: "adr36" for an ADR-36 sign doc, "raw" for the
+    /// message itself (SHA-256 of its bytes), once the signature is valid
+    #[wasm_bindgen(getter)]
+    pub fn format(&self) -> Option<String> {
+        self.format.clone()
+    }
+
+    /// Gets the bech32 address the signature was checked against
+    #[wasm_bindgen(getter)]
+    pub fn signer(&self) -> Option<String> {
+        self.signer.clone()
+    }
+}
+
+/// A signature with whatever an envelope says about the signer
+struct ParsedSignature {
+    signature: Signature,
+    public_key: Option<[u8; 33]>,
+    signer: Option<String>,
+    is_envelope: bool,
+}
+
+/// Decodes a 64-byte `r || s` or DER signature given as hex or base64
+fn decode_signature(input: &str) -> Result<Signature, String> {
+    let input = input.trim();
+    let bytes = if input.len().is_multiple_of(2) && input.chars().all(|c| c.is_ascii_hexdigit()) {
+        hex::decode(input).map_err(|e| e.to_string())?
+    } else {
+        BASE64_STANDARD
+            .decode(input)
+            .map_err(|_| "Signature is neither hex nor base64".to_string())?
+    };
+
+    let signature = if bytes.len() == 64 {
+        Signature::from_slice(&bytes)
+    } else {
+        Signature::from_der(&bytes)
+    }
+    .map_err(|_| "Signature is not a valid secp256k1 signature".to_string())?;
+    // A high-S signature is as valid as its low-S twin; accept either form
+    Ok(signature.normalize_s().unwrap_or(signature))
+}
+
+/// Parses a bare signature or an ADR-36 / `StdSignature` JSON envelope
+fn parse_signature(input: &str, message: &str) -> Result<ParsedSignature, String> {
+    if !input.trim_start().starts_with('{') {
+        return Ok(ParsedSignature {
+            signature: decode_signature(input)?,
+            public_key: None,
+            signer: None,
+            is_envelope: false,
+        });
+    }
+
+    let envelope: Value =
+        serde_json::from_str(input).map_err(|e| format!("Invalid signature JSON: {}", e))?;
+    let signature = envelope["signature"]
+        .as_str()
+        .ok_or("Missing or invalid field: signature")?;
+    let public_key = envelope["pub_key"]["value"]
+        .as_str()
+        .map(|value| parse_compressed_pubkey(value).map_err(|e| e.to_string()))
+        .transpose()?;
+    if let Some(data) = envelope["data"].as_str() {
+        if data != BASE64_STANDARD.encode(message) {
+            return Err("Envelope data does not match the message".to_string());
+        }
+    }
+
+    Ok(ParsedSignature {
+        signature: decode_signature(signature)?,
+        public_key,
+        signer: envelope["signer"].as_str().map(str::to_string),
+        is_envelope: true,
+    })
+}
+
+/// Checks that a public key is the key behind an address, returning the
+/// scheme (Cosmos or ethsecp256k1) the address was encoded under
+fn check_key_owns_address(public_key: &[u8; 33], address: &str) -> Result<AccountScheme, String> {
+    let (_, account_id) =
+        decode_bech32(address).map_err(|e| format!("Invalid address {}: {}", address, e))?;
+    if account_id == hash160(public_key) {
+        return Ok(AccountScheme::Cosmos);
+    }
+    if account_id == evm_account_id(public_key).map_err(|e| e.to_string())? {
+        return Ok(AccountScheme::EthSecp256k1);
+    }
+    Err(format!("Public key does not belong to {}", address))
+}
+
+/// Checks a signature over `bytes` with the scheme's message digest:
+/// SHA-256 for Cosmos, keccak256 for ethsecp256k1
+fn verify_with_scheme(
+    verifying_key: &VerifyingKey,
+    scheme: AccountScheme,
+    bytes: &[u8],
+    signature: &Signature,
+) -> bool {
+    match scheme {
+        AccountScheme::Cosmos => verifying_key.verify(bytes, signature).is_ok(),
+        AccountScheme::EthSecp256k1 => verifying_key
+            .verify_prehash(&Keccak256::digest(bytes), signature)
+            .is_ok(),
+    }
+}
+
+/// Checks a signature, returning what was signed and by which address
+fn check_signature(
+    address_or_pubkey: &str,
+    message: &str,
+    signature: &str,
+) -> Result<(&'static str, String), String> {
+    let parsed = parse_signature(signature, message)?;
+    let address_or_pubkey = address_or_pubkey.trim();
+    let (address, given_key) = match decode_bech32(address_or_pubkey) {
+        Ok(_) => (Some(address_or_pubkey.to_lowercase()), None),
+        Err(_) => (
+            None,
+            Some(parse_compressed_pubkey(address_or_pubkey).map_err(|e| e.to_string())?),
+        ),
+    };
+
+    let public_key = match (given_key, parsed.public_key) {
+        (Some(given), Some(embedded)) if given != embedded => {
+            return Err("Public key does not match the one in the signature envelope".to_string())
+        }
+        (Some(key), _) | (None, Some(key)) => key,
+        (None, None) => {
+            return Err(
+                "Checking against an address needs the public key: pass it instead, or a signature envelope with pub_key"
+                    .to_string(),
+            )
+        }
+    };
+    let (signer, scheme) = match address.or(parsed.signer) {
+        Some(signer) => {
+            let scheme = check_key_owns_address(&public_key, &signer)?;
+            (signer, scheme)
+        }
+        None => (
+            public_key_to_address(&public_key, MANTRA_HRP).map_err(|e| e.to_string())?,
+            AccountScheme::Cosmos,
+        ),
+    };
+
+    let verifying_key = VerifyingKey::from_sec1_bytes(&public_key)
+        .map_err(|_| "Invalid public key: not a valid secp256k1 point".to_string())?;
+    let sign_doc = adr36_sign_doc(&signer, message.as_bytes());
+    if verify_with_scheme(
+        &verifying_key,
+        scheme,
+        sign_doc.as_bytes(),
+        &parsed.signature,
+    ) {
+        return Ok(("adr36", signer));
+    }
+    if !parsed.is_envelope
+        && verify_with_scheme(
+            &verifying_key,
+            scheme,
+            message.as_bytes(),
+            &parsed.signature,
+        )
+    {
+        return Ok(("raw", signer));
+    }
+    Err("Signature does not match the message and key".to_string())
+}
+
+/// Verifies a secp256k1 signature over a message
+///
+/// Accepts ADR-36 signatures (as made by `sign_adr36()` or Keplr's
+/// `signArbitrary()`) and plain signatures over the message bytes. The digest
+/// follows the signer's account scheme: SHA-256 for Cosmos addresses and
+/// keccak256 for ethsecp256k1 addresses, as Ethermint-based wallets sign.
+/// The signature can be bare (64-byte `r || s` or DER, as hex or base64) or a
+/// JSON envelope with `signature` and `pub_key` such as `Adr36Signature.to_json()`
+/// or a Keplr `StdSignature`; envelopes are only checked as ADR-36.
+///
+/// When an address is given, the public key must come from the envelope and
+/// must hash to that address. When a public key is given, the ADR-36 signer
+/// is the envelope's `signer`, or else the key's "mantra" address.
+///
+/// # Arguments
+/// * `address_or_pubkey` - The signer's bech32 address, or compressed public key as hex or base64
+/// * `message` - The signed text
+/// * `signature` - The signature, bare or as a JSON envelope
+///
+/// # Returns
+/// * `SignatureVerification` - Whether the signature is valid, with the reason if not
+///
+/// # Example
+/// ```javascript
+/// const result = verify_signature("mantra1moon...", "I own this address", proofJson);
+/// if (!result.valid) console.warn(result.error);
+/// ```
+#[wasm_bindgen]
+pub fn verify_signature(
+    address_or_pubkey: &str,
+    message: &str,
+    signature: &str,
+) -> SignatureVerification {
+    match check_signature(address_or_pubkey, message, signature) {
+        Ok((format, signer)) => SignatureVerification {
+            valid: true,
+            error: None,
+            format: Some(format.to_string()),
+            signer: Some(signer),
+        },
+        Err(error) => SignatureVerification {
+            valid: false,
+            error: Some(error),
+            format: None,
+            signer: None,
+        },
+    }
+}
+
+/// A signature over caller-supplied bytes, with the signing public key
+#[wasm_bindgen]
+pub struct ByteSignature {
+    signature: Signature,
+    public_key: [u8; 33],
+}
+
+#[wasm_bindgen]
+impl ByteSignature {
+    /// Gets the 64-byte compact `r || s` signature as hex
+    #[wasm_bindgen(getter)]
+    pub fn signature_hex(&self) -> String {
+        hex::encode(self.signature.to_bytes())
+    }
+
+    /// Gets the ASN.1 DER-encoded signature as hex
+    #[wasm_bindgen(getter)]
+    pub fn signature_der_hex(&self) -> String {
+        hex::encode(self.signature.to_der().as_bytes())
+    }
+
+    /// Gets the compressed public key of the signing key as hex
+    #[wasm_bindgen(getter)]
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.public_key)
+    }
+}
+
+/// Signs arbitrary bytes with the key at a derivation path
+///
+/// The signature is a deterministic (RFC 6979), low-S secp256k1 ECDSA
+/// signature over SHA-256 of `bytes`; no sign doc or prefix is added. This is
+/// a building block for custom signing flows and tests, so only pass bytes
+/// you have checked: a transaction's sign bytes are accepted like any other.
+/// When the bytes are UTF-8 text, `verify_signature()` checks the result as
+/// a "raw" signature.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `path` - The BIP32 path string (e.g. `m/44'/118'/0'/0/0`)
+/// * `bytes` - The data to sign
+///
+/// # Returns
+/// * `ByteSignature` - The compact and DER signature and the public key, or an
+///   error for an invalid mnemonic or path
+///
+/// # Example
+/// ```javascript
+/// const sig = sign_bytes(mnemonic, "m/44'/118'/0'/0/0", new TextEncoder().encode("payload"));
+/// console.log(sig.signature_hex, sig.public_key_hex);
+/// ```
+#[wasm_bindgen]
+pub fn sign_bytes(
+    mnemonic_str: &str,
+    path: &str,
+    bytes: &[u8],
+) -> Result<ByteSignature, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    let indexes = parse_derivation_path(path)
+        .map_err(|e| VanityError::InvalidPath(format!("Invalid derivation path: {}", e)))?;
+    let derived = derive_key_at_path(&mnemonic, &indexes)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving key: {}", e)))?;
+    let signing_key = SigningKey::from_slice(&*Zeroizing::new(derived.private_key_bytes()))
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving key: {}", e)))?;
+
+    Ok(ByteSignature {
+        signature: signing_key.sign(bytes),
+        public_key: compressed_public_key(&signing_key),
+    })
+}
@@ -0,0 +1,334 @@
+//! Visual similarity of addresses
+//!
+//! Address poisoning attacks send dust from an address whose first and last
+//! characters match one the victim uses, hoping it gets copied from history.
+//! Vanity tools make such look-alikes cheap to produce, so this module scores
+//! how alike two addresses look to someone who only checks their ends. It
+//! also screens vanity matches whose pattern blends into its neighbours.
+
+use crate::crosscheck::paranoid_checked;
+use crate::error::VanityError;
+use crate::{
+    address_matches, check_target, hrp_or_default, random_keypair, AccountScheme, Keypair,
+    VanityPosition,
+};
+use wasm_bindgen::prelude::*;
+
+/// Groups of bech32 characters that are easily mistaken for one another
+pub const CONFUSABLE_GROUPS: [&str; 7] = ["9gq", "uv", "2z", "5s", "38", "mn", "7l"];
+
+/// Shared run length at both ends from which two addresses count as look-alikes
+const LOOK_ALIKE_RUN: u32 = 4;
+
+/// Checks whether two characters are different but visually confusable
+pub fn confusable(a: char, b: char) -> bool {
+    a != b
+        && CONFUSABLE_GROUPS
+            .iter()
+            .any(|group| group.contains(a) && group.contains(b))
+}
+
+/// How alike two addresses look
+#[wasm_bindgen]
+pub struct AddressSimilarity {
+    score: f64,
+    shared_prefix: u32,
+    shared_suffix: u32,
+    confusable_characters: u32,
+    look_alike: bool,
+}
+
+#[wasm_bindgen]
+impl AddressSimilarity {
+    /// Gets the share of the data part that looks identical when read from both ends (0 to 1)
+    #[wasm_bindgen(getter)]
+    pub fn score(&self) -> f64 {
+        self.score
+    }
+
+    /// Gets the number of leading data characters that look the same
+    #[wasm_bindgen(getter)]
+    pub fn shared_prefix(&self) -> u32 {
+        self.shared_prefix
+    }
+
+    /// Gets the number of trailing characters that look the same
+    #[wasm_bindgen(getter)]
+    pub fn shared_suffix(&self) -> u32 {
+        self.shared_suffix
+    }
+
+    /// Gets how many of the shared characters only match because they are confusable
+    #[wasm_bindgen(getter)]
+    pub fn confusable_characters(&self) -> u32 {
+        self.confusable_characters
+    }
+
+    /// Gets whether the addresses differ but share at least 4 look-alike characters at both ends
+    #[wasm_bindgen(getter)]
+    pub fn look_alike(&self) -> bool {
+        self.look_alike
+    }
+}
+
+/// Compares how alike two addresses look
+///
+/// Only the data parts (after the `1` separator) are compared, case-insensitively.
+/// Characters match when they are equal or belong to the same confusable
+/// group (such as `q`/`g`/`9` or `u`/`v`). The shared runs at the start and
+/// end are measured, since those are the characters people actually check.
+///
+/// # Arguments
+/// * `a` - The first address
+/// * `b` - The second address
+///
+/// # Returns
+/// * `AddressSimilarity` - Shared run lengths, confusable count, score and look-alike verdict
+///
+/// # Example
+/// ```javascript
+/// const similarity = address_similarity(recipient, knownContact);
+/// if (similarity.look_alike) warn("This address only resembles your contact");
+/// ```
+#[wasm_bindgen]
+pub fn address_similarity(a: &str, b: &str) -> AddressSimilarity {
+    let a: Vec<char> = data_part(a).chars().collect();
+    let b: Vec<char> = data_part(b).chars().collect();
+    let looks_same = |x: char, y: char| x == y || confusable(x, y);
+
+    let shared_prefix = a
+        .iter()
+        .zip(&b)
+        .take_while(|(x, y)| looks_same(**x, **y))
+        .count();
+    let shared_suffix = a
+        .iter()
+        .rev()
+        .zip(b.iter().rev())
+        .take_while(|(x, y)| looks_same(**x, **y))
+        .count();
+
+    // For identical-looking addresses both runs cover everything; count each character once
+    let shortest = a.len().min(b.len());
+    let covered = (shared_prefix + shared_suffix).min(shortest);
+    let confusable_characters = a
+        .iter()
+        .zip(&b)
+        .take(shared_prefix)
+        .chain(
+            a.iter()
+                .rev()
+                .zip(b.iter().rev())
+                .take(covered - shared_prefix),
+        )
+        .filter(|(x, y)| confusable(**x, **y))
+        .count();
+    let longest = a.len().max(b.len());
+    let score = if longest == 0 {
+        1.0
+    } else {
+        covered as f64 / longest as f64
+    };
+
+    AddressSimilarity {
+        score,
+        shared_prefix: shared_prefix as u32,
+        shared_suffix: shared_suffix as u32,
+        confusable_characters: confusable_characters as u32,
+        look_alike: a != b
+            && shared_prefix as u32 >= LOOK_ALIKE_RUN
+            && shared_suffix as u32 >= LOOK_ALIKE_RUN,
+    }
+}
+
+/// Returns the lowercased data part of an address, or the whole string if it has no separator
+fn data_part(address: &str) -> String {
+    let lower = address.trim().to_lowercase();
+    match lower.rfind('1') {
+        Some(separator) => lower[separator + 1..].to_string(),
+        None => lower,
+    }
+}
+
+/// Number of trailing checksum characters in a bech32 address
+const CHECKSUM_LENGTH: usize = 6;
+
+/// Outcome of screening a vanity match for ambiguous surroundings
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct ScreeningVerdict {
+    passed: bool,
+    reason: Option<String>,
+}
+
+#[wasm_bindgen]
+impl ScreeningVerdict {
+    /// Gets whether the pattern stands out clearly from its neighbours
+    #[wasm_bindgen(getter)]
+    pub fn passed(&self) -> bool {
+        self.passed
+    }
+
+    /// Gets why the match was flagged, if it was
+    #[wasm_bindgen(getter)]
+    pub fn reason(&self) -> Option<String> {
+        self.reason.clone()
+    }
+}
+
+/// Screens a vanity match for surroundings that make the pattern ambiguous
+///
+/// A pattern is flagged when the character just before it repeats or is
+/// confusable with its first character, or the character just after it does
+/// so with its last character (e.g. "moon" in `...nmoonn...`). Such matches are
+/// easy to imitate, since a look-alike only needs the pattern shifted by one.
+///
+/// # Arguments
+/// * `address` - The matching bech32 address
+/// * `target` - The vanity pattern
+/// * `position` - Where the pattern was required to appear
+///
+/// # Returns
+/// * `ScreeningVerdict` - Whether the match passed, with the reason if it did not
+///
+/// # Example
+/// ```javascript
+/// const verdict = screen_vanity_match(keypair.address, "moon", VanityPosition.Prefix);
+/// if (!verdict.passed) console.warn(verdict.reason);
+/// ```
+#[wasm_bindgen]
+pub fn screen_vanity_match(
+    address: &str,
+    target: &str,
+    position: VanityPosition,
+) -> ScreeningVerdict {
+    let data: Vec<char> = data_part(address).chars().collect();
+    let pattern: Vec<char> = target.to_lowercase().chars().collect();
+    let (Some(&first), Some(&last)) = (pattern.first(), pattern.last()) else {
+        return ScreeningVerdict::flagged("Pattern is empty");
+    };
+    let Some(start) = match_start(&data, &pattern, position) else {
+        return ScreeningVerdict::flagged("Pattern does not appear at the requested position");
+    };
+    let end = start + pattern.len();
+    let blends = |neighbour: char, edge: char| neighbour == edge || confusable(neighbour, edge);
+
+    if let Some(&before) = start.checked_sub(1).and_then(|index| data.get(index)) {
+        if blends(before, first) {
+            return ScreeningVerdict::flagged(format!(
+                "Pattern is preceded by {:?}, which blends with its first character {:?}",
+                before, first
+            ));
+        }
+    }
+    if let Some(&after) = data.get(end) {
+        if blends(after, last) {
+            return ScreeningVerdict::flagged(format!(
+                "Pattern is followed by {:?}, which blends with its last character {:?}",
+                after, last
+            ));
+        }
+    }
+
+    ScreeningVerdict {
+        passed: true,
+        reason: None,
+    }
+}
+
+impl ScreeningVerdict {
+    fn flagged(reason: impl Into<String>) -> Self {
+        ScreeningVerdict {
+            passed: false,
+            reason: Some(reason.into()),
+        }
+    }
+}
+
+/// Finds where the pattern sits in the data part for the given position
+fn match_start(data: &[char], pattern: &[char], position: VanityPosition) -> Option<usize> {
+    let fits_at = |start: usize| data.get(start..start + pattern.len()) == Some(pattern);
+    let start = match position {
+        VanityPosition::Anywhere => {
+            return (0..=data.len().checked_sub(pattern.len())?).find(|&start| fits_at(start))
+        }
+        VanityPosition::Prefix => 0,
+        VanityPosition::Suffix => data.len().checked_sub(pattern.len())?,
+        VanityPosition::BeforeChecksum => {
+            data.len().checked_sub(CHECKSUM_LENGTH + pattern.len())?
+        }
+    };
+    fits_at(start).then_some(start)
+}
+
+/// A vanity keypair together with its screening verdict
+#[wasm_bindgen]
+pub struct ScreenedVanityMatch {
+    keypair: Keypair,
+    verdict: ScreeningVerdict,
+}
+
+#[wasm_bindgen]
+impl ScreenedVanityMatch {
+    /// Gets the matching address
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.keypair.address.clone()
+    }
+
+    /// Takes the matching keypair out of the match
+    ///
+    /// This consumes the match, so the keypair's secrets are never copied; read
+    /// `verdict` first, as the JavaScript handle is unusable afterwards.
+    pub fn into_keypair(self) -> Keypair {
+        self.keypair
+    }
+
+    /// Gets the screening verdict for the match
+    #[wasm_bindgen(getter)]
+    pub fn verdict(&self) -> ScreeningVerdict {
+        self.verdict.clone()
+    }
+}
+
+/// Generate vanity keypairs in batches, screening each match with `screen_vanity_match()`
+///
+/// # Arguments
+/// * `target` - The substring pattern to search for in addresses
+/// * `position` - Where the pattern should appear
+/// * `batch_size` - Number of keypairs to generate and check in this batch
+/// * `reject_ambiguous` - true to skip flagged matches and keep searching;
+///   false to return the first match with its verdict
+/// * `hrp` - Optional bech32 prefix to search under (defaults to "mantra")
+///
+/// # Returns
+/// * `Option<ScreenedVanityMatch>` - The first accepted match, None if no match found,
+///   or a `VanityError` if generation fails
+#[wasm_bindgen]
+pub fn generate_screened_vanity_keypair_batch(
+    target: &str,
+    position: VanityPosition,
+    batch_size: u32,
+    reject_ambiguous: bool,
+    hrp: Option<String>,
+) -> Result<Option<ScreenedVanityMatch>, VanityError> {
+    let target_lower = check_target(target)?;
+    let hrp = hrp_or_default(hrp);
+
+    for _ in 0..batch_size {
+        let keypair = random_keypair(AccountScheme::Cosmos, &hrp)?;
+        if !address_matches(&keypair.address, &target_lower, position) {
+            continue;
+        }
+
+        let verdict = screen_vanity_match(&keypair.address, &target_lower, position);
+        if verdict.passed || !reject_ambiguous {
+            return Ok(Some(ScreenedVanityMatch {
+                keypair: paranoid_checked(keypair)?,
+                verdict,
+            }));
+        }
+    }
+
+    Ok(None)
+}
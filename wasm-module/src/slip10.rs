@@ -0,0 +1,182 @@
+//! SLIP-0010 ed25519 key derivation
+//!
+//! SLIP-0010 adapts BIP32 to ed25519: the master key uses the "ed25519 seed"
+//! HMAC key and every child must be hardened, since ed25519 has no public
+//! (non-hardened) derivation. Keys derived here are the foundation for the
+//! consensus-key and node-key features, and for chains with ed25519 accounts.
+
+use crate::address::{self, MANTRA_HRP};
+use crate::error::VanityError;
+use crate::hd::{format_derivation_path, parse_hardened_derivation_path, HARDENED_OFFSET};
+use crate::mnemonic::parse_mnemonic;
+use crate::redact::Redacted;
+use base64::prelude::{Engine, BASE64_STANDARD};
+use bip39::Mnemonic;
+use ed25519_dalek::SigningKey;
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Default SLIP-0010 path for MANTRA ed25519 keys (the Cosmos path with every level hardened)
+pub const ED25519_DEFAULT_PATH: &str = "m/44'/118'/0'/0'/0'";
+
+/// A SLIP-0010 ed25519 extended private key
+#[derive(Clone)]
+pub struct Ed25519ExtendedKey {
+    chain_code: [u8; 32],
+    private_key: [u8; 32],
+}
+
+impl Ed25519ExtendedKey {
+    /// Creates the master key from a BIP39 seed using HMAC-SHA512 with "ed25519 seed"
+    pub fn master(seed: &[u8]) -> Result<Self, Box<dyn std::error::Error>> {
+        Self::from_hmac(b"ed25519 seed", &[seed])
+    }
+
+    /// Derives a hardened child key (ed25519 only supports hardened derivation)
+    pub fn derive_child(&self, index: u32) -> Result<Self, Box<dyn std::error::Error>> {
+        if index < HARDENED_OFFSET {
+            return Err("SLIP-0010 ed25519 derivation only supports hardened indexes".into());
+        }
+
+        // Hardened derivation: use 0x00 + private_key + index
+        Self::from_hmac(
+            &self.chain_code,
+            &[&[0x00], &self.private_key, &index.to_be_bytes()],
+        )
+    }
+
+    /// Derives through each component of a path, starting from this key
+    pub fn derive_path(&self, path: &[u32]) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut key = self.clone();
+        for &index in path {
+            key = key.derive_child(index)?;
+        }
+        Ok(key)
+    }
+
+    /// Returns the 32-byte ed25519 public key
+    pub fn public_key(&self) -> [u8; 32] {
+        SigningKey::from_bytes(&self.private_key)
+            .verifying_key()
+            .to_bytes()
+    }
+
+    /// Splits HMAC-SHA512(key, data) into the private key (left) and chain code (right)
+    fn from_hmac(key: &[u8], data: &[&[u8]]) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key)
+            .map_err(|e| format!("Failed to create HMAC: {}", e))?;
+        for part in data {
+            mac.update(part);
+        }
+        let result = Zeroizing::new(mac.finalize().into_bytes());
+
+        let mut private_key = [0u8; 32];
+        let mut chain_code = [0u8; 32];
+        private_key.copy_from_slice(&result[0..32]);
+        chain_code.copy_from_slice(&result[32..64]);
+
+        Ok(Ed25519ExtendedKey {
+            chain_code,
+            private_key,
+        })
+    }
+}
+
+/// Wipes the private key and chain code when the key goes out of scope
+impl Drop for Ed25519ExtendedKey {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+        self.chain_code.zeroize();
+    }
+}
+
+/// Prints the key and chain code as `[REDACTED]`
+impl fmt::Debug for Ed25519ExtendedKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ed25519ExtendedKey")
+            .field("chain_code", &Redacted)
+            .field("private_key", &Redacted)
+            .finish()
+    }
+}
+
+/// An ed25519 keypair derived from a mnemonic with SLIP-0010
+#[wasm_bindgen]
+pub struct Ed25519Keypair {
+    public_key: [u8; 32],
+    derivation_path: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl Ed25519Keypair {
+    /// Gets the 32-byte public key as hex
+    #[wasm_bindgen(getter)]
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.public_key)
+    }
+
+    /// Gets the 32-byte public key as standard base64
+    #[wasm_bindgen(getter)]
+    pub fn public_key_base64(&self) -> String {
+        BASE64_STANDARD.encode(self.public_key)
+    }
+
+    /// Gets the consensus address of the key if used as a validator key (`mantravalcons1...`)
+    #[wasm_bindgen(getter)]
+    pub fn consensus_address(&self) -> Option<String> {
+        address::consensus_address(&self.public_key, MANTRA_HRP).ok()
+    }
+
+    /// Gets the SLIP-0010 derivation path used to produce the key
+    #[wasm_bindgen(getter)]
+    pub fn derivation_path(&self) -> String {
+        format_derivation_path(&self.derivation_path)
+    }
+}
+
+/// Derives an ed25519 keypair from a BIP39 mnemonic using SLIP-0010
+///
+/// This produces ed25519 keys from the same mnemonic used for secp256k1
+/// accounts. Every path component must be hardened.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `path` - Optional SLIP-0010 path; defaults to `m/44'/118'/0'/0'/0'`
+///
+/// # Returns
+/// * `Ed25519Keypair` - The derived ed25519 public key and path, or an error
+///
+/// # Example
+/// ```javascript
+/// const key = derive_ed25519_keypair("word1 word2 ... word24", undefined);
+/// console.log(`Public key: ${key.public_key_base64}`);
+/// ```
+#[wasm_bindgen]
+pub fn derive_ed25519_keypair(
+    mnemonic_str: &str,
+    path: Option<String>,
+) -> Result<Ed25519Keypair, VanityError> {
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+
+    derive_ed25519(&mnemonic, path.as_deref().unwrap_or(ED25519_DEFAULT_PATH))
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving ed25519 key: {}", e)))
+}
+
+/// Derives the SLIP-0010 ed25519 key for a mnemonic at a path string
+fn derive_ed25519(
+    mnemonic: &Mnemonic,
+    path: &str,
+) -> Result<Ed25519Keypair, Box<dyn std::error::Error>> {
+    let derivation_path = parse_hardened_derivation_path(path)?;
+    let seed = Zeroizing::new(mnemonic.to_seed(""));
+    let key = Ed25519ExtendedKey::master(&*seed)?.derive_path(&derivation_path)?;
+
+    Ok(Ed25519Keypair {
+        public_key: key.public_key(),
+        derivation_path,
+    })
+}
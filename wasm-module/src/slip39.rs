@@ -0,0 +1,767 @@
+//! SLIP-39 Shamir backup shares
+//!
+//! SLIP-39 splits a master secret into mnemonic shares, any threshold of which
+//! recombine it while fewer reveal nothing. Here the master secret is the
+//! BIP39 entropy of the wallet mnemonic, so recombining the shares gives back
+//! the original mnemonic and therefore the same addresses.
+//!
+//! Wallets with native SLIP-39 support (such as Trezor) use the master secret
+//! directly as the BIP32 seed rather than running it through BIP39, so
+//! importing these shares there opens a different account.
+
+use crate::audit::audit_export;
+use crate::ct::secret_eq;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::mnemonic::parse_mnemonic;
+use crate::rng::KeyRng;
+use bip39::Mnemonic;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use sha2::Sha256;
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+use wasm_bindgen::prelude::*;
+
+/// The SLIP-39 wordlist (1024 words, sorted), embedded at compile time
+const WORDLIST_TXT: &str = include_str!("slip39_wordlist.txt");
+
+/// Bits encoded by each share word
+const RADIX_BITS: usize = 10;
+
+/// Words taken by the identifier, extendable flag and iteration exponent
+const ID_EXP_WORDS: usize = 2;
+
+/// Words taken by the group and member parameters
+const PARAMS_WORDS: usize = 2;
+
+/// Words taken by the RS1024 checksum
+const CHECKSUM_WORDS: usize = 3;
+
+/// Shortest valid share (a 128-bit secret)
+const MIN_SHARE_WORDS: usize = 20;
+
+/// Most shares a single split may produce
+const MAX_SHARE_COUNT: u32 = 16;
+
+/// Iteration exponent used for new shares (20000 PBKDF2 iterations in total)
+const ITERATION_EXPONENT: u8 = 1;
+
+/// PBKDF2 iterations across all Feistel rounds at exponent 0
+const BASE_ITERATIONS: u32 = 10000;
+
+/// Rounds of the Feistel cipher that encrypts the master secret
+const ROUNDS: u8 = 4;
+
+/// Share index holding the secret itself
+const SECRET_INDEX: u8 = 255;
+
+/// Share index holding the digest of the secret
+const DIGEST_INDEX: u8 = 254;
+
+/// Bytes of HMAC-SHA256 digest kept to verify a recovered secret
+const DIGEST_LENGTH: usize = 4;
+
+/// Returns the wordlist, split once on first use
+fn wordlist() -> &'static [&'static str] {
+    static WORDS: OnceLock<Vec<&'static str>> = OnceLock::new();
+    WORDS.get_or_init(|| WORDLIST_TXT.lines().collect())
+}
+
+/// A single decoded SLIP-39 share
+#[derive(Clone)]
+struct Share {
+    identifier: u16,
+    extendable: bool,
+    iteration_exponent: u8,
+    group_index: u8,
+    group_threshold: u8,
+    group_count: u8,
+    member_index: u8,
+    member_threshold: u8,
+    value: Vec<u8>,
+}
+
+/// Compares the share value with `secret_eq()`; the header fields are public
+impl PartialEq for Share {
+    fn eq(&self, other: &Self) -> bool {
+        (
+            self.identifier,
+            self.extendable,
+            self.iteration_exponent,
+            self.group_index,
+            self.group_threshold,
+            self.group_count,
+            self.member_index,
+            self.member_threshold,
+        ) == (
+            other.identifier,
+            other.extendable,
+            other.iteration_exponent,
+            other.group_index,
+            other.group_threshold,
+            other.group_count,
+            other.member_index,
+            other.member_threshold,
+        ) && secret_eq(&self.value, &other.value)
+    }
+}
+
+impl Share {
+    /// Parses a share mnemonic, checking its checksum and padding
+    fn parse(share: &str) -> Result<Share, String> {
+        let words = wordlist();
+        let indexes = share
+            .split_whitespace()
+            .map(|word| {
+                let word = word.to_lowercase();
+                words
+                    .binary_search(&word.as_str())
+                    .map(|index| index as u16)
+                    .map_err(|_| format!("{:?} is not in the SLIP-39 wordlist", word))
+            })
+            .collect::<Result<Vec<u16>, String>>()?;
+
+        if indexes.len() < MIN_SHARE_WORDS {
+            return Err(format!(
+                "Share has {} words, expected at least {}",
+                indexes.len(),
+                MIN_SHARE_WORDS
+            ));
+        }
+        let value_words = indexes.len() - ID_EXP_WORDS - PARAMS_WORDS - CHECKSUM_WORDS;
+        let padding_bits = (RADIX_BITS * value_words) % 16;
+        if padding_bits > 8 {
+            return Err("Share has an invalid length".to_string());
+        }
+
+        let extendable = indexes[1] & 0x10 != 0;
+        if !rs1024_verify(customization(extendable), &indexes) {
+            return Err("Share has an invalid checksum".to_string());
+        }
+
+        let id_exp = (u32::from(indexes[0]) << 10) | u32::from(indexes[1]);
+        let params = (u32::from(indexes[2]) << 10) | u32::from(indexes[3]);
+        let nibble = |shift: u32| ((params >> shift) & 0xF) as u8;
+        let group_threshold = nibble(12) + 1;
+        let group_count = nibble(8) + 1;
+        if group_threshold > group_count {
+            return Err("Share has a group threshold above its group count".to_string());
+        }
+
+        let value_indexes = &indexes[ID_EXP_WORDS + PARAMS_WORDS..indexes.len() - CHECKSUM_WORDS];
+        let value = unpack_bits(value_indexes, padding_bits)
+            .ok_or_else(|| "Share has invalid padding".to_string())?;
+
+        Ok(Share {
+            identifier: (id_exp >> 5) as u16,
+            extendable,
+            iteration_exponent: (id_exp & 0xF) as u8,
+            group_index: nibble(16),
+            group_threshold,
+            group_count,
+            member_index: nibble(4),
+            member_threshold: nibble(0) + 1,
+            value,
+        })
+    }
+
+    /// Encodes the share as a mnemonic
+    fn encode(&self) -> String {
+        let id_exp = (u32::from(self.identifier) << 5)
+            | (u32::from(self.extendable) << 4)
+            | u32::from(self.iteration_exponent);
+        let params = (u32::from(self.group_index) << 16)
+            | (u32::from(self.group_threshold - 1) << 12)
+            | (u32::from(self.group_count - 1) << 8)
+            | (u32::from(self.member_index) << 4)
+            | u32::from(self.member_threshold - 1);
+
+        let mut indexes = vec![
+            (id_exp >> 10) as u16,
+            (id_exp & 0x3FF) as u16,
+            (params >> 10) as u16,
+            (params & 0x3FF) as u16,
+        ];
+        indexes.extend(pack_bits(&self.value));
+        let checksum = rs1024_checksum(customization(self.extendable), &indexes);
+        indexes.extend(checksum);
+
+        let words = wordlist();
+        indexes
+            .iter()
+            .map(|&index| words[index as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Splits bytes into 10-bit words, padding with leading zero bits
+fn pack_bits(bytes: &[u8]) -> Vec<u16> {
+    let word_count = (bytes.len() * 8).div_ceil(RADIX_BITS);
+    let padding = word_count * RADIX_BITS - bytes.len() * 8;
+    let bit = |position: usize| {
+        position >= padding && {
+            let position = position - padding;
+            bytes[position / 8] >> (7 - position % 8) & 1 == 1
+        }
+    };
+
+    (0..word_count)
+        .map(|word| {
+            (0..RADIX_BITS).fold(0u16, |acc, offset| {
+                (acc << 1) | u16::from(bit(word * RADIX_BITS + offset))
+            })
+        })
+        .collect()
+}
+
+/// Joins 10-bit words back into bytes, or None if the padding bits are not zero
+fn unpack_bits(words: &[u16], padding_bits: usize) -> Option<Vec<u8>> {
+    let bits: Vec<bool> = words
+        .iter()
+        .flat_map(|&word| {
+            (0..RADIX_BITS)
+                .rev()
+                .map(move |shift| word >> shift & 1 == 1)
+        })
+        .collect();
+    if bits[..padding_bits].iter().any(|&bit| bit) {
+        return None;
+    }
+
+    Some(
+        bits[padding_bits..]
+            .chunks(8)
+            .map(|byte| {
+                byte.iter()
+                    .fold(0u8, |acc, &bit| (acc << 1) | u8::from(bit))
+            })
+            .collect(),
+    )
+}
+
+/// Checksum customization string for the share format
+fn customization(extendable: bool) -> &'static [u8] {
+    if extendable {
+        b"shamir_extendable"
+    } else {
+        b"shamir"
+    }
+}
+
+/// RS1024 polynomial remainder over the customization string and words
+fn rs1024_polymod(customization: &[u8], values: &[u16]) -> u32 {
+    const GENERATOR: [u32; 10] = [
+        0xE0E040, 0x1C1C080, 0x3838100, 0x7070200, 0xE0E0009, 0x1C0C2412, 0x38086C24, 0x3090FC48,
+        0x21B1F890, 0x3F3F120,
+    ];
+
+    customization
+        .iter()
+        .map(|&byte| u16::from(byte))
+        .chain(values.iter().copied())
+        .fold(1u32, |chk, value| {
+            let top = chk >> 20;
+            let chk = ((chk & 0xFFFFF) << 10) ^ u32::from(value);
+            (0..10)
+                .filter(|bit| top >> bit & 1 == 1)
+                .fold(chk, |chk, bit| chk ^ GENERATOR[bit])
+        })
+}
+
+/// Computes the three checksum words for a share
+fn rs1024_checksum(customization: &[u8], values: &[u16]) -> [u16; CHECKSUM_WORDS] {
+    let mut padded = values.to_vec();
+    padded.extend([0; CHECKSUM_WORDS]);
+    let polymod = rs1024_polymod(customization, &padded) ^ 1;
+    [2, 1, 0].map(|i| ((polymod >> (RADIX_BITS * i)) & 0x3FF) as u16)
+}
+
+/// Checks the checksum words at the end of a share
+fn rs1024_verify(customization: &[u8], values: &[u16]) -> bool {
+    rs1024_polymod(customization, values) == 1
+}
+
+/// Exponent and logarithm tables for GF(256) with the Rijndael polynomial
+fn gf256_tables() -> &'static ([u8; 255], [u8; 256]) {
+    static TABLES: OnceLock<([u8; 255], [u8; 256])> = OnceLock::new();
+    TABLES.get_or_init(|| {
+        let mut exp = [0u8; 255];
+        let mut log = [0u8; 256];
+        let mut poly: u16 = 1;
+        for (power, entry) in exp.iter_mut().enumerate() {
+            *entry = poly as u8;
+            log[poly as usize] = power as u8;
+            // Multiply by the generator x + 1
+            poly = (poly << 1) ^ poly;
+            if poly & 0x100 != 0 {
+                poly ^= 0x11B;
+            }
+        }
+        (exp, log)
+    })
+}
+
+/// Evaluates the polynomial through the given points at `x`
+fn interpolate(shares: &[(u8, Vec<u8>)], x: u8) -> Result<Vec<u8>, String> {
+    if let Some((_, value)) = shares.iter().find(|(index, _)| *index == x) {
+        return Ok(value.clone());
+    }
+    let length = shares.first().map_or(0, |(_, value)| value.len());
+    if shares.iter().any(|(_, value)| value.len() != length) {
+        return Err("All shares must have the same length".to_string());
+    }
+
+    let (exp, log) = gf256_tables();
+    let log_of = |value: u8| i32::from(log[value as usize]);
+    let log_product: i32 = shares.iter().map(|(index, _)| log_of(index ^ x)).sum();
+
+    let mut result = vec![0u8; length];
+    for (index, value) in shares {
+        let log_basis = (log_product
+            - log_of(index ^ x)
+            - shares
+                .iter()
+                .filter(|(other, _)| other != index)
+                .map(|(other, _)| log_of(index ^ other))
+                .sum::<i32>())
+        .rem_euclid(255);
+        for (out, &byte) in result.iter_mut().zip(value) {
+            if byte != 0 {
+                *out ^= exp[((log_of(byte) + log_basis) % 255) as usize];
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Truncated HMAC-SHA256 digest that lets a recovered secret be checked
+fn share_digest(random_part: &[u8], secret: &[u8]) -> Vec<u8> {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(random_part).expect("HMAC accepts keys of any length");
+    mac.update(secret);
+    mac.finalize().into_bytes()[..DIGEST_LENGTH].to_vec()
+}
+
+/// Splits a secret into `count` shares, any `threshold` of which recover it
+///
+/// The random shares are drawn from `KeyRng`, so a failed health test is
+/// reported as `RngFailure`.
+fn split_secret(
+    threshold: u8,
+    count: u8,
+    secret: &[u8],
+) -> Result<Vec<(u8, Vec<u8>)>, VanityError> {
+    if threshold == 1 {
+        return Ok((0..count).map(|index| (index, secret.to_vec())).collect());
+    }
+
+    let mut shares: Vec<(u8, Vec<u8>)> = (0..threshold - 2)
+        .map(|index| Ok((index, random_bytes(secret.len())?)))
+        .collect::<Result<_, VanityError>>()?;
+    let random_part = random_bytes(secret.len() - DIGEST_LENGTH)?;
+    let mut digest_share = share_digest(&random_part, secret);
+    digest_share.extend(random_part);
+
+    let mut base = shares.clone();
+    base.push((DIGEST_INDEX, digest_share));
+    base.push((SECRET_INDEX, secret.to_vec()));
+    for index in threshold - 2..count {
+        shares.push((
+            index,
+            interpolate(&base, index).map_err(VanityError::InvalidInput)?,
+        ));
+    }
+    Ok(shares)
+}
+
+/// Draws `length` bytes from `KeyRng`
+fn random_bytes(length: usize) -> Result<Vec<u8>, VanityError> {
+    let mut bytes = vec![0u8; length];
+    KeyRng
+        .try_fill_bytes(&mut bytes)
+        .map_err(|e| VanityError::RngFailure(e.to_string()))?;
+    Ok(bytes)
+}
+
+/// Recovers a secret from `threshold` shares, checking its digest
+fn recover_secret(threshold: u8, shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>, String> {
+    if threshold == 1 {
+        return Ok(shares[0].1.clone());
+    }
+
+    let secret = interpolate(shares, SECRET_INDEX)?;
+    let digest_share = interpolate(shares, DIGEST_INDEX)?;
+    let (digest, random_part) = digest_share.split_at(DIGEST_LENGTH);
+    if !secret_eq(digest, &share_digest(random_part, &secret)) {
+        return Err("Shares do not combine to a valid secret (digest mismatch)".to_string());
+    }
+    Ok(secret)
+}
+
+/// Runs the four-round Feistel cipher that protects the master secret
+fn feistel(
+    input: &[u8],
+    passphrase: &str,
+    iteration_exponent: u8,
+    identifier: u16,
+    extendable: bool,
+    decrypt: bool,
+) -> Vec<u8> {
+    let half = input.len() / 2;
+    let (mut left, mut right) = (input[..half].to_vec(), input[half..].to_vec());
+    let mut salt_prefix = Vec::new();
+    if !extendable {
+        salt_prefix.extend_from_slice(b"shamir");
+        salt_prefix.extend_from_slice(&identifier.to_be_bytes());
+    }
+    let iterations = (BASE_ITERATIONS << iteration_exponent) / u32::from(ROUNDS);
+
+    let rounds: Vec<u8> = if decrypt {
+        (0..ROUNDS).rev().collect()
+    } else {
+        (0..ROUNDS).collect()
+    };
+    for round in rounds {
+        let mut password = vec![round];
+        password.extend_from_slice(passphrase.as_bytes());
+        let mut salt = salt_prefix.clone();
+        salt.extend_from_slice(&right);
+
+        let mut round_key = vec![0u8; half];
+        pbkdf2::pbkdf2_hmac::<Sha256>(&password, &salt, iterations, &mut round_key);
+        let next_right: Vec<u8> = left.iter().zip(&round_key).map(|(a, b)| a ^ b).collect();
+        left = std::mem::replace(&mut right, next_right);
+    }
+
+    [right, left].concat()
+}
+
+/// Checks that a passphrase only uses printable ASCII, as SLIP-39 requires
+fn check_passphrase(passphrase: &str) -> Result<(), String> {
+    if passphrase.bytes().all(|byte| (32..=126).contains(&byte)) {
+        Ok(())
+    } else {
+        Err("SLIP-39 passphrases may only contain printable ASCII characters".to_string())
+    }
+}
+
+/// Splits a master secret into single-group SLIP-39 share mnemonics
+pub fn split_master_secret(
+    secret: &[u8],
+    threshold: u32,
+    share_count: u32,
+    passphrase: &str,
+) -> Result<Vec<String>, VanityError> {
+    if secret.len() < 16 || !secret.len().is_multiple_of(2) {
+        return Err(VanityError::InvalidInput(format!(
+            "Master secret must be an even number of bytes, at least 16 (got {})",
+            secret.len()
+        )));
+    }
+    if share_count == 0 || share_count > MAX_SHARE_COUNT {
+        return Err(VanityError::InvalidInput(format!(
+            "Share count must be between 1 and {}",
+            MAX_SHARE_COUNT
+        )));
+    }
+    if threshold == 0 || threshold > share_count {
+        return Err(VanityError::InvalidInput(format!(
+            "Invalid threshold: {} (expected 1 to {})",
+            threshold, share_count
+        )));
+    }
+    if threshold == 1 && share_count > 1 {
+        return Err(VanityError::InvalidInput(
+            "A threshold of 1 only allows a single share; use 1-of-1".to_string(),
+        ));
+    }
+    check_passphrase(passphrase).map_err(VanityError::InvalidInput)?;
+
+    let mut identifier_bytes = [0u8; 2];
+    KeyRng
+        .try_fill_bytes(&mut identifier_bytes)
+        .map_err(|e| VanityError::RngFailure(e.to_string()))?;
+    let identifier = u16::from_be_bytes(identifier_bytes) & 0x7FFF;
+    let encrypted = feistel(
+        secret,
+        passphrase,
+        ITERATION_EXPONENT,
+        identifier,
+        false,
+        false,
+    );
+    let members = split_secret(threshold as u8, share_count as u8, &encrypted)?;
+
+    Ok(members
+        .into_iter()
+        .map(|(member_index, value)| {
+            Share {
+                identifier,
+                extendable: false,
+                iteration_exponent: ITERATION_EXPONENT,
+                group_index: 0,
+                group_threshold: 1,
+                group_count: 1,
+                member_index,
+                member_threshold: threshold as u8,
+                value,
+            }
+            .encode()
+        })
+        .collect())
+}
+
+/// Recombines SLIP-39 share mnemonics (any group layout) into the master secret
+pub fn combine_master_secret(shares: &[String], passphrase: &str) -> Result<Vec<u8>, String> {
+    check_passphrase(passphrase)?;
+    let mut parsed = Vec::new();
+    for (position, share) in shares.iter().filter(|s| !s.trim().is_empty()).enumerate() {
+        let share = Share::parse(share).map_err(|e| format!("Share {}: {}", position + 1, e))?;
+        if !parsed.contains(&share) {
+            parsed.push(share);
+        }
+    }
+    let Some(first) = parsed.first().cloned() else {
+        return Err("No shares given".to_string());
+    };
+    if parsed.iter().any(|share| {
+        (share.identifier, share.extendable, share.iteration_exponent)
+            != (first.identifier, first.extendable, first.iteration_exponent)
+    }) {
+        return Err("Shares belong to different backups".to_string());
+    }
+    if parsed.iter().any(|share| {
+        (share.group_threshold, share.group_count) != (first.group_threshold, first.group_count)
+    }) {
+        return Err("Shares disagree on the group threshold or count".to_string());
+    }
+
+    let mut groups: BTreeMap<u8, Vec<Share>> = BTreeMap::new();
+    for share in parsed {
+        groups.entry(share.group_index).or_default().push(share);
+    }
+
+    let mut group_secrets = Vec::new();
+    for (group_index, members) in &groups {
+        let threshold = members[0].member_threshold;
+        if members.iter().any(|m| m.member_threshold != threshold) {
+            return Err(format!(
+                "Shares in group {} disagree on the member threshold",
+                group_index + 1
+            ));
+        }
+        let mut indexes: Vec<u8> = members.iter().map(|m| m.member_index).collect();
+        indexes.sort_unstable();
+        indexes.dedup();
+        if indexes.len() != members.len() {
+            return Err(format!(
+                "Group {} has different shares with the same index",
+                group_index + 1
+            ));
+        }
+        if members.len() < threshold as usize {
+            continue;
+        }
+
+        let points: Vec<(u8, Vec<u8>)> = members
+            .iter()
+            .take(threshold as usize)
+            .map(|m| (m.member_index, m.value.clone()))
+            .collect();
+        group_secrets.push((*group_index, recover_secret(threshold, &points)?));
+    }
+
+    if group_secrets.len() < first.group_threshold as usize {
+        return Err(format!(
+            "Not enough shares: {} of {} required groups are complete",
+            group_secrets.len(),
+            first.group_threshold
+        ));
+    }
+    group_secrets.truncate(first.group_threshold as usize);
+    let encrypted = recover_secret(first.group_threshold, &group_secrets)?;
+
+    Ok(feistel(
+        &encrypted,
+        passphrase,
+        first.iteration_exponent,
+        first.identifier,
+        first.extendable,
+        true,
+    ))
+}
+
+/// Splits a mnemonic into SLIP-39 Shamir backup shares
+///
+/// The BIP39 entropy of the mnemonic becomes the SLIP-39 master secret, so a
+/// 12-word mnemonic gives 20-word shares and a 24-word mnemonic 33-word
+/// shares. Any `threshold` of the shares recombine with
+/// `combine_slip39_shares()` into the original mnemonic; fewer reveal nothing
+/// about it. Note that SLIP-39 wallets such as Trezor would open a different
+/// account from these shares, as they skip the BIP39 step.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase to back up
+/// * `threshold` - Number of shares needed to recover (1 only for a single share)
+/// * `share_count` - Number of shares to create (at most 16)
+/// * `passphrase` - Optional SLIP-39 passphrase (printable ASCII); recovering
+///   with a different passphrase silently gives a different mnemonic
+///
+/// # Returns
+/// * `Vec<String>` - The share mnemonics, or an error if the inputs are
+///   invalid, the randomness fails its health tests or hardened mode is on
+///
+/// # Example
+/// ```javascript
+/// const shares = split_mnemonic_slip39(keypair.reveal_mnemonic(false), 2, 3, undefined);
+/// shares.forEach((share, i) => console.log(`Share ${i + 1}: ${share}`));
+/// ```
+#[wasm_bindgen]
+pub fn split_mnemonic_slip39(
+    mnemonic_str: &str,
+    threshold: u32,
+    share_count: u32,
+    passphrase: Option<String>,
+) -> Result<Vec<String>, VanityError> {
+    check_plaintext_secrets("SLIP-39 shares")?;
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+
+    let shares = split_master_secret(
+        &mnemonic.to_entropy(),
+        threshold,
+        share_count,
+        passphrase.as_deref().unwrap_or(""),
+    )?;
+    audit_export("slip39_shares", None)?;
+    Ok(shares)
+}
+
+/// Recombines SLIP-39 shares into the mnemonic they were split from
+///
+/// Shares from multi-group backups are accepted as long as enough groups are
+/// complete. The recovered master secret must be valid BIP39 entropy (16 to
+/// 32 bytes), which holds for every backup made by `split_mnemonic_slip39()`.
+///
+/// # Arguments
+/// * `shares` - The share mnemonics (at least the threshold number)
+/// * `passphrase` - Optional SLIP-39 passphrase used when splitting
+///
+/// # Returns
+/// * `String` - The recovered English mnemonic, or an error if the shares are
+///   invalid, inconsistent or too few, or hardened mode is on
+///
+/// # Example
+/// ```javascript
+/// const mnemonic = combine_slip39_shares([shares[0], shares[2]], undefined);
+/// const address = derive_address_from_mnemonic(mnemonic, undefined);
+/// ```
+#[wasm_bindgen]
+pub fn combine_slip39_shares(
+    shares: Vec<String>,
+    passphrase: Option<String>,
+) -> Result<String, VanityError> {
+    check_plaintext_secrets("a recovered mnemonic")?;
+    let secret = combine_master_secret(&shares, passphrase.as_deref().unwrap_or(""))
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Error combining shares: {}", e)))?;
+
+    let mnemonic = Mnemonic::from_entropy(&secret)
+        .map(|mnemonic| mnemonic.to_string())
+        .map_err(|e| {
+            VanityError::InvalidInput(format!(
+                "Recovered secret is not a BIP39 mnemonic ({} bytes): {}",
+                secret.len(),
+                e
+            ))
+        })?;
+    audit_export("slip39_recovered_mnemonic", None)?;
+    Ok(mnemonic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    /// The official SLIP-0039 test vectors: description, shares and the
+    /// master secret as hex (empty when the shares must be rejected)
+    const VECTORS_JSON: &str = include_str!("slip39_vectors.json");
+
+    /// Passphrase every official vector was created with
+    const VECTOR_PASSPHRASE: &str = "TREZOR";
+
+    fn vectors() -> Vec<(String, Vec<String>, String)> {
+        let vectors: Vec<Value> = serde_json::from_str(VECTORS_JSON).unwrap();
+        vectors
+            .into_iter()
+            .map(|vector| {
+                let shares = vector[1]
+                    .as_array()
+                    .unwrap()
+                    .iter()
+                    .map(|share| share.as_str().unwrap().to_string())
+                    .collect();
+                (
+                    vector[0].as_str().unwrap().to_string(),
+                    shares,
+                    vector[2].as_str().unwrap().to_string(),
+                )
+            })
+            .collect()
+    }
+
+    fn vector(number: usize) -> (String, Vec<String>, String) {
+        vectors().swap_remove(number - 1)
+    }
+
+    #[test]
+    fn valid_single_share_vectors_recover_the_master_secret() {
+        for number in [1, 20] {
+            let (description, shares, secret) = vector(number);
+            let recovered = combine_master_secret(&shares, VECTOR_PASSPHRASE).unwrap();
+            assert_eq!(hex::encode(recovered), secret, "{}", description);
+        }
+    }
+
+    #[test]
+    fn invalid_checksum_vectors_are_rejected() {
+        for number in [2, 21] {
+            let (description, shares, _) = vector(number);
+            let error = combine_master_secret(&shares, VECTOR_PASSPHRASE).unwrap_err();
+            assert!(error.contains("checksum"), "{}: {}", description, error);
+        }
+    }
+
+    #[test]
+    fn invalid_padding_vectors_are_rejected() {
+        for number in [3, 22] {
+            let (description, shares, _) = vector(number);
+            let error = combine_master_secret(&shares, VECTOR_PASSPHRASE).unwrap_err();
+            assert!(error.contains("padding"), "{}: {}", description, error);
+        }
+    }
+
+    #[test]
+    fn every_official_vector_matches() {
+        for (description, shares, secret) in vectors() {
+            let result = combine_master_secret(&shares, VECTOR_PASSPHRASE);
+            if secret.is_empty() {
+                assert!(result.is_err(), "{} should be rejected", description);
+            } else {
+                assert_eq!(hex::encode(result.unwrap()), secret, "{}", description);
+            }
+        }
+    }
+
+    #[test]
+    fn split_shares_recombine() {
+        let secret = hex::decode("bb54aac4b89dc868ba37d9cc21b2cece").unwrap();
+        let shares = split_master_secret(&secret, 2, 3, VECTOR_PASSPHRASE).unwrap();
+        let recovered =
+            combine_master_secret(&[shares[2].clone(), shares[0].clone()], VECTOR_PASSPHRASE)
+                .unwrap();
+        assert_eq!(recovered, secret);
+    }
+}
@@ -0,0 +1,392 @@
+//! Tendermint (CometBFT) validator and node keys
+//!
+//! Validators sign blocks with an ed25519 consensus key, and every node has an
+//! ed25519 node key that identifies it to peers; neither comes from a BIP39
+//! account. Generating one is just 32 random bytes and a scalar
+//! multiplication, so grinding for a vanity `mantravalcons1...` address or
+//! node ID is far faster than the PBKDF2-bound mnemonic search.
+
+use crate::address::{consensus_address, consensus_address_bytes, MANTRA_HRP};
+use crate::audit::audit_export;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::redact::Redacted;
+use crate::rng::KeyRng;
+use crate::{address_matches, check_hex_target, check_target, VanityPosition};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use ed25519_dalek::SigningKey;
+use rand::RngCore;
+use serde_json::json;
+use std::fmt;
+use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Amino type name of an ed25519 private key in Tendermint key files
+const PRIV_KEY_TYPE: &str = "tendermint/PrivKeyEd25519";
+
+/// Amino type name of an ed25519 public key in Tendermint key files
+const PUB_KEY_TYPE: &str = "tendermint/PubKeyEd25519";
+
+/// A randomly generated ed25519 consensus keypair
+///
+/// **SENSITIVE**: `export_private_key_base64()` returns the validator's signing
+/// key. Anyone holding it can double-sign and get the validator slashed.
+#[wasm_bindgen]
+pub struct ConsensusKeypair {
+    private_key: [u8; 32],
+    public_key: [u8; 32],
+    consensus_address: String,
+}
+
+#[wasm_bindgen]
+impl ConsensusKeypair {
+    /// Gets the validator consensus address (`mantravalcons1...`)
+    #[wasm_bindgen(getter)]
+    pub fn consensus_address(&self) -> String {
+        self.consensus_address.clone()
+    }
+
+    /// Gets the uppercase hex consensus address, as shown in `priv_validator_key.json`
+    #[wasm_bindgen(getter)]
+    pub fn address_hex(&self) -> String {
+        consensus_address_bytes(&self.public_key)
+            .map(hex::encode_upper)
+            .unwrap_or_default()
+    }
+
+    /// Gets the 32-byte public key as hex
+    #[wasm_bindgen(getter)]
+    pub fn public_key_hex(&self) -> String {
+        hex::encode(self.public_key)
+    }
+
+    /// Gets the 32-byte public key as standard base64 (the `pub_key.value` field)
+    #[wasm_bindgen(getter)]
+    pub fn public_key_base64(&self) -> String {
+        BASE64_STANDARD.encode(self.public_key)
+    }
+
+    /// Returns the private key in Tendermint's 64-byte (seed + public key) form
+    /// as base64, guarded by an explicit confirmation
+    ///
+    /// Refused unless `confirm` is true and in hardened mode; each export is
+    /// reported to the hook registered with `set_export_audit_hook()`.
+    ///
+    /// # Arguments
+    /// * `confirm` - Must be true to confirm the private key should be exposed
+    ///
+    /// # Returns
+    /// * `String` - The base64 private key, or an error if not confirmed,
+    ///   hardened mode is on or the audit hook failed
+    ///
+    /// # Example
+    /// ```javascript
+    /// const privateKey = key.export_private_key_base64(userConfirmed);
+    /// ```
+    pub fn export_private_key_base64(&self, confirm: bool) -> Result<String, VanityError> {
+        check_key_export(confirm, "consensus_private_key", &self.consensus_address)?;
+        Ok(expanded_private_key_base64(
+            &self.private_key,
+            &self.public_key,
+        ))
+    }
+
+    /// Returns the contents of a ready-to-use `config/priv_validator_key.json`,
+    /// guarded by an explicit confirmation
+    ///
+    /// Pair it with a fresh `data/priv_validator_state.json` (height 0) when
+    /// moving the key to a new node, and never run two nodes with the same key.
+    /// Refused unless `confirm` is true and in hardened mode; each export is
+    /// reported to the hook registered with `set_export_audit_hook()`.
+    ///
+    /// # Arguments
+    /// * `confirm` - Must be true to confirm the private key should be exposed
+    ///
+    /// # Returns
+    /// * `String` - The key file JSON, or an error if not confirmed, hardened
+    ///   mode is on or the audit hook failed
+    ///
+    /// # Example
+    /// ```javascript
+    /// download("priv_validator_key.json", key.export_priv_validator_key_json(userConfirmed));
+    /// ```
+    pub fn export_priv_validator_key_json(&self, confirm: bool) -> Result<String, VanityError> {
+        check_key_export(confirm, "priv_validator_key", &self.consensus_address)?;
+        let private_key = Zeroizing::new(expanded_private_key_base64(
+            &self.private_key,
+            &self.public_key,
+        ));
+        let priv_validator_key = json!({
+            "address": self.address_hex(),
+            "pub_key": {
+                "type": PUB_KEY_TYPE,
+                "value": self.public_key_base64(),
+            },
+            "priv_key": {
+                "type": PRIV_KEY_TYPE,
+                "value": private_key.as_str(),
+            }
+        });
+        serde_json::to_string_pretty(&priv_validator_key).map_err(|e| {
+            VanityError::DerivationFailed(format!("Error serializing key file: {}", e))
+        })
+    }
+}
+
+/// Wipes the private key when the keypair is freed from JavaScript
+impl Drop for ConsensusKeypair {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+/// Shows the address and public key; the private key prints as `[REDACTED]`
+impl fmt::Debug for ConsensusKeypair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConsensusKeypair")
+            .field("private_key", &Redacted)
+            .field("public_key", &hex::encode(self.public_key))
+            .field("consensus_address", &self.consensus_address)
+            .finish()
+    }
+}
+
+/// A randomly generated ed25519 node key
+///
+/// **SENSITIVE**: `node_key_json` contains the private key. It only controls
+/// the node's peer identity, not funds or signing, but should still be kept
+/// private.
+#[wasm_bindgen]
+pub struct NodeKeypair {
+    private_key: [u8; 32],
+    public_key: [u8; 32],
+    node_id: String,
+}
+
+#[wasm_bindgen]
+impl NodeKeypair {
+    /// Gets the node ID (40 lowercase hex characters) used in `id@host:port` peer strings
+    #[wasm_bindgen(getter)]
+    pub fn node_id(&self) -> String {
+        self.node_id.clone()
+    }
+
+    /// Gets the 32-byte public key as standard base64
+    #[wasm_bindgen(getter)]
+    pub fn public_key_base64(&self) -> String {
+        BASE64_STANDARD.encode(self.public_key)
+    }
+
+    /// Returns the contents of a ready-to-use `config/node_key.json`, guarded by
+    /// an explicit confirmation
+    ///
+    /// Refused unless `confirm` is true and in hardened mode; each export is
+    /// reported to the hook registered with `set_export_audit_hook()`.
+    ///
+    /// # Arguments
+    /// * `confirm` - Must be true to confirm the private key should be exposed
+    ///
+    /// # Returns
+    /// * `String` - The key file JSON, or an error if not confirmed, hardened
+    ///   mode is on or the audit hook failed
+    ///
+    /// # Example
+    /// ```javascript
+    /// download("node_key.json", node.export_node_key_json(userConfirmed));
+    /// ```
+    pub fn export_node_key_json(&self, confirm: bool) -> Result<String, VanityError> {
+        check_key_export(confirm, "node_key", &self.node_id)?;
+        let private_key = Zeroizing::new(expanded_private_key_base64(
+            &self.private_key,
+            &self.public_key,
+        ));
+        let node_key = json!({
+            "priv_key": {
+                "type": PRIV_KEY_TYPE,
+                "value": private_key.as_str(),
+            }
+        });
+        serde_json::to_string_pretty(&node_key).map_err(|e| {
+            VanityError::DerivationFailed(format!("Error serializing key file: {}", e))
+        })
+    }
+}
+
+/// Wipes the private key when the keypair is freed from JavaScript
+impl Drop for NodeKeypair {
+    fn drop(&mut self) {
+        self.private_key.zeroize();
+    }
+}
+
+/// Shows the node ID and public key; the private key prints as `[REDACTED]`
+impl fmt::Debug for NodeKeypair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeKeypair")
+            .field("private_key", &Redacted)
+            .field("public_key", &hex::encode(self.public_key))
+            .field("node_id", &self.node_id)
+            .finish()
+    }
+}
+
+/// Draws 32 bytes from `KeyRng` and returns them with the matching ed25519 public key
+///
+/// Fails if the randomness does not pass the continuous health tests.
+fn random_ed25519_key() -> Result<(Zeroizing<[u8; 32]>, [u8; 32]), VanityError> {
+    let mut private_key = Zeroizing::new([0u8; 32]);
+    KeyRng
+        .try_fill_bytes(&mut *private_key)
+        .map_err(|e| VanityError::RngFailure(e.to_string()))?;
+
+    let public_key = SigningKey::from_bytes(&private_key)
+        .verifying_key()
+        .to_bytes();
+    Ok((private_key, public_key))
+}
+
+/// Checks that a private key export was confirmed and is allowed, then reports it
+fn check_key_export(confirm: bool, event: &str, identifier: &str) -> Result<(), VanityError> {
+    if !confirm {
+        return Err(VanityError::NotAcknowledged(
+            "Refusing to export a Tendermint private key without confirm = true".to_string(),
+        ));
+    }
+    check_plaintext_secrets("a Tendermint private key")?;
+    audit_export(event, Some(identifier))
+}
+
+/// Encodes an ed25519 key in Tendermint's 64-byte (seed + public key) form as base64
+fn expanded_private_key_base64(private_key: &[u8; 32], public_key: &[u8; 32]) -> String {
+    let mut expanded = Zeroizing::new([0u8; 64]);
+    expanded[..32].copy_from_slice(private_key);
+    expanded[32..].copy_from_slice(public_key);
+    BASE64_STANDARD.encode(*expanded)
+}
+
+/// Generates a random ed25519 consensus keypair for a MANTRA validator
+///
+/// # Returns
+/// * `ConsensusKeypair` - The new key and its `mantravalcons1...` address, or a
+///   `VanityError` (`RngFailure`) if the randomness fails its health tests
+///
+/// # Example
+/// ```javascript
+/// const key = generate_consensus_keypair();
+/// console.log(`Consensus address: ${key.consensus_address}`);
+/// ```
+#[wasm_bindgen]
+pub fn generate_consensus_keypair() -> Result<ConsensusKeypair, VanityError> {
+    let (private_key, public_key) = random_ed25519_key()?;
+    let consensus_address = consensus_address(&public_key, MANTRA_HRP).map_err(|e| {
+        VanityError::DerivationFailed(format!("Error encoding consensus address: {}", e))
+    })?;
+
+    Ok(ConsensusKeypair {
+        private_key: *private_key,
+        public_key,
+        consensus_address,
+    })
+}
+
+/// Generate consensus keypairs in batches until the valcons address matches
+///
+/// Works like `generate_vanity_keypair_batch()`, but over ed25519 consensus
+/// keys and their `mantravalcons1...` addresses. Prefix matches start right
+/// after `mantravalcons1`.
+///
+/// # Arguments
+/// * `target` - The substring pattern to search for in consensus addresses
+/// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
+/// * `batch_size` - Number of keypairs to generate and check in this batch
+///
+/// # Returns
+/// * `Option<ConsensusKeypair>` - The first matching keypair, None if no match
+///   found, or a `VanityError` if the target can never match or generation fails
+#[wasm_bindgen]
+pub fn generate_consensus_vanity_keypair_batch(
+    target: &str,
+    position: VanityPosition,
+    batch_size: u32,
+) -> Result<Option<ConsensusKeypair>, VanityError> {
+    let target_lower = check_target(target)?;
+
+    for _ in 0..batch_size {
+        let keypair = generate_consensus_keypair()?;
+
+        if address_matches(&keypair.consensus_address, &target_lower, position) {
+            return Ok(Some(keypair));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Generates a random ed25519 node key
+///
+/// The node ID is the hex encoding of the first 20 bytes of SHA256 over the
+/// public key, the same identifier CometBFT prints with `show-node-id`.
+///
+/// # Returns
+/// * `NodeKeypair` - The new node key and its node ID, or a `VanityError`
+///   (`RngFailure`) if the randomness fails its health tests
+///
+/// # Example
+/// ```javascript
+/// const node = generate_node_keypair();
+/// console.log(`persistent_peers = "${node.node_id}@1.2.3.4:26656"`);
+/// ```
+#[wasm_bindgen]
+pub fn generate_node_keypair() -> Result<NodeKeypair, VanityError> {
+    let (private_key, public_key) = random_ed25519_key()?;
+    let node_id = consensus_address_bytes(&public_key)
+        .map(hex::encode)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error computing node ID: {}", e)))?;
+
+    Ok(NodeKeypair {
+        private_key: *private_key,
+        public_key,
+        node_id,
+    })
+}
+
+/// Generate node keys in batches until the node ID matches the target
+///
+/// Node IDs are lowercase hex, so the target may only contain 0-9 and a-f
+/// (check it with `validate_evm_target_string()`). Prefix matches start at
+/// the first character of the node ID.
+///
+/// # Arguments
+/// * `target` - The hex pattern to search for in node IDs
+/// * `position` - Where the pattern should appear (Anywhere, Prefix, or Suffix)
+/// * `batch_size` - Number of node keys to generate and check in this batch
+///
+/// # Returns
+/// * `Option<NodeKeypair>` - The first matching node key, None if no match
+///   found, or a `VanityError` if the target is not hex or generation fails
+#[wasm_bindgen]
+pub fn generate_node_id_vanity_batch(
+    target: &str,
+    position: VanityPosition,
+    batch_size: u32,
+) -> Result<Option<NodeKeypair>, VanityError> {
+    check_hex_target(target)?;
+    let target_lower = target.to_lowercase();
+
+    for _ in 0..batch_size {
+        let keypair = generate_node_keypair()?;
+
+        let matches = match position {
+            VanityPosition::Anywhere => keypair.node_id.contains(&target_lower),
+            VanityPosition::Prefix => keypair.node_id.starts_with(&target_lower),
+            VanityPosition::Suffix | VanityPosition::BeforeChecksum => {
+                keypair.node_id.ends_with(&target_lower)
+            }
+        };
+        if matches {
+            return Ok(Some(keypair));
+        }
+    }
+
+    Ok(None)
+}
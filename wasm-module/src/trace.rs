@@ -0,0 +1,283 @@
+//! Step-by-step derivation traces for auditors
+//!
+//! Comparing only the final address against a reference implementation says
+//! that something differs, not where. A trace records every intermediate value
+//! of one derivation (the seed, each child's key material, and the hashes that
+//! turn the public key into an address) so it can be diffed line by line
+//! against another wallet's output.
+//!
+//! **SENSITIVE OUTPUT**: a trace contains the seed and every private key on the
+//! path. It is only returned when the caller acknowledges this explicitly.
+
+use crate::address::check_hrp;
+use crate::audit::audit_export;
+use crate::error::VanityError;
+use crate::hardened::check_plaintext_secrets;
+use crate::hd::{format_derivation_path, hash160, parse_derivation_path, ExtendedPrivateKey};
+use crate::mnemonic::parse_mnemonic;
+use crate::schema::SCHEMA_VERSION;
+use crate::{hrp_or_default, AccountScheme};
+use k256::{elliptic_curve::sec1::ToEncodedPoint, PublicKey};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+use wasm_bindgen::prelude::*;
+use zeroize::{Zeroize, Zeroizing};
+
+/// Key material of one node on a traced derivation path
+///
+/// **SENSITIVE OUTPUT**: `private_key_hex` controls every key below this node.
+#[wasm_bindgen]
+#[derive(Clone, Serialize)]
+pub struct DerivationStep {
+    path: String,
+    child_number: u32,
+    #[serde(rename = "chain_code")]
+    chain_code_hex: String,
+    #[serde(rename = "private_key")]
+    private_key_hex: String,
+    #[serde(rename = "public_key")]
+    public_key_hex: String,
+}
+
+#[wasm_bindgen]
+impl DerivationStep {
+    /// Gets the path of this node (e.g. "m/44'/118'"; "m" for the master key)
+    #[wasm_bindgen(getter)]
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// Gets the child index actually used, with the hardened bit set for hardened children
+    #[wasm_bindgen(getter)]
+    pub fn child_number(&self) -> u32 {
+        self.child_number
+    }
+
+    /// Gets the chain code as hex
+    #[wasm_bindgen(getter)]
+    pub fn chain_code_hex(&self) -> String {
+        self.chain_code_hex.clone()
+    }
+
+    /// Gets the private key as hex
+    #[wasm_bindgen(getter)]
+    pub fn private_key_hex(&self) -> String {
+        self.private_key_hex.clone()
+    }
+
+    /// Gets the compressed public key as hex
+    #[wasm_bindgen(getter)]
+    pub fn public_key_hex(&self) -> String {
+        self.public_key_hex.clone()
+    }
+}
+
+/// Wipes the chain code and private key when the step is freed from JavaScript
+impl Drop for DerivationStep {
+    fn drop(&mut self) {
+        self.chain_code_hex.zeroize();
+        self.private_key_hex.zeroize();
+    }
+}
+
+/// Every intermediate value of one mnemonic-to-address derivation
+///
+/// **SENSITIVE OUTPUT**: the seed and the private keys grant full control over
+/// the wallet. Never log or transmit a trace of a phrase that holds funds.
+#[wasm_bindgen]
+pub struct DerivationTrace {
+    seed_hex: String,
+    steps: Vec<DerivationStep>,
+    scheme: AccountScheme,
+    hash_input_hex: String,
+    address_hash_hex: String,
+    account_id_hex: String,
+    address: String,
+}
+
+#[wasm_bindgen]
+impl DerivationTrace {
+    /// Gets the 64-byte BIP39 seed as hex
+    #[wasm_bindgen(getter)]
+    pub fn seed_hex(&self) -> String {
+        self.seed_hex.clone()
+    }
+
+    /// Gets the master key followed by each child on the path, in derivation order
+    pub fn steps(&self) -> Vec<DerivationStep> {
+        self.steps.clone()
+    }
+
+    /// Gets the account scheme the address was encoded under
+    #[wasm_bindgen(getter)]
+    pub fn scheme(&self) -> AccountScheme {
+        self.scheme
+    }
+
+    /// Gets the public key bytes that are hashed into the account ID, as hex
+    ///
+    /// The 33-byte compressed key for Cosmos, the 64-byte uncompressed key
+    /// without its 0x04 tag for ethsecp256k1.
+    #[wasm_bindgen(getter)]
+    pub fn hash_input_hex(&self) -> String {
+        self.hash_input_hex.clone()
+    }
+
+    /// Gets the first address hash as hex: SHA-256 for Cosmos, keccak256 for ethsecp256k1
+    #[wasm_bindgen(getter)]
+    pub fn address_hash_hex(&self) -> String {
+        self.address_hash_hex.clone()
+    }
+
+    /// Gets the 20-byte account ID as hex (RIPEMD-160 of the SHA-256 hash, or
+    /// the last 20 bytes of the keccak256 hash)
+    #[wasm_bindgen(getter)]
+    pub fn account_id_hex(&self) -> String {
+        self.account_id_hex.clone()
+    }
+
+    /// Gets the bech32 address
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.address.clone()
+    }
+
+    /// Serializes the trace as pretty-printed JSON, for diffing against other tools
+    ///
+    /// # Returns
+    /// * `String` - The trace JSON, or an error if serialization fails
+    pub fn to_json(&self) -> Result<String, VanityError> {
+        serde_json::to_string_pretty(&serde_json::json!({
+            "schema_version": SCHEMA_VERSION,
+            "seed": self.seed_hex,
+            "steps": self.steps,
+            "scheme": self.scheme.name(),
+            "hash_input": self.hash_input_hex,
+            "address_hash": self.address_hash_hex,
+            "account_id": self.account_id_hex,
+            "address": self.address,
+        }))
+        .map_err(|e| VanityError::DerivationFailed(format!("Error serializing trace: {}", e)))
+    }
+}
+
+/// Wipes the seed when the trace is freed from JavaScript; the steps wipe themselves
+impl Drop for DerivationTrace {
+    fn drop(&mut self) {
+        self.seed_hex.zeroize();
+    }
+}
+
+/// Records one node of the path
+fn trace_step(
+    key: &ExtendedPrivateKey,
+    path: &[u32],
+) -> Result<DerivationStep, Box<dyn std::error::Error>> {
+    Ok(DerivationStep {
+        path: format_derivation_path(path),
+        child_number: key.child_number(),
+        chain_code_hex: hex::encode(key.chain_code()),
+        private_key_hex: hex::encode(Zeroizing::new(key.private_key_bytes()).as_slice()),
+        public_key_hex: hex::encode(key.public_key()?),
+    })
+}
+
+/// Derives along a path, recording every intermediate value
+fn build_trace(
+    seed: &[u8],
+    path: &[u32],
+    scheme: AccountScheme,
+    hrp: &str,
+) -> Result<DerivationTrace, Box<dyn std::error::Error>> {
+    let mut key = ExtendedPrivateKey::master(seed)?;
+    let mut steps = vec![trace_step(&key, &[])?];
+    for depth in 0..path.len() {
+        key = key.derive_child(path[depth])?;
+        steps.push(trace_step(&key, &path[..=depth])?);
+    }
+
+    let public_key = key.public_key()?;
+    let (hash_input, address_hash, account_id) = match scheme {
+        AccountScheme::Cosmos => (
+            public_key.to_vec(),
+            Sha256::digest(public_key).to_vec(),
+            hash160(&public_key).to_vec(),
+        ),
+        AccountScheme::EthSecp256k1 => {
+            let uncompressed = PublicKey::from_sec1_bytes(&public_key)?.to_encoded_point(false);
+            let hash = Keccak256::digest(&uncompressed.as_bytes()[1..]);
+            (
+                uncompressed.as_bytes()[1..].to_vec(),
+                hash.to_vec(),
+                hash[12..].to_vec(),
+            )
+        }
+    };
+
+    Ok(DerivationTrace {
+        seed_hex: hex::encode(seed),
+        steps,
+        scheme,
+        hash_input_hex: hex::encode(hash_input),
+        address_hash_hex: hex::encode(address_hash),
+        account_id_hex: hex::encode(account_id),
+        address: scheme.address(&public_key, hrp)?,
+    })
+}
+
+/// Derives an address while exposing every intermediate value
+///
+/// Returns the BIP39 seed, the chain code and keys of the master key and of
+/// each child on the path, and the hashes that turn the final public key into
+/// the address, so auditors can compare each step against a reference
+/// implementation. Because the output contains the seed and private keys, the
+/// caller must pass `acknowledge_sensitive_output = true`, and the export is
+/// reported to the audit hook (see `set_export_audit_hook()`). Traces are
+/// unavailable in hardened mode.
+///
+/// # Arguments
+/// * `mnemonic_str` - The mnemonic phrase as a string
+/// * `path` - Optional BIP32 path (defaults to the scheme's standard path)
+/// * `scheme` - The account scheme used for the address encoding
+/// * `hrp` - Optional bech32 prefix for the address (defaults to "mantra")
+/// * `acknowledge_sensitive_output` - Must be true to confirm secret material may be returned
+///
+/// # Returns
+/// * `DerivationTrace` - The intermediate values and the address, or an error
+///
+/// # Example
+/// ```javascript
+/// const trace = trace_derivation(testMnemonic, "m/44'/118'/0'/0/0", AccountScheme.Cosmos, undefined, true);
+/// trace.steps().forEach((step) => console.log(step.path, step.chain_code_hex, step.public_key_hex));
+/// ```
+#[wasm_bindgen]
+pub fn trace_derivation(
+    mnemonic_str: &str,
+    path: Option<String>,
+    scheme: AccountScheme,
+    hrp: Option<String>,
+    acknowledge_sensitive_output: bool,
+) -> Result<DerivationTrace, VanityError> {
+    if !acknowledge_sensitive_output {
+        return Err(VanityError::NotAcknowledged(
+            "Derivation traces require acknowledge_sensitive_output = true".to_string(),
+        ));
+    }
+    check_plaintext_secrets("a derivation trace")?;
+
+    let mnemonic = parse_mnemonic(mnemonic_str)
+        .map_err(|e| VanityError::InvalidMnemonic(format!("Invalid mnemonic: {}", e)))?;
+    let path = match path {
+        Some(path) => parse_derivation_path(&path)
+            .map_err(|e| VanityError::InvalidPath(format!("Invalid derivation path: {}", e)))?,
+        None => scheme.derivation_path().to_vec(),
+    };
+    let hrp = hrp_or_default(hrp);
+    check_hrp(&hrp)?;
+
+    let trace = build_trace(&*Zeroizing::new(mnemonic.to_seed("")), &path, scheme, &hrp)
+        .map_err(|e| VanityError::DerivationFailed(format!("Error deriving address: {}", e)))?;
+    audit_export("derivation_trace", Some(&trace.address))?;
+    Ok(trace)
+}
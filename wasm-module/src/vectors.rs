@@ -0,0 +1,179 @@
+//! Known-answer derivation vectors
+//!
+//! A regression in seed, BIP32 or address code would silently produce
+//! addresses no other wallet derives from the same phrase. These vectors were
+//! produced by reference wallets (CosmJS and Keplr for Cosmos keys, MetaMask for
+//! ethsecp256k1) and are re-derived at runtime by `verify_derivation_vectors()`,
+//! so a frontend can check the exact build it loaded before trusting it.
+
+use crate::hd::parse_derivation_path;
+use crate::mnemonic::parse_mnemonic;
+use crate::{derive_key_at_path, AccountScheme};
+use wasm_bindgen::prelude::*;
+
+/// One mnemonic, path and the address a reference wallet derives for it
+struct DerivationVector {
+    source: &'static str,
+    mnemonic: &'static str,
+    path: &'static str,
+    scheme: AccountScheme,
+    /// Expected address under the "cosmos" prefix
+    address: &'static str,
+}
+
+const ABANDON_ABOUT: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                             abandon abandon abandon about";
+
+const COSMJS_FAUCET: &str = "economy stock theory fatal elder harbor betray wasp final emotion \
+                             task crumble siren bottom lizard educate guess current outdoor pair \
+                             theory focus wife stone";
+
+const DERIVATION_VECTORS: [DerivationVector; 5] = [
+    DerivationVector {
+        source: "CosmJS / Keplr",
+        mnemonic: ABANDON_ABOUT,
+        path: "m/44'/118'/0'/0/0",
+        scheme: AccountScheme::Cosmos,
+        address: "cosmos19rl4cm2hmr8afy4kldpxz3fka4jguq0auqdal4",
+    },
+    DerivationVector {
+        source: "CosmJS test faucet, account 0",
+        mnemonic: COSMJS_FAUCET,
+        path: "m/44'/118'/0'/0/0",
+        scheme: AccountScheme::Cosmos,
+        address: "cosmos1pkptre7fdkl6gfrzlesjjvhxhlc3r4gmmk8rs6",
+    },
+    DerivationVector {
+        source: "CosmJS test faucet, account 1",
+        mnemonic: COSMJS_FAUCET,
+        path: "m/44'/118'/0'/0/1",
+        scheme: AccountScheme::Cosmos,
+        address: "cosmos10dyr9899g6t0pelew4nvf4j5c3jcgv0r73qga5",
+    },
+    DerivationVector {
+        source: "CosmJS Secp256k1HdWallet",
+        mnemonic: "special sign fit simple patrol salute grocery chicken wheat radar tonight \
+                   ceiling",
+        path: "m/44'/118'/0'/0/0",
+        scheme: AccountScheme::Cosmos,
+        address: "cosmos1jhg0e7s6gn44tfc5k37kr04sznyhedtc9rzys5",
+    },
+    DerivationVector {
+        // Bech32 form of 0x9858EfFD232B4033E47d90003D41EC34EcaEda94
+        source: "MetaMask",
+        mnemonic: ABANDON_ABOUT,
+        path: "m/44'/60'/0'/0/0",
+        scheme: AccountScheme::EthSecp256k1,
+        address: "cosmos1npvwllfr9dqr8erajqqr6s0vxnk2ak55fsjtl6",
+    },
+];
+
+/// Outcome of re-deriving one known-answer vector
+#[wasm_bindgen]
+pub struct VectorResult {
+    source: String,
+    derivation_path: String,
+    scheme: AccountScheme,
+    expected: String,
+    actual: Option<String>,
+    error: Option<String>,
+}
+
+#[wasm_bindgen]
+impl VectorResult {
+    /// Gets the wallet that produced the expected address
+    #[wasm_bindgen(getter)]
+    pub fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    /// Gets the derivation path of the vector
+    #[wasm_bindgen(getter)]
+    pub fn derivation_path(&self) -> String {
+        self.derivation_path.clone()
+    }
+
+    /// Gets the account scheme of the vector
+    #[wasm_bindgen(getter)]
+    pub fn scheme(&self) -> AccountScheme {
+        self.scheme
+    }
+
+    /// Gets the address the reference wallet derives
+    #[wasm_bindgen(getter)]
+    pub fn expected(&self) -> String {
+        self.expected.clone()
+    }
+
+    /// Gets the address this build derives, if derivation succeeded
+    #[wasm_bindgen(getter)]
+    pub fn actual(&self) -> Option<String> {
+        self.actual.clone()
+    }
+
+    /// Gets whether this build derives the expected address
+    #[wasm_bindgen(getter)]
+    pub fn passed(&self) -> bool {
+        self.actual.as_deref() == Some(self.expected.as_str())
+    }
+
+    /// Gets the error that stopped derivation, if any
+    #[wasm_bindgen(getter)]
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+}
+
+/// Derives a vector's address with this crate's own code
+fn derive_vector_address(vector: &DerivationVector) -> Result<String, Box<dyn std::error::Error>> {
+    let mnemonic = parse_mnemonic(vector.mnemonic)?;
+    let path = parse_derivation_path(vector.path)?;
+    let key = derive_key_at_path(&mnemonic, &path)?;
+    vector.scheme.address(&key.public_key()?, "cosmos")
+}
+
+/// Re-derives the first vector, for the start-up self-test
+pub fn check_first_vector() -> Result<(), String> {
+    let vector = &DERIVATION_VECTORS[0];
+    let actual = derive_vector_address(vector).map_err(|e| e.to_string())?;
+    if actual != vector.address {
+        return Err(format!("derived {}, expected {}", actual, vector.address));
+    }
+    Ok(())
+}
+
+/// Re-derives the built-in known-answer vectors and reports each result
+///
+/// Each vector is a mnemonic and path together with the address CosmJS,
+/// Keplr or MetaMask derives for it. A build whose seed, BIP32 or address
+/// code has regressed fails at least one of them. The check takes a few
+/// milliseconds, so it can run on every page load.
+///
+/// # Returns
+/// * `Vec<VectorResult>` - One result per vector, with expected and actual addresses
+///
+/// # Example
+/// ```javascript
+/// const results = verify_derivation_vectors();
+/// if (results.every((result) => result.passed)) showBadge("Derivation verified");
+/// ```
+#[wasm_bindgen]
+pub fn verify_derivation_vectors() -> Vec<VectorResult> {
+    DERIVATION_VECTORS
+        .iter()
+        .map(|vector| {
+            let (actual, error) = match derive_vector_address(vector) {
+                Ok(address) => (Some(address), None),
+                Err(e) => (None, Some(e.to_string())),
+            };
+            VectorResult {
+                source: vector.source.to_string(),
+                derivation_path: vector.path.to_string(),
+                scheme: vector.scheme,
+                expected: vector.address.to_string(),
+                actual,
+                error,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,105 @@
+//! Watch-only account bundles
+//!
+//! Portfolio trackers and explorers only need public data to follow an
+//! account. A watch-only bundle carries the account-level xpub together with
+//! the path, prefix and first receive addresses, so a found account can be
+//! monitored without the mnemonic ever leaving the generating machine.
+
+use crate::address::decode_bech32;
+use crate::error::VanityError;
+use crate::hd::{format_derivation_path, ExtendedPublicKey, HARDENED_OFFSET};
+use crate::mnemonic::parse_mnemonic;
+use crate::schema::SCHEMA_VERSION;
+use crate::{derive_key_at_path, Keypair};
+use serde_json::json;
+use wasm_bindgen::prelude::*;
+
+/// Most receive addresses a bundle lists
+const MAX_WATCH_ONLY_ADDRESSES: u32 = 1000;
+
+/// Exports a watch-only bundle for a keypair as JSON
+///
+/// The bundle holds the account xpub (e.g. for m/44'/118'/0'), the account
+/// path, the HRP, the scheme and the first `address_count` receive addresses
+/// (`<account>/0/i`), each derived from the xpub alone. It contains no
+/// private material. The account is taken from the keypair's derivation path,
+/// which must end in two non-hardened components as BIP44 paths do.
+///
+/// # Arguments
+/// * `keypair` - The keypair to watch (must have a mnemonic)
+/// * `address_count` - Number of receive addresses to list (1 to 1000)
+///
+/// # Returns
+/// * `String` - The bundle as JSON, or an error if the keypair cannot be exported
+///
+/// # Example
+/// ```javascript
+/// const bundle = export_watch_only(keypair, 5);
+/// download("watch-only.json", bundle);
+/// ```
+#[wasm_bindgen]
+pub fn export_watch_only(keypair: &Keypair, address_count: u32) -> Result<String, VanityError> {
+    if address_count == 0 || address_count > MAX_WATCH_ONLY_ADDRESSES {
+        return Err(VanityError::InvalidInput(format!(
+            "Address count must be between 1 and {}",
+            MAX_WATCH_ONLY_ADDRESSES
+        )));
+    }
+
+    watch_only_bundle(keypair, address_count)
+        .map_err(|e| VanityError::InvalidInput(format!("Error exporting watch-only bundle: {}", e)))
+}
+
+/// Builds the watch-only bundle for a keypair
+fn watch_only_bundle(
+    keypair: &Keypair,
+    address_count: u32,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let mnemonic = parse_mnemonic(
+        keypair
+            .mnemonic
+            .as_deref()
+            .ok_or("Keypair has no mnemonic to derive an xpub from")?,
+    )?;
+    let path = keypair
+        .derivation_path
+        .clone()
+        .unwrap_or_else(|| keypair.scheme.derivation_path().to_vec());
+    let account_path = match path.as_slice() {
+        [account @ .., change, index]
+            if !account.is_empty() && *change < HARDENED_OFFSET && *index < HARDENED_OFFSET =>
+        {
+            account
+        }
+        _ => return Err("Derivation path must end in non-hardened change and index".into()),
+    };
+    let (hrp, _) = decode_bech32(&keypair.address)?;
+
+    let xpub = derive_key_at_path(&mnemonic, account_path)?.to_xpub()?;
+    let receive_chain = ExtendedPublicKey::from_xpub(&xpub)?.derive_child(0)?;
+    let mut addresses = Vec::with_capacity(address_count as usize);
+    let mut next_index = 0;
+    while addresses.len() < address_count as usize {
+        // BIP32 skips invalid children, so record the index actually used
+        let child = receive_chain.derive_child(next_index)?;
+        let index = child.child_number();
+        let mut child_path = account_path.to_vec();
+        child_path.extend([0, index]);
+        addresses.push(json!({
+            "index": index,
+            "path": format_derivation_path(&child_path),
+            "address": keypair.scheme.address(&child.public_key_bytes(), &hrp)?,
+        }));
+        next_index = index + 1;
+    }
+
+    let bundle = json!({
+        "schema_version": SCHEMA_VERSION,
+        "xpub": xpub,
+        "account_path": format_derivation_path(account_path),
+        "hrp": hrp,
+        "scheme": keypair.scheme.name(),
+        "addresses": addresses,
+    });
+    Ok(serde_json::to_string_pretty(&bundle)?)
+}
@@ -0,0 +1,76 @@
+//! BIP39 wordlist access
+//!
+//! All ten BIP39 wordlists are compiled into the module, so frontends can
+//! offer type-ahead mnemonic entry without shipping their own copies.
+
+use crate::error::VanityError;
+use bip39::Language;
+use wasm_bindgen::prelude::*;
+
+/// Resolves a wordlist name such as "english" or "japanese"
+///
+/// Names are case-insensitive; "chinese-simplified" and "chinese-traditional"
+/// also accept underscores, and ISO 639-1 codes ("en", "ja", ...) are accepted
+/// where they are unambiguous.
+pub fn parse_language(name: &str) -> Result<Language, String> {
+    let language = match name.trim().to_lowercase().replace('_', "-").as_str() {
+        "english" | "en" => Language::English,
+        "chinese-simplified" | "zh-hans" => Language::SimplifiedChinese,
+        "chinese-traditional" | "zh-hant" => Language::TraditionalChinese,
+        "czech" | "cs" => Language::Czech,
+        "french" | "fr" => Language::French,
+        "italian" | "it" => Language::Italian,
+        "japanese" | "ja" => Language::Japanese,
+        "korean" | "ko" => Language::Korean,
+        "portuguese" | "pt" => Language::Portuguese,
+        "spanish" | "es" => Language::Spanish,
+        other => return Err(format!("Unsupported wordlist language: {:?}", other)),
+    };
+    Ok(language)
+}
+
+/// Resolves an optional wordlist name, defaulting to English
+pub fn language_or_default(language: Option<String>) -> Result<Language, String> {
+    language.map_or(Ok(Language::English), |name| parse_language(&name))
+}
+
+/// Looks up each word in the wordlist, naming the first unknown word
+pub fn word_indexes(words: &[String], language: Language) -> Result<Vec<u16>, String> {
+    words
+        .iter()
+        .enumerate()
+        .map(|(position, word)| {
+            language.find_word(word).ok_or_else(|| {
+                format!(
+                    "Word {} {:?} is not in the BIP39 wordlist",
+                    position + 1,
+                    word
+                )
+            })
+        })
+        .collect()
+}
+
+/// Suggests wordlist entries that start with the typed prefix
+///
+/// # Arguments
+/// * `prefix` - The characters typed so far (case-insensitive)
+/// * `language` - Optional wordlist name (defaults to "english"; e.g. "spanish", "japanese")
+///
+/// # Returns
+/// * `Vec<String>` - The matching words in wordlist order, or an error for an unknown language
+///
+/// # Example
+/// ```javascript
+/// const options = suggest_words("aba", undefined); // ["abandon"]
+/// ```
+#[wasm_bindgen]
+pub fn suggest_words(prefix: &str, language: Option<String>) -> Result<Vec<String>, VanityError> {
+    let language = language_or_default(language).map_err(VanityError::InvalidInput)?;
+
+    Ok(language
+        .words_by_prefix(&prefix.trim().to_lowercase())
+        .iter()
+        .map(|word| word.to_string())
+        .collect())
+}
@@ -94,7 +94,8 @@ async function handleVanityBatch(id, { target, position, batchSize, workerId })
             data: {
                 keypair: {
                     address: result.address,
-                    mnemonic: result.mnemonic
+                    // One-shot: the phrase leaves WASM memory exactly once
+                    mnemonic: result.reveal_mnemonic(true)
                 },
                 workerId,
                 attempts: actualBatchSize